@@ -0,0 +1,1351 @@
+// `auton`: command-line access to every auton_program instruction.
+// Every command prints a single JSON object so output can be piped into other tools.
+
+mod output;
+
+use anyhow::{anyhow, bail, Context, Result};
+use auton_client::instructions::{
+    self, AddContentArgs, BundleArgs, CouponArgs, CouponPayment, SubscriptionTierArgs, TokenPayment,
+};
+use auton_client::{
+    accounts, pda, Bundle, BundleReceipt, Coupon, CouponDiscount, FeeRounding, FeeTier, LegacyPaidAccessAccount,
+    PaidAccessAccount, RentalTerms, RevenueSplit, SplitRecipient, Subscription, SubscriptionTier, TransferFeePayer,
+    CREATOR_DEFAULT_SPLIT, PROGRAM_ID,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde_json::{json, Value};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::account::from_account;
+use solana_sdk::clock::Clock;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use solana_sdk::sysvar;
+use solana_sdk::transaction::Transaction;
+
+#[derive(Parser)]
+#[command(name = "auton", version, about = "Manage auton_program config, creators, content and purchases")]
+struct Cli {
+    /// RPC endpoint to send transactions to
+    #[arg(long, short = 'u', env = "AUTON_RPC_URL", default_value = "https://api.devnet.solana.com", global = true)]
+    url: String,
+
+    /// Keypair that signs and pays for transactions
+    #[arg(long, short = 'k', env = "AUTON_KEYPAIR", default_value = "~/.config/solana/id.json", global = true)]
+    keypair: String,
+
+    /// Simulate transactions instead of sending them
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Protocol configuration (admin only, except `show`)
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// Collected platform fees
+    #[command(subcommand)]
+    Treasury(TreasuryCommand),
+    /// Per-creator platform fees (admin only, except `show`)
+    #[command(subcommand)]
+    FeeOverride(FeeOverrideCommand),
+    /// Username registry
+    #[command(subcommand)]
+    Username(UsernameCommand),
+    /// Creator accounts
+    #[command(subcommand)]
+    Creator(CreatorCommand),
+    /// Content items
+    #[command(subcommand)]
+    Content(ContentCommand),
+    /// Creator profile metadata
+    #[command(subcommand)]
+    Profile(ProfileCommand),
+    /// Revenue splits between collaborators
+    #[command(subcommand)]
+    Split(SplitCommand),
+    /// Affiliate shares and referrer earnings
+    #[command(subcommand)]
+    Referral(ReferralCommand),
+    /// Bundles of content items sold together
+    #[command(subcommand)]
+    Bundle(BundleCommand),
+    /// Promo codes for the signer's content
+    #[command(subcommand)]
+    Coupon(CouponCommand),
+    /// Buy a content item
+    Purchase(PurchaseArgs),
+    /// Rent a content item, or extend the signer's rental of it
+    Rent(RentArgs),
+    /// Tip a creator
+    Tip(TipArgs),
+    /// Tips and pay-what-you-want totals
+    #[command(subcommand)]
+    Supporters(SupportersCommand),
+    /// Access receipts
+    #[command(subcommand)]
+    Receipt(ReceiptCommand),
+    /// Subscription tiers and subscriptions
+    #[command(subcommand)]
+    Subscription(SubscriptionCommand),
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Create the protocol config with the signer as admin
+    Init {
+        /// Platform fee in basis points (500 = 5%)
+        #[arg(long)]
+        fee_bps: u64,
+    },
+    /// Upgrade a config created by an older program version to the current layout (admin only)
+    Migrate,
+    /// Change the platform fee and/or how fractional fees are rounded.
+    /// Fee increases take effect after a notice period; cuts apply immediately.
+    Update {
+        #[arg(long)]
+        fee_bps: Option<u64>,
+        #[arg(long, value_enum)]
+        fee_rounding: Option<Rounding>,
+    },
+    /// Propose handing the admin role to another wallet
+    ProposeAdmin { new_admin: Pubkey },
+    /// Take over the admin role as the proposed admin
+    AcceptAdmin,
+    /// Withdraw a pending admin proposal
+    CancelAdminProposal,
+    /// Hand the fee recipient role to another wallet (signed by the current fee recipient)
+    SetFeeRecipient { new_fee_recipient: Pubkey },
+    /// Replace the volume fee tiers, e.g. `--tier 100:400 --tier 1000:300` (min sales:fee bps)
+    SetFeeTiers {
+        /// Omit to remove all tiers
+        #[arg(long = "tier", value_parser = parse_fee_tier)]
+        tiers: Vec<FeeTier>,
+    },
+    /// Change the hard cap on the platform fee (governance, or the admin to lower it)
+    SetMaxFee { max_fee_bps: u64 },
+    /// Hand the governance role to another wallet (signed by the current governance key)
+    SetGovernance { new_governance: Pubkey },
+    /// Set which instructions are paused (admin or guardian); pass no flags to resume everything
+    SetPaused(PauseFlags),
+    /// Replace the guardian key that can pause the protocol
+    SetGuardian { new_guardian: Pubkey },
+    /// Print the current config
+    Show,
+}
+
+#[derive(Args)]
+struct PauseFlags {
+    /// Pause everything below
+    #[arg(long)]
+    all: bool,
+    /// Pause purchases, rentals, tips, subscriptions and renewals
+    #[arg(long)]
+    payments: bool,
+    #[arg(long)]
+    add_content: bool,
+    #[arg(long)]
+    register_username: bool,
+    #[arg(long)]
+    initialize_creator: bool,
+}
+
+impl PauseFlags {
+    fn mask(&self) -> u8 {
+        if self.all {
+            return auton_client::PAUSE_ALL;
+        }
+        [
+            (self.payments, auton_client::PAUSE_PAYMENTS),
+            (self.add_content, auton_client::PAUSE_ADD_CONTENT),
+            (self.register_username, auton_client::PAUSE_REGISTER_USERNAME),
+            (self.initialize_creator, auton_client::PAUSE_INITIALIZE_CREATOR),
+        ]
+        .into_iter()
+        .filter(|(set, _)| *set)
+        .fold(0, |mask, (_, flag)| mask | flag)
+    }
+}
+
+#[derive(Subcommand)]
+enum TreasuryCommand {
+    /// Send collected fees to the config's fee recipient (admin only)
+    Withdraw {
+        /// Amount in lamports, or in base units of `--mint`
+        amount: u64,
+        /// Withdraw this SPL or Token-2022 mint instead of SOL
+        #[arg(long)]
+        mint: Option<Pubkey>,
+    },
+    /// Print the treasury's address and SOL balance
+    Show,
+}
+
+#[derive(Subcommand)]
+enum FeeOverrideCommand {
+    /// Give a creator a custom platform fee
+    Create {
+        creator: Pubkey,
+        #[arg(long)]
+        fee_bps: u64,
+        /// Unix timestamp the override stops applying at (omit for no expiry)
+        #[arg(long)]
+        expires_at: Option<i64>,
+    },
+    /// Change a creator's custom fee and expiry
+    Update {
+        creator: Pubkey,
+        #[arg(long)]
+        fee_bps: u64,
+        #[arg(long)]
+        expires_at: Option<i64>,
+    },
+    /// Remove a creator's custom fee
+    Remove { creator: Pubkey },
+    /// Print a creator's fee override
+    Show { creator: Pubkey },
+}
+
+#[derive(Subcommand)]
+enum UsernameCommand {
+    /// Claim a username for the signer
+    Register { username: String },
+    /// Print the wallet a username belongs to
+    Lookup { username: String },
+}
+
+#[derive(Subcommand)]
+enum CreatorCommand {
+    /// Create the signer's creator account
+    Init,
+    /// Move the signer's content out of a creator account made by an older program version
+    Migrate {
+        /// Content items moved per transaction
+        #[arg(long, default_value_t = 8)]
+        batch_size: usize,
+    },
+    /// Print a creator account (defaults to the signer)
+    Show { creator: Option<Pubkey> },
+}
+
+#[derive(Subcommand)]
+enum ContentCommand {
+    /// Add a content item for the signer
+    Add {
+        #[arg(long)]
+        title: String,
+        /// Price in lamports, or in base units of `--mint`
+        #[arg(long)]
+        price: u64,
+        /// Encrypted CID as hex (nonce + ciphertext + auth tag)
+        #[arg(long)]
+        encrypted_cid: String,
+        /// Price the content in this SPL or Token-2022 mint instead of SOL
+        #[arg(long)]
+        mint: Option<Pubkey>,
+        /// Who absorbs the mint's transfer fee, if it has one
+        #[arg(long, value_enum, default_value_t = FeePayer::Buyer)]
+        transfer_fee_payer: FeePayer,
+        /// Sell at most this many copies, as a limited edition
+        #[arg(long)]
+        max_supply: Option<u64>,
+    },
+    /// Edit one of the signer's content items
+    Update {
+        content_id: u64,
+        #[arg(long)]
+        title: Option<String>,
+        #[arg(long)]
+        price: Option<u64>,
+        /// Encrypted CID as hex
+        #[arg(long)]
+        encrypted_cid: Option<String>,
+    },
+    /// Take one of the signer's content items off sale
+    Unlist { content_id: u64 },
+    /// Put one of the signer's content items back on sale
+    Relist { content_id: u64 },
+    /// Let buyers pay what they want for one of the signer's content items, its price
+    /// becoming the minimum (`--off` to go back to a fixed price)
+    PayWhatYouWant {
+        content_id: u64,
+        #[arg(long)]
+        off: bool,
+    },
+    /// Cap the copies of one of the signer's content items that can be bought. Caps can only be lowered.
+    MaxSupply { content_id: u64, max_supply: u64 },
+    /// Offer one of the signer's content items for rent (`--off` to stop renting it out)
+    Rental {
+        content_id: u64,
+        /// Rental price in the content's currency
+        #[arg(long, required_unless_present = "off")]
+        price: Option<u64>,
+        /// Seconds of access per rental
+        #[arg(long, required_unless_present = "off")]
+        duration: Option<i64>,
+        #[arg(long, conflicts_with_all = ["price", "duration"])]
+        off: bool,
+    },
+    /// List a creator's content items (defaults to the signer)
+    List { creator: Option<Pubkey> },
+}
+
+#[derive(Subcommand)]
+enum ProfileCommand {
+    /// Set the signer's profile metadata CID
+    Update { profile_cid: String },
+}
+
+#[derive(Subcommand)]
+enum SplitCommand {
+    /// Split the signer's proceeds from a content item (or all content) between recipients
+    Create {
+        #[command(flatten)]
+        split: SplitArgs,
+    },
+    /// Replace the recipients of one of the signer's revenue splits
+    Update {
+        #[command(flatten)]
+        split: SplitArgs,
+    },
+    /// Remove one of the signer's revenue splits
+    Remove {
+        /// Content item the split is for (omit for the creator-wide default)
+        #[arg(long)]
+        content_id: Option<u64>,
+    },
+    /// Print the split that applies to a creator's content item (defaults to the signer)
+    Show {
+        #[arg(long)]
+        creator: Option<Pubkey>,
+        /// Omit for the creator-wide default
+        #[arg(long)]
+        content_id: Option<u64>,
+    },
+}
+
+#[derive(Args)]
+struct SplitArgs {
+    /// Content item the split is for (omit for the creator-wide default)
+    #[arg(long)]
+    content_id: Option<u64>,
+    /// A recipient as WALLET:SHARE_BPS; shares must add up to 10000
+    #[arg(long = "recipient", value_parser = parse_split_recipient, required = true)]
+    recipients: Vec<SplitRecipient>,
+}
+
+#[derive(Subcommand)]
+enum ReferralCommand {
+    /// Set the share of the signer's sales paid to referrers, in basis points (0 turns it off)
+    SetShare { affiliate_bps: u64 },
+    /// Set one of the signer's content items' own affiliate share; omit `--affiliate-bps`
+    /// to fall back to the creator-wide share
+    SetContentShare {
+        content_id: u64,
+        #[arg(long)]
+        affiliate_bps: Option<u64>,
+    },
+    /// Register the signer as a referrer for payouts in SOL, or in `--mint`
+    Register {
+        #[arg(long)]
+        mint: Option<Pubkey>,
+    },
+    /// Print a referrer's earnings (defaults to the signer) in SOL, or in `--mint`
+    Show {
+        referrer: Option<Pubkey>,
+        #[arg(long)]
+        mint: Option<Pubkey>,
+    },
+}
+
+#[derive(Subcommand)]
+enum BundleCommand {
+    /// Create a bundle of the signer's content items
+    Create {
+        bundle_id: u8,
+        #[command(flatten)]
+        terms: BundleTerms,
+        #[arg(long)]
+        mint: Option<Pubkey>,
+        #[arg(long, value_enum, default_value_t = FeePayer::Buyer)]
+        transfer_fee_payer: FeePayer,
+    },
+    /// Change one of the signer's bundles
+    Update {
+        bundle_id: u8,
+        #[command(flatten)]
+        terms: BundleTerms,
+    },
+    /// Take one of the signer's bundles off sale
+    Remove { bundle_id: u8 },
+    /// Print a creator's bundle (defaults to the signer)
+    Show {
+        #[arg(long)]
+        creator: Option<Pubkey>,
+        bundle_id: u8,
+    },
+    /// Buy every item in a creator's bundle
+    Buy {
+        #[arg(long)]
+        creator: Pubkey,
+        bundle_id: u8,
+        /// Highest price to accept (defaults to the current price after discount)
+        #[arg(long)]
+        max_price: Option<u64>,
+        /// Highest platform fee to accept, in basis points
+        #[arg(long)]
+        max_fee_bps: Option<u64>,
+    },
+}
+
+#[derive(Args)]
+struct BundleTerms {
+    /// Content IDs in the bundle
+    #[arg(long, value_delimiter = ',', required = true)]
+    content_ids: Vec<u64>,
+    /// Price before discount, in lamports or base units of the bundle's mint
+    #[arg(long)]
+    price: u64,
+    /// Discount off the price, in basis points
+    #[arg(long)]
+    discount_bps: Option<u64>,
+}
+
+#[derive(Subcommand)]
+enum CouponCommand {
+    /// Create a coupon for the signer's content
+    Create {
+        code: String,
+        #[command(flatten)]
+        discount: DiscountArgs,
+        /// Total redemptions allowed
+        #[arg(long)]
+        max_redemptions: Option<u64>,
+        /// Redemptions allowed per buyer
+        #[arg(long)]
+        max_per_buyer: Option<u64>,
+        /// Unix timestamp from which the coupon can't be used
+        #[arg(long)]
+        expires_at: Option<i64>,
+        /// Mint an amount-off discount is in (defaults to SOL)
+        #[arg(long)]
+        mint: Option<Pubkey>,
+    },
+    /// Withdraw one of the signer's coupons
+    Remove { code: String },
+    /// Print a creator's coupon (defaults to the signer)
+    Show {
+        #[arg(long)]
+        creator: Option<Pubkey>,
+        code: String,
+    },
+    /// Create the signer's redemption counter for a coupon with a per-buyer limit
+    Claim {
+        #[arg(long)]
+        creator: Pubkey,
+        code: String,
+    },
+}
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct DiscountArgs {
+    /// Percentage off, in basis points
+    #[arg(long)]
+    percent_off_bps: Option<u64>,
+    /// Fixed amount off, in lamports or base units of `--mint`
+    #[arg(long)]
+    amount_off: Option<u64>,
+}
+
+#[derive(Args)]
+struct PurchaseArgs {
+    #[arg(long)]
+    creator: Pubkey,
+    #[arg(long)]
+    content_id: u64,
+    /// Highest price to accept (defaults to the current price); for pay-what-you-want
+    /// content, the amount to pay
+    #[arg(long)]
+    max_price: Option<u64>,
+    /// Highest platform fee to accept, in basis points
+    #[arg(long)]
+    max_fee_bps: Option<u64>,
+    /// Registered referrer to credit with the sale
+    #[arg(long)]
+    referrer: Option<Pubkey>,
+    /// Code of one of the creator's coupons to redeem
+    #[arg(long)]
+    coupon: Option<String>,
+}
+
+#[derive(Args)]
+struct RentArgs {
+    #[arg(long)]
+    creator: Pubkey,
+    #[arg(long)]
+    content_id: u64,
+    /// Highest rental price to accept (defaults to the current one)
+    #[arg(long)]
+    max_price: Option<u64>,
+    /// Highest platform fee to accept, in basis points
+    #[arg(long)]
+    max_fee_bps: Option<u64>,
+}
+
+#[derive(Args)]
+struct TipArgs {
+    #[arg(long)]
+    creator: Pubkey,
+    /// Amount in lamports, or in base units of `--mint`
+    amount: u64,
+    /// Short message to the creator
+    #[arg(long)]
+    message: Option<String>,
+    /// Tip in this SPL or Token-2022 mint instead of SOL
+    #[arg(long)]
+    mint: Option<Pubkey>,
+    /// Highest platform fee to accept, in basis points
+    #[arg(long)]
+    max_fee_bps: Option<u64>,
+}
+
+#[derive(Subcommand)]
+enum SupportersCommand {
+    /// Accept tips and pay-what-you-want sales in SOL, or in `--mint`
+    Create {
+        #[arg(long)]
+        mint: Option<Pubkey>,
+    },
+    /// Print a creator's supporter totals (defaults to the signer) in SOL, or in `--mint`
+    Show {
+        creator: Option<Pubkey>,
+        #[arg(long)]
+        mint: Option<Pubkey>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ReceiptCommand {
+    /// Check whether a buyer (defaults to the signer) holds a receipt (current or legacy), unexpired rental,
+    /// bundle receipt or active subscription for a content item
+    Check {
+        #[arg(long)]
+        creator: Pubkey,
+        #[arg(long)]
+        content_id: u64,
+        #[arg(long)]
+        buyer: Option<Pubkey>,
+    },
+    /// Move a legacy receipt to the creator-scoped layout
+    Migrate {
+        #[arg(long)]
+        creator: Pubkey,
+        #[arg(long)]
+        content_id: u64,
+        #[arg(long)]
+        buyer: Option<Pubkey>,
+    },
+}
+
+#[derive(Subcommand)]
+enum SubscriptionCommand {
+    /// Create a subscription tier for the signer
+    CreateTier {
+        tier_id: u8,
+        #[command(flatten)]
+        terms: TierTerms,
+        #[arg(long)]
+        mint: Option<Pubkey>,
+        #[arg(long, value_enum, default_value_t = FeePayer::Buyer)]
+        transfer_fee_payer: FeePayer,
+    },
+    /// Change one of the signer's subscription tiers
+    UpdateTier {
+        tier_id: u8,
+        #[command(flatten)]
+        terms: TierTerms,
+    },
+    /// Subscribe to a creator's tier
+    Subscribe {
+        #[arg(long)]
+        creator: Pubkey,
+        tier_id: u8,
+        #[arg(long, default_value_t = 1)]
+        periods: u32,
+        /// Highest total price to accept for all the periods (defaults to the current one)
+        #[arg(long)]
+        max_price: Option<u64>,
+        /// Highest platform fee to accept, in basis points
+        #[arg(long)]
+        max_fee_bps: Option<u64>,
+    },
+    /// Renew the signer's subscription to a creator
+    Renew {
+        #[arg(long)]
+        creator: Pubkey,
+        tier_id: u8,
+        #[arg(long, default_value_t = 1)]
+        periods: u32,
+        /// Highest total price to accept for all the periods (defaults to the current one)
+        #[arg(long)]
+        max_price: Option<u64>,
+        /// Highest platform fee to accept, in basis points
+        #[arg(long)]
+        max_fee_bps: Option<u64>,
+    },
+}
+
+#[derive(Args)]
+struct TierTerms {
+    /// Price per period, in lamports or base units of the tier's mint
+    #[arg(long)]
+    price: u64,
+    #[arg(long)]
+    period_seconds: i64,
+    /// Content IDs the tier covers (omit for all content)
+    #[arg(long, value_delimiter = ',')]
+    content_ids: Vec<u64>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FeePayer {
+    Buyer,
+    Creator,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Rounding {
+    Down,
+    Up,
+    HalfUp,
+}
+
+impl From<Rounding> for FeeRounding {
+    fn from(rounding: Rounding) -> Self {
+        match rounding {
+            Rounding::Down => FeeRounding::Down,
+            Rounding::Up => FeeRounding::Up,
+            Rounding::HalfUp => FeeRounding::HalfUp,
+        }
+    }
+}
+
+impl From<FeePayer> for TransferFeePayer {
+    fn from(fee_payer: FeePayer) -> Self {
+        match fee_payer {
+            FeePayer::Buyer => TransferFeePayer::Buyer,
+            FeePayer::Creator => TransferFeePayer::Creator,
+        }
+    }
+}
+
+// Connection and signer shared by every command.
+// The keypair is only required by commands that sign or default to the signer's wallet,
+// so read-only lookups work without one.
+struct Session {
+    client: RpcClient,
+    signer: std::result::Result<Keypair, String>,
+    dry_run: bool,
+}
+
+impl Session {
+    fn new(cli: &Cli) -> Self {
+        let keypair_path = expand_home(&cli.keypair);
+        let signer = read_keypair_file(&keypair_path)
+            .map_err(|err| format!("failed to read keypair {keypair_path}: {err}"));
+        Self {
+            client: RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed()),
+            signer,
+            dry_run: cli.dry_run,
+        }
+    }
+
+    fn signer(&self) -> Result<&Keypair> {
+        self.signer.as_ref().map_err(|err| anyhow!("{err}"))
+    }
+
+    fn pubkey(&self) -> Result<Pubkey> {
+        Ok(self.signer()?.pubkey())
+    }
+
+    // Signs `instructions` with the session keypair and sends them, or simulates them in dry-run mode.
+    fn send(&self, instructions: &[Instruction]) -> Result<Value> {
+        let signer = self.signer()?;
+        let blockhash = self.client.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&signer.pubkey()),
+            &[signer],
+            blockhash,
+        );
+
+        if self.dry_run {
+            let simulation = self.client.simulate_transaction(&transaction)?.value;
+            return Ok(json!({
+                "dry_run": true,
+                "success": simulation.err.is_none(),
+                "error": simulation.err.map(|err| err.to_string()),
+                "units_consumed": simulation.units_consumed,
+                "logs": simulation.logs.unwrap_or_default(),
+            }));
+        }
+
+        let signature = self.client.send_and_confirm_transaction(&transaction)?;
+        Ok(json!({ "signature": signature.to_string() }))
+    }
+
+    // Fetches and decodes a program account, or None if it doesn't exist.
+    fn fetch<T: anchor_lang::AccountDeserialize>(&self, address: &Pubkey) -> Result<Option<T>> {
+        let account = self
+            .client
+            .get_account_with_commitment(address, self.client.commitment())?
+            .value;
+        account
+            .map(|account| accounts::decode(&account.data).map_err(|err| anyhow!("failed to decode {address}: {err}")))
+            .transpose()
+    }
+
+    fn fetch_required<T: anchor_lang::AccountDeserialize>(&self, address: &Pubkey, what: &str) -> Result<T> {
+        self.fetch(address)?
+            .with_context(|| format!("{what} not found at {address}"))
+    }
+
+    fn fee_recipient(&self) -> Result<Pubkey> {
+        let config: auton_client::ProtocolConfig = self.fetch_required(&pda::config().0, "protocol config")?;
+        Ok(config.fee_recipient)
+    }
+
+    // The revenue split a purchase of the content item pays: its own, else the creator's default.
+    fn revenue_split(&self, creator: &Pubkey, content_id: u64) -> Result<Option<RevenueSplit>> {
+        match self.fetch(&pda::revenue_split(creator, content_id).0)? {
+            Some(split) => Ok(Some(split)),
+            None => self.fetch(&pda::revenue_split(creator, CREATOR_DEFAULT_SPLIT).0),
+        }
+    }
+
+    // `buyer`'s receipt for the content ID from before receipts were scoped per creator, if it
+    // hasn't been migrated yet. It only proves access to the creator recorded on it.
+    fn legacy_receipt_for(
+        &self,
+        buyer: &Pubkey,
+        creator: &Pubkey,
+        content_id: u64,
+    ) -> Result<Option<(Pubkey, LegacyPaidAccessAccount)>> {
+        let address = pda::legacy_receipt(buyer, content_id).0;
+        let account = self
+            .client
+            .get_account_with_commitment(&address, self.client.commitment())?
+            .value;
+        let Some(account) = account.filter(|account| account.owner == PROGRAM_ID) else { return Ok(None) };
+        let receipt = accounts::decode_legacy_receipt(&account.data)
+            .map_err(|err| anyhow!("failed to decode {address}: {err}"))?;
+        Ok((receipt.creator == *creator).then_some((address, receipt)))
+    }
+
+    // A bundle receipt of `buyer`'s that grants access to one of `creator`'s content items.
+    // Bundle IDs are a u8, so every possible receipt address is checked.
+    fn bundle_receipt_for(
+        &self,
+        buyer: &Pubkey,
+        creator: &Pubkey,
+        content_id: u64,
+    ) -> Result<Option<(Pubkey, BundleReceipt)>> {
+        let addresses: Vec<Pubkey> = (0..=u8::MAX)
+            .map(|bundle_id| pda::bundle_receipt(buyer, creator, bundle_id).0)
+            .collect();
+        for chunk in addresses.chunks(100) {
+            let fetched = self.client.get_multiple_accounts(chunk)?;
+            for (address, account) in chunk.iter().zip(fetched) {
+                let Some(account) = account else { continue };
+                let receipt = accounts::decode_bundle_receipt(&account.data)
+                    .map_err(|err| anyhow!("failed to decode {address}: {err}"))?;
+                if receipt.grants_access(creator, content_id) {
+                    return Ok(Some((*address, receipt)));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    // The buyer's subscription to the creator, if it currently grants access to the content.
+    fn subscription_for(
+        &self,
+        subscriber: &Pubkey,
+        creator: &Pubkey,
+        content_id: u64,
+        now: i64,
+    ) -> Result<Option<(Pubkey, Subscription)>> {
+        let address = pda::subscription(subscriber, creator).0;
+        let Some(subscription) = self.fetch::<Subscription>(&address)? else { return Ok(None) };
+        let tier: Option<SubscriptionTier> = self.fetch(&pda::subscription_tier(creator, subscription.tier_id).0)?;
+        let item: Option<auton_client::ContentItem> = self.fetch(&pda::content(creator, content_id).0)?;
+        Ok(tier
+            .zip(item)
+            .filter(|(tier, item)| subscription.grants_access(tier, item, now))
+            .map(|_| (address, subscription)))
+    }
+
+    // The cluster's current Unix time, which rental and subscription expiries are compared with.
+    // Read from the Clock sysvar, the same clock the program checks them against.
+    fn now(&self) -> Result<i64> {
+        let account = self.client.get_account(&sysvar::clock::ID)?;
+        let clock: Clock = from_account(&account).context("failed to decode the Clock sysvar")?;
+        Ok(clock.unix_timestamp)
+    }
+
+    // The token a price is paid in, resolving the token program from the mint's owner.
+    fn token_payment(&self, payment_mint: Option<Pubkey>) -> Result<Option<TokenPayment>> {
+        payment_mint
+            .map(|mint| {
+                let token_program = self.client.get_account(&mint)?.owner;
+                Ok(TokenPayment { mint, token_program })
+            })
+            .transpose()
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    match run(cli) {
+        Ok(value) => println!("{}", serde_json::to_string_pretty(&value).unwrap()),
+        Err(err) => {
+            println!("{}", json!({ "error": format!("{err:#}") }));
+            std::process::exit(1);
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<Value> {
+    let session = Session::new(&cli);
+    let me = || session.pubkey();
+
+    match cli.command {
+        Command::Config(ConfigCommand::Init { fee_bps }) => {
+            session.send(&[instructions::initialize_config(&me()?, fee_bps)])
+        }
+        Command::Config(ConfigCommand::Migrate) => session.send(&[instructions::migrate_config(&me()?)]),
+        Command::Config(ConfigCommand::Update { fee_bps, fee_rounding }) => {
+            if fee_bps.is_none() && fee_rounding.is_none() {
+                bail!("nothing to update: pass --fee-bps and/or --fee-rounding");
+            }
+            let fee_rounding = fee_rounding.map(FeeRounding::from);
+            session.send(&[instructions::update_config(&me()?, fee_bps, fee_rounding)])
+        }
+        Command::Config(ConfigCommand::ProposeAdmin { new_admin }) => {
+            session.send(&[instructions::propose_admin(&me()?, &new_admin)])
+        }
+        Command::Config(ConfigCommand::AcceptAdmin) => {
+            session.send(&[instructions::accept_admin(&me()?)])
+        }
+        Command::Config(ConfigCommand::CancelAdminProposal) => {
+            session.send(&[instructions::cancel_admin_proposal(&me()?)])
+        }
+        Command::Config(ConfigCommand::SetFeeRecipient { new_fee_recipient }) => {
+            session.send(&[instructions::set_fee_recipient(&me()?, &new_fee_recipient)])
+        }
+        Command::Config(ConfigCommand::SetFeeTiers { tiers }) => {
+            session.send(&[instructions::set_fee_tiers(&me()?, tiers)])
+        }
+        Command::Config(ConfigCommand::SetMaxFee { max_fee_bps }) => {
+            session.send(&[instructions::set_max_fee_percentage(&me()?, max_fee_bps)])
+        }
+        Command::Config(ConfigCommand::SetGovernance { new_governance }) => {
+            session.send(&[instructions::set_governance(&me()?, &new_governance)])
+        }
+        Command::Config(ConfigCommand::SetPaused(flags)) => {
+            session.send(&[instructions::set_paused(&me()?, flags.mask())])
+        }
+        Command::Config(ConfigCommand::SetGuardian { new_guardian }) => {
+            session.send(&[instructions::set_guardian(&me()?, &new_guardian)])
+        }
+        Command::Config(ConfigCommand::Show) => {
+            let address = pda::config().0;
+            let config = session.fetch_required(&address, "protocol config")?;
+            Ok(output::protocol_config(&address, &config))
+        }
+
+        Command::Treasury(TreasuryCommand::Withdraw { amount, mint }) => {
+            let token = session.token_payment(mint)?;
+            let fee_recipient = session.fee_recipient()?;
+            session.send(&[instructions::withdraw_treasury(&me()?, &fee_recipient, amount, token.as_ref())])
+        }
+        Command::Treasury(TreasuryCommand::Show) => {
+            let address = pda::treasury().0;
+            let lamports = session.client.get_balance(&address)?;
+            Ok(json!({ "address": address.to_string(), "lamports": lamports }))
+        }
+
+        Command::FeeOverride(FeeOverrideCommand::Create { creator, fee_bps, expires_at }) => {
+            session.send(&[instructions::create_fee_override(&me()?, &creator, fee_bps, expires_at)])
+        }
+        Command::FeeOverride(FeeOverrideCommand::Update { creator, fee_bps, expires_at }) => {
+            session.send(&[instructions::update_fee_override(&me()?, &creator, fee_bps, expires_at)])
+        }
+        Command::FeeOverride(FeeOverrideCommand::Remove { creator }) => {
+            session.send(&[instructions::close_fee_override(&me()?, &creator)])
+        }
+        Command::FeeOverride(FeeOverrideCommand::Show { creator }) => {
+            let address = pda::fee_override(&creator).0;
+            let fee_override = session.fetch_required(&address, "fee override")?;
+            Ok(output::fee_override(&address, &fee_override))
+        }
+
+        Command::Username(UsernameCommand::Register { username }) => {
+            session.send(&[instructions::register_username(&me()?, &me()?, &username)])
+        }
+        Command::Username(UsernameCommand::Lookup { username }) => {
+            let address = pda::username(&username).0;
+            let account = session.fetch_required(&address, "username")?;
+            Ok(output::username_account(&address, &account))
+        }
+
+        Command::Creator(CreatorCommand::Init) => {
+            session.send(&[instructions::initialize_creator(&me()?, &me()?)])
+        }
+        Command::Creator(CreatorCommand::Migrate { batch_size }) => {
+            let creator = me()?;
+            let address = pda::creator(&creator).0;
+            let account = session
+                .client
+                .get_account(&address)
+                .with_context(|| format!("creator account not found at {address}"))?;
+            let legacy = accounts::decode_legacy_creator_account(&account.data)
+                .map_err(|err| anyhow!("{address} is not a legacy creator account: {err}"))?;
+            let content_ids: Vec<u64> = legacy.content.iter().map(|item| item.id).collect();
+            // The last transaction, with whatever is left (possibly nothing), also converts the account
+            let mut batches: Vec<&[u64]> = content_ids.chunks(batch_size.max(1)).collect();
+            if batches.is_empty() {
+                batches.push(&[]);
+            }
+            let results = batches
+                .into_iter()
+                .map(|batch| session.send(&[instructions::migrate_creator_content(&creator, batch)]))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(json!(results))
+        }
+        Command::Creator(CreatorCommand::Show { creator }) => {
+            let address = pda::creator(&creator.map_or_else(me, Ok)?).0;
+            let account = session.fetch_required(&address, "creator account")?;
+            Ok(output::creator_account(&address, &account))
+        }
+
+        Command::Content(ContentCommand::Add { title, price, encrypted_cid, mint, transfer_fee_payer, max_supply }) => {
+            // The new item's PDA is derived from the ID the program is about to assign.
+            let creator_account: auton_client::CreatorAccount =
+                session.fetch_required(&pda::creator(&me()?).0, "creator account")?;
+            let content_id = creator_account.last_content_id + 1;
+            let args = AddContentArgs {
+                title,
+                price,
+                encrypted_cid: parse_hex(&encrypted_cid)?,
+                payment_mint: mint,
+                transfer_fee_payer: transfer_fee_payer.into(),
+            };
+            let mut batch = vec![instructions::add_content(&me()?, &me()?, content_id, args)];
+            // Capped in the same transaction, so no copies can be bought before the cap applies.
+            if let Some(max_supply) = max_supply {
+                batch.push(instructions::set_max_supply(&me()?, content_id, max_supply));
+            }
+            let mut result = session.send(&batch)?;
+            result["content_id"] = json!(content_id);
+            Ok(result)
+        }
+        Command::Content(ContentCommand::Update { content_id, title, price, encrypted_cid }) => {
+            let encrypted_cid = encrypted_cid.as_deref().map(parse_hex).transpose()?;
+            session.send(&[instructions::update_content(&me()?, content_id, title, price, encrypted_cid)])
+        }
+        Command::Content(ContentCommand::Unlist { content_id }) => {
+            session.send(&[instructions::unlist_content(&me()?, content_id)])
+        }
+        Command::Content(ContentCommand::Relist { content_id }) => {
+            session.send(&[instructions::relist_content(&me()?, content_id)])
+        }
+        Command::Content(ContentCommand::PayWhatYouWant { content_id, off }) => {
+            let item: auton_client::ContentItem =
+                session.fetch_required(&pda::content(&me()?, content_id).0, "content item")?;
+            session.send(&[instructions::set_pay_what_you_want(
+                &me()?,
+                content_id,
+                !off,
+                item.payment_mint.as_ref(),
+            )])
+        }
+        Command::Content(ContentCommand::MaxSupply { content_id, max_supply }) => {
+            session.send(&[instructions::set_max_supply(&me()?, content_id, max_supply)])
+        }
+        Command::Content(ContentCommand::Rental { content_id, price, duration, off }) => {
+            // clap requires both terms unless `--off` is passed.
+            let rental = price.zip(duration).filter(|_| !off).map(|(price, duration)| RentalTerms { price, duration });
+            session.send(&[instructions::set_rental_terms(&me()?, content_id, rental)])
+        }
+        Command::Content(ContentCommand::List { creator }) => {
+            let creator = creator.map_or_else(me, Ok)?;
+            let creator_account: auton_client::CreatorAccount =
+                session.fetch_required(&pda::creator(&creator).0, "creator account")?;
+            let addresses: Vec<Pubkey> = (1..=creator_account.last_content_id)
+                .map(|content_id| pda::content(&creator, content_id).0)
+                .collect();
+
+            let mut items = Vec::with_capacity(addresses.len());
+            for chunk in addresses.chunks(100) {
+                let fetched = session.client.get_multiple_accounts(chunk)?;
+                for (address, account) in chunk.iter().zip(fetched) {
+                    let Some(account) = account else { continue };
+                    let item = accounts::decode_content_item(&account.data)
+                        .map_err(|err| anyhow!("failed to decode {address}: {err}"))?;
+                    items.push(output::content_item(address, &item));
+                }
+            }
+            Ok(json!({ "creator": creator.to_string(), "content": items }))
+        }
+
+        Command::Profile(ProfileCommand::Update { profile_cid }) => {
+            session.send(&[instructions::update_profile(&me()?, &profile_cid)])
+        }
+
+        Command::Split(SplitCommand::Create { split }) => {
+            let content_id = split.content_id.unwrap_or(CREATOR_DEFAULT_SPLIT);
+            session.send(&[instructions::create_revenue_split(&me()?, &me()?, content_id, split.recipients)])
+        }
+        Command::Split(SplitCommand::Update { split }) => {
+            let content_id = split.content_id.unwrap_or(CREATOR_DEFAULT_SPLIT);
+            session.send(&[instructions::update_revenue_split(&me()?, content_id, split.recipients)])
+        }
+        Command::Split(SplitCommand::Remove { content_id }) => {
+            let content_id = content_id.unwrap_or(CREATOR_DEFAULT_SPLIT);
+            session.send(&[instructions::close_revenue_split(&me()?, content_id)])
+        }
+        Command::Split(SplitCommand::Show { creator, content_id }) => {
+            let creator = creator.map_or_else(me, Ok)?;
+            let address = pda::revenue_split(&creator, content_id.unwrap_or(CREATOR_DEFAULT_SPLIT)).0;
+            let split = session.fetch_required(&address, "revenue split")?;
+            Ok(output::revenue_split(&address, &split))
+        }
+
+        Command::Referral(ReferralCommand::SetShare { affiliate_bps }) => {
+            session.send(&[instructions::set_affiliate_share(&me()?, affiliate_bps)])
+        }
+        Command::Referral(ReferralCommand::SetContentShare { content_id, affiliate_bps }) => {
+            session.send(&[instructions::set_content_affiliate_share(&me()?, content_id, affiliate_bps)])
+        }
+        Command::Referral(ReferralCommand::Register { mint }) => {
+            session.send(&[instructions::register_referrer(&me()?, &me()?, mint)])
+        }
+        Command::Referral(ReferralCommand::Show { referrer, mint }) => {
+            let address = pda::referrer_stats(&referrer.map_or_else(me, Ok)?, mint.as_ref()).0;
+            let stats = session.fetch_required(&address, "referrer stats")?;
+            Ok(output::referrer_stats(&address, &stats))
+        }
+
+        Command::Purchase(PurchaseArgs { creator, content_id, max_price, max_fee_bps, referrer, coupon }) => {
+            let item: auton_client::ContentItem =
+                session.fetch_required(&pda::content(&creator, content_id).0, "content item")?;
+            let token = session.token_payment(item.payment_mint)?;
+            let (coupon, discount_amount) = match coupon {
+                Some(code) => {
+                    let address = pda::coupon(&creator, &code).0;
+                    let coupon: Coupon = session.fetch_required(&address, "coupon")?;
+                    let payment = CouponPayment { coupon: address, per_buyer_limit: coupon.max_per_buyer.is_some() };
+                    (Some(payment), coupon.discount_on(item.price, item.payment_mint)?)
+                }
+                None => (None, 0),
+            };
+            let mut instruction = instructions::process_payment(
+                &me()?,
+                &creator,
+                content_id,
+                max_price.unwrap_or(item.price - discount_amount),
+                max_fee_bps,
+                referrer.as_ref(),
+                coupon.as_ref(),
+                token.as_ref(),
+            );
+            if let Some(split) = session.revenue_split(&creator, content_id)? {
+                instructions::add_split_recipients(&mut instruction, &split.recipients, token.as_ref());
+            }
+            let mut result = session.send(&[instruction])?;
+            result["receipt"] = json!(pda::receipt(&me()?, &creator, content_id).0.to_string());
+            Ok(result)
+        }
+
+        Command::Rent(RentArgs { creator, content_id, max_price, max_fee_bps }) => {
+            let item: auton_client::ContentItem =
+                session.fetch_required(&pda::content(&creator, content_id).0, "content item")?;
+            let rental = item.rental.context("content item isn't offered for rent")?;
+            let token = session.token_payment(item.payment_mint)?;
+            let address = pda::receipt(&me()?, &creator, content_id).0;
+            // A rental receipt already at the address is renewed, whether or not it has expired.
+            let renew = match session.fetch::<PaidAccessAccount>(&address)? {
+                Some(receipt) if receipt.expires_at.is_none() => bail!("signer already owns this content item"),
+                Some(_) => true,
+                None => false,
+            };
+            let build = if renew { instructions::renew_rental } else { instructions::rent_content };
+            let mut instruction = build(
+                &me()?,
+                &creator,
+                content_id,
+                max_price.unwrap_or(rental.price),
+                max_fee_bps,
+                token.as_ref(),
+            );
+            if let Some(split) = session.revenue_split(&creator, content_id)? {
+                instructions::add_split_recipients(&mut instruction, &split.recipients, token.as_ref());
+            }
+            let mut result = session.send(&[instruction])?;
+            result["receipt"] = json!(address.to_string());
+            result["renewal"] = json!(renew);
+            Ok(result)
+        }
+
+        Command::Receipt(ReceiptCommand::Check { creator, content_id, buyer }) => {
+            let buyer = buyer.map_or_else(me, Ok)?;
+            let now = session.now()?;
+            let address = pda::receipt(&buyer, &creator, content_id).0;
+            let receipt: Option<PaidAccessAccount> = session.fetch(&address)?;
+            if let Some(receipt) = &receipt {
+                if receipt.is_active(now) {
+                    return Ok(json!({ "has_access": true, "receipt": output::paid_access_account(&address, receipt) }));
+                }
+            }
+            if let Some((address, legacy_receipt)) = session.legacy_receipt_for(&buyer, &creator, content_id)? {
+                return Ok(json!({
+                    "has_access": true,
+                    "legacy_receipt": output::legacy_receipt(&address, &legacy_receipt),
+                }));
+            }
+            // An expired rental is still shown, but only a bundle receipt or subscription can grant access.
+            let receipt = receipt.map_or(Value::Null, |receipt| output::paid_access_account(&address, &receipt));
+            if let Some((address, bundle_receipt)) = session.bundle_receipt_for(&buyer, &creator, content_id)? {
+                return Ok(json!({
+                    "has_access": true,
+                    "receipt": receipt,
+                    "bundle_receipt": output::bundle_receipt(&address, &bundle_receipt),
+                }));
+            }
+            Ok(match session.subscription_for(&buyer, &creator, content_id, now)? {
+                Some((address, subscription)) => json!({
+                    "has_access": true,
+                    "receipt": receipt,
+                    "subscription": output::subscription(&address, &subscription),
+                }),
+                None => json!({ "has_access": false, "receipt": receipt }),
+            })
+        }
+        Command::Receipt(ReceiptCommand::Migrate { creator, content_id, buyer }) => {
+            let buyer = buyer.map_or_else(me, Ok)?;
+            session.send(&[instructions::migrate_receipt(&buyer, &me()?, &creator, content_id)])
+        }
+
+        Command::Bundle(BundleCommand::Create { bundle_id, terms, mint, transfer_fee_payer }) => {
+            session.send(&[instructions::create_bundle(
+                &me()?,
+                &me()?,
+                bundle_id,
+                terms.into(),
+                mint,
+                transfer_fee_payer.into(),
+            )])
+        }
+        Command::Bundle(BundleCommand::Update { bundle_id, terms }) => {
+            session.send(&[instructions::update_bundle(&me()?, bundle_id, terms.into())])
+        }
+        Command::Bundle(BundleCommand::Remove { bundle_id }) => {
+            session.send(&[instructions::close_bundle(&me()?, bundle_id)])
+        }
+        Command::Bundle(BundleCommand::Show { creator, bundle_id }) => {
+            let address = pda::bundle(&creator.map_or_else(me, Ok)?, bundle_id).0;
+            let bundle = session.fetch_required(&address, "bundle")?;
+            Ok(output::bundle(&address, &bundle))
+        }
+        Command::Bundle(BundleCommand::Buy { creator, bundle_id, max_price, max_fee_bps }) => {
+            let bundle: Bundle = session.fetch_required(&pda::bundle(&creator, bundle_id).0, "bundle")?;
+            let token = session.token_payment(bundle.payment_mint)?;
+            let max_price = match max_price {
+                Some(max_price) => max_price,
+                None => bundle.sale_price()?,
+            };
+            let mut instruction = instructions::purchase_bundle(
+                &me()?,
+                &creator,
+                bundle_id,
+                &bundle.content_ids,
+                max_price,
+                max_fee_bps,
+                token.as_ref(),
+            );
+            // Content splits don't apply to bundles, only the creator's default split.
+            let default_split = pda::revenue_split(&creator, CREATOR_DEFAULT_SPLIT).0;
+            if let Some(split) = session.fetch::<RevenueSplit>(&default_split)? {
+                instructions::add_split_recipients(&mut instruction, &split.recipients, token.as_ref());
+            }
+            let mut result = session.send(&[instruction])?;
+            result["bundle_receipt"] = json!(pda::bundle_receipt(&me()?, &creator, bundle_id).0.to_string());
+            Ok(result)
+        }
+
+        Command::Tip(TipArgs { creator, amount, message, mint, max_fee_bps }) => {
+            let token = session.token_payment(mint)?;
+            session.send(&[instructions::tip_creator(
+                &me()?,
+                &creator,
+                amount,
+                max_fee_bps,
+                message.as_deref(),
+                token.as_ref(),
+            )])
+        }
+        Command::Supporters(SupportersCommand::Create { mint }) => {
+            session.send(&[instructions::create_supporter_stats(&me()?, &me()?, mint)])
+        }
+        Command::Supporters(SupportersCommand::Show { creator, mint }) => {
+            let address = pda::supporter_stats(&creator.map_or_else(me, Ok)?, mint.as_ref()).0;
+            let stats = session.fetch_required(&address, "supporter stats")?;
+            Ok(output::supporter_stats(&address, &stats))
+        }
+
+        Command::Coupon(CouponCommand::Create {
+            code,
+            discount,
+            max_redemptions,
+            max_per_buyer,
+            expires_at,
+            mint,
+        }) => {
+            let args = CouponArgs { discount: discount.into(), max_redemptions, max_per_buyer, expires_at };
+            session.send(&[instructions::create_coupon(&me()?, &me()?, &code, args, mint)])
+        }
+        Command::Coupon(CouponCommand::Remove { code }) => {
+            session.send(&[instructions::close_coupon(&me()?, &code)])
+        }
+        Command::Coupon(CouponCommand::Show { creator, code }) => {
+            let address = pda::coupon(&creator.map_or_else(me, Ok)?, &code).0;
+            let coupon = session.fetch_required(&address, "coupon")?;
+            Ok(output::coupon(&address, &coupon))
+        }
+        Command::Coupon(CouponCommand::Claim { creator, code }) => {
+            let coupon = pda::coupon(&creator, &code).0;
+            session.send(&[instructions::create_coupon_redemption(&me()?, &me()?, &coupon)])
+        }
+
+        Command::Subscription(SubscriptionCommand::CreateTier { tier_id, terms, mint, transfer_fee_payer }) => {
+            session.send(&[instructions::create_subscription_tier(
+                &me()?,
+                &me()?,
+                tier_id,
+                terms.into(),
+                mint,
+                transfer_fee_payer.into(),
+            )])
+        }
+        Command::Subscription(SubscriptionCommand::UpdateTier { tier_id, terms }) => {
+            session.send(&[instructions::update_subscription_tier(&me()?, tier_id, terms.into())])
+        }
+        Command::Subscription(SubscriptionCommand::Subscribe { creator, tier_id, periods, max_price, max_fee_bps }) => {
+            let tier: SubscriptionTier =
+                session.fetch_required(&pda::subscription_tier(&creator, tier_id).0, "subscription tier")?;
+            let token = session.token_payment(tier.payment_mint)?;
+            let max_price = match max_price {
+                Some(max_price) => max_price,
+                None => tier.terms_for(periods)?.0,
+            };
+            session.send(&[instructions::subscribe(
+                &me()?,
+                &creator,
+                tier_id,
+                periods,
+                max_price,
+                max_fee_bps,
+                token.as_ref(),
+            )])
+        }
+        Command::Subscription(SubscriptionCommand::Renew { creator, tier_id, periods, max_price, max_fee_bps }) => {
+            let tier: SubscriptionTier =
+                session.fetch_required(&pda::subscription_tier(&creator, tier_id).0, "subscription tier")?;
+            let token = session.token_payment(tier.payment_mint)?;
+            let max_price = match max_price {
+                Some(max_price) => max_price,
+                None => tier.terms_for(periods)?.0,
+            };
+            session.send(&[instructions::renew_subscription(
+                &me()?,
+                &creator,
+                tier_id,
+                periods,
+                max_price,
+                max_fee_bps,
+                token.as_ref(),
+            )])
+        }
+    }
+}
+
+impl From<DiscountArgs> for CouponDiscount {
+    fn from(discount: DiscountArgs) -> Self {
+        // clap's group makes exactly one of them set.
+        match (discount.percent_off_bps, discount.amount_off) {
+            (Some(bps), _) => CouponDiscount::PercentOff { bps },
+            (None, amount) => CouponDiscount::AmountOff { amount: amount.unwrap_or_default() },
+        }
+    }
+}
+
+impl From<BundleTerms> for BundleArgs {
+    fn from(terms: BundleTerms) -> Self {
+        BundleArgs {
+            content_ids: terms.content_ids,
+            price: terms.price,
+            discount_bps: terms.discount_bps,
+        }
+    }
+}
+
+impl From<TierTerms> for SubscriptionTierArgs {
+    fn from(terms: TierTerms) -> Self {
+        SubscriptionTierArgs {
+            price: terms.price,
+            period_seconds: terms.period_seconds,
+            content_ids: terms.content_ids,
+        }
+    }
+}
+
+fn parse_hex(value: &str) -> Result<Vec<u8>> {
+    hex::decode(value.trim_start_matches("0x")).context("encrypted CID must be hex")
+}
+
+// Parses a `min_sales:fee_bps` pair for `config set-fee-tiers`.
+fn parse_fee_tier(value: &str) -> std::result::Result<FeeTier, String> {
+    let (min_sales, fee_bps) = value
+        .split_once(':')
+        .ok_or_else(|| format!("expected MIN_SALES:FEE_BPS, got `{value}`"))?;
+    Ok(FeeTier {
+        min_sales: min_sales.parse().map_err(|err| format!("invalid min sales `{min_sales}`: {err}"))?,
+        fee_bps: fee_bps.parse().map_err(|err| format!("invalid fee bps `{fee_bps}`: {err}"))?,
+    })
+}
+
+// Parses a `wallet:share_bps` pair for `split create` and `split update`.
+fn parse_split_recipient(value: &str) -> std::result::Result<SplitRecipient, String> {
+    let (wallet, share_bps) = value
+        .split_once(':')
+        .ok_or_else(|| format!("expected WALLET:SHARE_BPS, got `{value}`"))?;
+    Ok(SplitRecipient {
+        wallet: wallet.parse().map_err(|err| format!("invalid wallet `{wallet}`: {err}"))?,
+        share_bps: share_bps.parse().map_err(|err| format!("invalid share bps `{share_bps}`: {err}"))?,
+    })
+}
+
+// Expands a leading `~/` the way a shell would, so the default keypair path works.
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
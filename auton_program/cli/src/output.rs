@@ -0,0 +1,229 @@
+// JSON views of the program's accounts for CLI output.
+// Amounts stay integers in lamports or token base units; keys are base58 strings.
+
+use auton_client::{
+    Bundle, BundleReceipt, ContentItem, Coupon, CouponDiscount, CreatorAccount, FeeOverride, LegacyPaidAccessAccount,
+    PaidAccessAccount, ProtocolConfig, ReferrerStats, RevenueSplit, Subscription, SupporterStats, UsernameAccount,
+};
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+
+fn optional_key(key: &Option<Pubkey>) -> Value {
+    key.map_or(Value::Null, |key| json!(key.to_string()))
+}
+
+// Names of the instruction groups set in a `ProtocolConfig::paused` mask.
+fn paused_flags(paused: u8) -> Value {
+    let names = [
+        (auton_client::PAUSE_PAYMENTS, "payments"),
+        (auton_client::PAUSE_ADD_CONTENT, "add_content"),
+        (auton_client::PAUSE_REGISTER_USERNAME, "register_username"),
+        (auton_client::PAUSE_INITIALIZE_CREATOR, "initialize_creator"),
+    ];
+    json!(names
+        .iter()
+        .filter(|(flag, _)| paused & flag != 0)
+        .map(|(_, name)| *name)
+        .collect::<Vec<_>>())
+}
+
+pub fn protocol_config(address: &Pubkey, config: &ProtocolConfig) -> Value {
+    json!({
+        "address": address.to_string(),
+        "admin_wallet": config.admin_wallet.to_string(),
+        "fee_bps": config.fee_percentage,
+        "fee_rounding": format!("{:?}", config.fee_rounding),
+        "pending_fee_bps": config.pending_fee_percentage,
+        "fee_effective_at": config.pending_fee_percentage.map(|_| config.fee_effective_at),
+        "max_fee_bps": config.max_fee_percentage,
+        "governance": config.governance.to_string(),
+        "fee_tiers": config
+            .fee_tiers
+            .iter()
+            .map(|tier| json!({ "min_sales": tier.min_sales, "fee_bps": tier.fee_bps }))
+            .collect::<Vec<_>>(),
+        "pending_admin": optional_key(&config.pending_admin),
+        "fee_recipient": config.fee_recipient.to_string(),
+        "guardian": config.guardian.to_string(),
+        "paused": paused_flags(config.paused),
+    })
+}
+
+pub fn username_account(address: &Pubkey, account: &UsernameAccount) -> Value {
+    json!({
+        "address": address.to_string(),
+        "username": account.username,
+        "authority": account.authority.to_string(),
+    })
+}
+
+pub fn creator_account(address: &Pubkey, account: &CreatorAccount) -> Value {
+    json!({
+        "address": address.to_string(),
+        "creator_wallet": account.creator_wallet.to_string(),
+        "last_content_id": account.last_content_id,
+        "profile_cid": account.profile_cid,
+        "sales_count": account.sales_count,
+        "affiliate_bps": account.affiliate_bps,
+    })
+}
+
+pub fn fee_override(address: &Pubkey, fee_override: &FeeOverride) -> Value {
+    json!({
+        "address": address.to_string(),
+        "creator": fee_override.creator.to_string(),
+        "fee_bps": fee_override.fee_bps,
+        "expires_at": fee_override.expires_at,
+    })
+}
+
+pub fn content_item(address: &Pubkey, item: &ContentItem) -> Value {
+    json!({
+        "address": address.to_string(),
+        "creator": item.creator.to_string(),
+        "id": item.id,
+        "title": item.title,
+        "price": item.price,
+        "payment_mint": optional_key(&item.payment_mint),
+        "transfer_fee_payer": format!("{:?}", item.transfer_fee_payer),
+        "listed": item.listed,
+        // null means the creator's affiliate share applies
+        "affiliate_bps": item.affiliate_bps,
+        // price is a minimum when true
+        "pay_what_you_want": item.pay_what_you_want,
+        // null means unlimited
+        "max_supply": item.max_supply,
+        "sold": item.sold,
+        // null means the item can't be rented
+        "rental": item.rental.map(|rental| json!({ "price": rental.price, "duration": rental.duration })),
+        "encrypted_cid": hex::encode(&item.encrypted_cid),
+    })
+}
+
+pub fn revenue_split(address: &Pubkey, split: &RevenueSplit) -> Value {
+    json!({
+        "address": address.to_string(),
+        "creator": split.creator.to_string(),
+        // 0 is the creator-wide default split
+        "content_id": split.content_id,
+        "recipients": split
+            .recipients
+            .iter()
+            .map(|recipient| json!({ "wallet": recipient.wallet.to_string(), "share_bps": recipient.share_bps }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+pub fn paid_access_account(address: &Pubkey, receipt: &PaidAccessAccount) -> Value {
+    json!({
+        "address": address.to_string(),
+        "buyer": receipt.buyer.to_string(),
+        "creator": receipt.creator.to_string(),
+        "content_id": receipt.content_id,
+        "created_at": receipt.created_at,
+        "price": receipt.price,
+        "fee_amount": receipt.fee_amount,
+        "creator_amount": receipt.creator_amount,
+        "payment_mint": optional_key(&receipt.payment_mint),
+        "referrer": optional_key(&receipt.referrer),
+        "referral_amount": receipt.referral_amount,
+        "coupon": optional_key(&receipt.coupon),
+        "discount_amount": receipt.discount_amount,
+        // null for purchases, which never expire
+        "expires_at": receipt.expires_at,
+        // numbered from 1 in purchase order; null for rentals
+        "edition": receipt.edition,
+    })
+}
+
+pub fn bundle(address: &Pubkey, bundle: &Bundle) -> Value {
+    json!({
+        "address": address.to_string(),
+        "creator": bundle.creator.to_string(),
+        "bundle_id": bundle.bundle_id,
+        "content_ids": bundle.content_ids,
+        "price": bundle.price,
+        "discount_bps": bundle.discount_bps,
+        // the price a buyer pays, after the discount
+        "sale_price": bundle.sale_price().ok(),
+        "payment_mint": optional_key(&bundle.payment_mint),
+        "transfer_fee_payer": format!("{:?}", bundle.transfer_fee_payer),
+    })
+}
+
+pub fn bundle_receipt(address: &Pubkey, receipt: &BundleReceipt) -> Value {
+    json!({
+        "address": address.to_string(),
+        "buyer": receipt.buyer.to_string(),
+        "creator": receipt.creator.to_string(),
+        "bundle_id": receipt.bundle_id,
+        "content_ids": receipt.content_ids,
+        "created_at": receipt.created_at,
+        "price": receipt.price,
+        "fee_amount": receipt.fee_amount,
+        "creator_amount": receipt.creator_amount,
+        "payment_mint": optional_key(&receipt.payment_mint),
+    })
+}
+
+pub fn supporter_stats(address: &Pubkey, stats: &SupporterStats) -> Value {
+    json!({
+        "address": address.to_string(),
+        "creator": stats.creator.to_string(),
+        "payment_mint": optional_key(&stats.payment_mint),
+        "tip_count": stats.tip_count,
+        "total_tipped": stats.total_tipped,
+        "pay_what_you_want_count": stats.pay_what_you_want_count,
+        "total_overpaid": stats.total_overpaid,
+    })
+}
+
+pub fn coupon(address: &Pubkey, coupon: &Coupon) -> Value {
+    let discount = match coupon.discount {
+        CouponDiscount::PercentOff { bps } => json!({ "percent_off_bps": bps }),
+        CouponDiscount::AmountOff { amount } => json!({ "amount_off": amount }),
+    };
+    json!({
+        "address": address.to_string(),
+        "creator": coupon.creator.to_string(),
+        "code": coupon.code,
+        "discount": discount,
+        "payment_mint": optional_key(&coupon.payment_mint),
+        "max_redemptions": coupon.max_redemptions,
+        "redemptions": coupon.redemptions,
+        "max_per_buyer": coupon.max_per_buyer,
+        "expires_at": coupon.expires_at,
+    })
+}
+
+pub fn referrer_stats(address: &Pubkey, stats: &ReferrerStats) -> Value {
+    json!({
+        "address": address.to_string(),
+        "referrer": stats.referrer.to_string(),
+        "payment_mint": optional_key(&stats.payment_mint),
+        "referral_count": stats.referral_count,
+        "total_earned": stats.total_earned,
+    })
+}
+
+// Legacy receipts only recorded who bought what and when.
+pub fn legacy_receipt(address: &Pubkey, receipt: &LegacyPaidAccessAccount) -> Value {
+    json!({
+        "address": address.to_string(),
+        "buyer": receipt.buyer.to_string(),
+        "creator": receipt.creator.to_string(),
+        "content_id": receipt.content_id,
+        "created_at": receipt.created_at,
+    })
+}
+
+pub fn subscription(address: &Pubkey, subscription: &Subscription) -> Value {
+    json!({
+        "address": address.to_string(),
+        "subscriber": subscription.subscriber.to_string(),
+        "creator": subscription.creator.to_string(),
+        "tier_id": subscription.tier_id,
+        "started_at": subscription.started_at,
+        "expires_at": subscription.expires_at,
+    })
+}
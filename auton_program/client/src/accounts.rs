@@ -0,0 +1,87 @@
+// Decoders for the program's accounts. Each checks the account discriminator
+// before deserializing, so passing the wrong account type is an error rather
+// than garbage.
+
+use anchor_lang::{AccountDeserialize, Result};
+use auton_program::{
+    Bundle, BundleReceipt, ContentItem, Coupon, CouponRedemption, CreatorAccount, FeeOverride, LegacyCreatorAccount,
+    LegacyPaidAccessAccount, PaidAccessAccount, ProtocolConfig, ReferrerStats, RevenueSplit, Subscription,
+    SubscriptionTier, SupporterStats, UsernameAccount,
+};
+
+// Decodes any `auton_program` account from its raw data.
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_protocol_config(data: &[u8]) -> Result<ProtocolConfig> {
+    decode(data)
+}
+
+pub fn decode_fee_override(data: &[u8]) -> Result<FeeOverride> {
+    decode(data)
+}
+
+pub fn decode_creator_account(data: &[u8]) -> Result<CreatorAccount> {
+    decode(data)
+}
+
+// A creator account still in the layout that kept content in a list, before
+// `migrate_creator_content`. Fails on a creator account that has been migrated.
+pub fn decode_legacy_creator_account(data: &[u8]) -> Result<LegacyCreatorAccount> {
+    LegacyCreatorAccount::try_from_data(data)
+}
+
+pub fn decode_username_account(data: &[u8]) -> Result<UsernameAccount> {
+    decode(data)
+}
+
+pub fn decode_content_item(data: &[u8]) -> Result<ContentItem> {
+    decode(data)
+}
+
+pub fn decode_revenue_split(data: &[u8]) -> Result<RevenueSplit> {
+    decode(data)
+}
+
+pub fn decode_paid_access_account(data: &[u8]) -> Result<PaidAccessAccount> {
+    decode(data)
+}
+
+// A receipt from before receipts were scoped per creator, at `pda::legacy_receipt` until
+// `migrate_receipt` moves it. It shares the `PaidAccessAccount` discriminator but not its layout.
+pub fn decode_legacy_receipt(data: &[u8]) -> Result<LegacyPaidAccessAccount> {
+    LegacyPaidAccessAccount::try_from_data(data)
+}
+
+pub fn decode_referrer_stats(data: &[u8]) -> Result<ReferrerStats> {
+    decode(data)
+}
+
+pub fn decode_supporter_stats(data: &[u8]) -> Result<SupporterStats> {
+    decode(data)
+}
+
+pub fn decode_bundle(data: &[u8]) -> Result<Bundle> {
+    decode(data)
+}
+
+pub fn decode_bundle_receipt(data: &[u8]) -> Result<BundleReceipt> {
+    decode(data)
+}
+
+pub fn decode_coupon(data: &[u8]) -> Result<Coupon> {
+    decode(data)
+}
+
+pub fn decode_coupon_redemption(data: &[u8]) -> Result<CouponRedemption> {
+    decode(data)
+}
+
+pub fn decode_subscription_tier(data: &[u8]) -> Result<SubscriptionTier> {
+    decode(data)
+}
+
+pub fn decode_subscription(data: &[u8]) -> Result<Subscription> {
+    decode(data)
+}
@@ -0,0 +1,985 @@
+// Instruction builders for every `auton_program` instruction.
+// Each builder derives the PDAs the instruction touches and returns an
+// `Instruction` ready to be put in a transaction and signed.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::{system_program, Id, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::memo::Memo;
+use auton_program::{
+    accounts, instruction, CouponDiscount, FeeRounding, FeeTier, RentalTerms, SplitRecipient, TransferFeePayer,
+    CREATOR_DEFAULT_SPLIT, ID as PROGRAM_ID,
+};
+
+use crate::pda;
+
+// The token a payment is made in, for content and tiers priced in an SPL or Token-2022 mint.
+// The payer's, creator's and treasury's token accounts are taken to be their associated token accounts.
+// The treasury's must already exist; create it with the treasury PDA as an off-curve owner.
+#[derive(Clone, Copy, Debug)]
+pub struct TokenPayment {
+    pub mint: Pubkey,
+    pub token_program: Pubkey, // spl_token or spl_token_2022, whichever owns the mint
+}
+
+// A coupon redeemed on a purchase. Coupons with a per-buyer limit also need the buyer's
+// redemption counter, created beforehand with `create_coupon_redemption`.
+#[derive(Clone, Copy, Debug)]
+pub struct CouponPayment {
+    pub coupon: Pubkey, // From `pda::coupon`
+    pub per_buyer_limit: bool, // Whether the coupon has a `max_per_buyer`
+}
+
+// Token accounts passed to a paid instruction, or all None for SOL payments.
+struct PaymentAccountKeys {
+    payment_mint: Option<Pubkey>,
+    payer_token_account: Option<Pubkey>,
+    creator_token_account: Option<Pubkey>,
+    treasury_token_account: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    memo_program: Option<Pubkey>,
+}
+
+impl PaymentAccountKeys {
+    fn new(payer: &Pubkey, creator_wallet: &Pubkey, token: Option<&TokenPayment>) -> Self {
+        let Some(token) = token else {
+            return Self {
+                payment_mint: None,
+                payer_token_account: None,
+                creator_token_account: None,
+                treasury_token_account: None,
+                token_program: None,
+                memo_program: None,
+            };
+        };
+        let ata = |wallet: &Pubkey| {
+            get_associated_token_address_with_program_id(wallet, &token.mint, &token.token_program)
+        };
+        Self {
+            payment_mint: Some(token.mint),
+            payer_token_account: Some(ata(payer)),
+            creator_token_account: Some(ata(creator_wallet)),
+            treasury_token_account: Some(ata(&pda::treasury().0)),
+            token_program: Some(token.token_program),
+            // Always passed so receiving accounts that require memos can be paid.
+            memo_program: Some(Memo::id()),
+        }
+    }
+}
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn initialize_config(admin: &Pubkey, initial_fee_percentage: u64) -> Instruction {
+    build(
+        accounts::InitializeConfig {
+            protocol_config: pda::config().0,
+            treasury: pda::treasury().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeConfig { initial_fee_percentage },
+    )
+}
+
+pub fn migrate_config(admin: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateConfig {
+            protocol_config: pda::config().0,
+            treasury: pda::treasury().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateConfig {},
+    )
+}
+
+pub fn update_config(
+    admin: &Pubkey,
+    new_fee_percentage: Option<u64>,
+    new_fee_rounding: Option<FeeRounding>,
+) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            protocol_config: pda::config().0,
+            admin: *admin,
+        },
+        instruction::UpdateConfig { new_fee_percentage, new_fee_rounding },
+    )
+}
+
+pub fn propose_admin(admin: &Pubkey, new_admin: &Pubkey) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            protocol_config: pda::config().0,
+            admin: *admin,
+        },
+        instruction::ProposeAdmin { new_admin: *new_admin },
+    )
+}
+
+// Must be signed by the proposed admin.
+pub fn accept_admin(new_admin: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptAdmin {
+            protocol_config: pda::config().0,
+            new_admin: *new_admin,
+        },
+        instruction::AcceptAdmin {},
+    )
+}
+
+pub fn cancel_admin_proposal(admin: &Pubkey) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            protocol_config: pda::config().0,
+            admin: *admin,
+        },
+        instruction::CancelAdminProposal {},
+    )
+}
+
+// Must be signed by the current fee recipient.
+pub fn set_fee_recipient(fee_recipient: &Pubkey, new_fee_recipient: &Pubkey) -> Instruction {
+    build(
+        accounts::SetFeeRecipient {
+            protocol_config: pda::config().0,
+            fee_recipient: *fee_recipient,
+        },
+        instruction::SetFeeRecipient { new_fee_recipient: *new_fee_recipient },
+    )
+}
+
+// Tiers must be ordered by strictly increasing `min_sales`; an empty list removes them.
+pub fn set_fee_tiers(admin: &Pubkey, fee_tiers: Vec<FeeTier>) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            protocol_config: pda::config().0,
+            admin: *admin,
+        },
+        instruction::SetFeeTiers { fee_tiers },
+    )
+}
+
+pub fn create_fee_override(admin: &Pubkey, creator: &Pubkey, fee_bps: u64, expires_at: Option<i64>) -> Instruction {
+    build(
+        accounts::CreateFeeOverride {
+            fee_override: pda::fee_override(creator).0,
+            protocol_config: pda::config().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::CreateFeeOverride { creator: *creator, fee_bps, expires_at },
+    )
+}
+
+pub fn update_fee_override(admin: &Pubkey, creator: &Pubkey, fee_bps: u64, expires_at: Option<i64>) -> Instruction {
+    build(
+        accounts::UpdateFeeOverride {
+            fee_override: pda::fee_override(creator).0,
+            protocol_config: pda::config().0,
+            admin: *admin,
+        },
+        instruction::UpdateFeeOverride { creator: *creator, fee_bps, expires_at },
+    )
+}
+
+pub fn close_fee_override(admin: &Pubkey, creator: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseFeeOverride {
+            fee_override: pda::fee_override(creator).0,
+            protocol_config: pda::config().0,
+            admin: *admin,
+        },
+        instruction::CloseFeeOverride { creator: *creator },
+    )
+}
+
+// Governance can set any cap; the admin can only lower it.
+pub fn set_max_fee_percentage(authority: &Pubkey, new_max_fee_percentage: u64) -> Instruction {
+    build(
+        accounts::SetMaxFeePercentage {
+            protocol_config: pda::config().0,
+            authority: *authority,
+        },
+        instruction::SetMaxFeePercentage { new_max_fee_percentage },
+    )
+}
+
+// Must be signed by the current governance key.
+pub fn set_governance(governance: &Pubkey, new_governance: &Pubkey) -> Instruction {
+    build(
+        accounts::SetGovernance {
+            protocol_config: pda::config().0,
+            governance: *governance,
+        },
+        instruction::SetGovernance { new_governance: *new_governance },
+    )
+}
+
+// `paused` is a mask of the program's `PAUSE_*` bits; 0 resumes everything.
+// Must be signed by the admin or the guardian.
+pub fn set_paused(authority: &Pubkey, paused: u8) -> Instruction {
+    build(
+        accounts::SetPaused {
+            protocol_config: pda::config().0,
+            authority: *authority,
+        },
+        instruction::SetPaused { paused },
+    )
+}
+
+pub fn set_guardian(admin: &Pubkey, new_guardian: &Pubkey) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            protocol_config: pda::config().0,
+            admin: *admin,
+        },
+        instruction::SetGuardian { new_guardian: *new_guardian },
+    )
+}
+
+// `fee_recipient` must be the config's current fee recipient. Pass `token` to withdraw
+// tokens from the treasury's associated token account instead of lamports.
+pub fn withdraw_treasury(
+    admin: &Pubkey,
+    fee_recipient: &Pubkey,
+    amount: u64,
+    token: Option<&TokenPayment>,
+) -> Instruction {
+    let treasury = pda::treasury().0;
+    let ata = |wallet: &Pubkey, token: &TokenPayment| {
+        get_associated_token_address_with_program_id(wallet, &token.mint, &token.token_program)
+    };
+    build(
+        accounts::WithdrawTreasury {
+            protocol_config: pda::config().0,
+            treasury,
+            fee_recipient: *fee_recipient,
+            admin: *admin,
+            payment_mint: token.map(|token| token.mint),
+            treasury_token_account: token.map(|token| ata(&treasury, token)),
+            recipient_token_account: token.map(|token| ata(fee_recipient, token)),
+            token_program: token.map(|token| token.token_program),
+            memo_program: token.map(|_| Memo::id()),
+        },
+        instruction::WithdrawTreasury { amount },
+    )
+}
+
+// `payer` covers the rent and can be the creator or a relayer.
+pub fn register_username(creator: &Pubkey, payer: &Pubkey, username: &str) -> Instruction {
+    build(
+        accounts::RegisterUsername {
+            username_account: pda::username(username).0,
+            protocol_config: pda::config().0,
+            creator: *creator,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterUsername { username: username.to_string() },
+    )
+}
+
+pub fn initialize_creator(creator: &Pubkey, payer: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeCreator {
+            creator_account: pda::creator(creator).0,
+            protocol_config: pda::config().0,
+            creator: *creator,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeCreator {},
+    )
+}
+
+// Moves the legacy content items `content_ids` (the next ones in the creator account's legacy
+// list, in list order) into their own content accounts.
+pub fn migrate_creator_content(creator: &Pubkey, content_ids: &[u64]) -> Instruction {
+    let mut instruction = build(
+        accounts::MigrateCreatorContent {
+            creator_account: pda::creator(creator).0,
+            creator: *creator,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateCreatorContent {},
+    );
+    instruction.accounts.extend(
+        content_ids
+            .iter()
+            .map(|&content_id| AccountMeta::new(pda::content(creator, content_id).0, false)),
+    );
+    instruction
+}
+
+// Arguments for `add_content`.
+#[derive(Clone, Debug)]
+pub struct AddContentArgs {
+    pub title: String,
+    pub price: u64,
+    pub encrypted_cid: Vec<u8>,
+    pub payment_mint: Option<Pubkey>, // None = priced in lamports
+    pub transfer_fee_payer: TransferFeePayer,
+}
+
+// `content_id` must be the ID the program will assign, i.e. the creator
+// account's `last_content_id + 1`, since the content PDA is derived from it.
+pub fn add_content(creator: &Pubkey, payer: &Pubkey, content_id: u64, args: AddContentArgs) -> Instruction {
+    build(
+        accounts::AddContent {
+            creator_account: pda::creator(creator).0,
+            content_item: pda::content(creator, content_id).0,
+            protocol_config: pda::config().0,
+            creator: *creator,
+            payer: *payer,
+            payment_mint: args.payment_mint,
+            system_program: system_program::ID,
+        },
+        instruction::AddContent {
+            title: args.title,
+            price: args.price,
+            encrypted_cid: args.encrypted_cid,
+            transfer_fee_payer: args.transfer_fee_payer,
+        },
+    )
+}
+
+// Fields passed as None are left unchanged.
+pub fn update_content(
+    creator: &Pubkey,
+    content_id: u64,
+    title: Option<String>,
+    price: Option<u64>,
+    encrypted_cid: Option<Vec<u8>>,
+) -> Instruction {
+    build(
+        accounts::UpdateContent {
+            content_item: pda::content(creator, content_id).0,
+            creator: *creator,
+            system_program: system_program::ID,
+        },
+        instruction::UpdateContent { content_id, title, price, encrypted_cid },
+    )
+}
+
+pub fn unlist_content(creator: &Pubkey, content_id: u64) -> Instruction {
+    build(
+        accounts::SetContentListing {
+            content_item: pda::content(creator, content_id).0,
+            creator: *creator,
+        },
+        instruction::UnlistContent { content_id },
+    )
+}
+
+pub fn relist_content(creator: &Pubkey, content_id: u64) -> Instruction {
+    build(
+        accounts::SetContentListing {
+            content_item: pda::content(creator, content_id).0,
+            creator: *creator,
+        },
+        instruction::RelistContent { content_id },
+    )
+}
+
+// The cap can only be lowered once set, and never below the copies already sold.
+pub fn set_max_supply(creator: &Pubkey, content_id: u64, max_supply: u64) -> Instruction {
+    build(
+        accounts::SetContentListing {
+            content_item: pda::content(creator, content_id).0,
+            creator: *creator,
+        },
+        instruction::SetMaxSupply { content_id, max_supply },
+    )
+}
+
+// Pass None to stop renting the item out.
+pub fn set_rental_terms(creator: &Pubkey, content_id: u64, rental: Option<RentalTerms>) -> Instruction {
+    build(
+        accounts::SetContentListing {
+            content_item: pda::content(creator, content_id).0,
+            creator: *creator,
+        },
+        instruction::SetRentalTerms { content_id, rental },
+    )
+}
+
+// `payment_mint` is the content item's mint, or None for SOL-priced content; turning
+// pay-what-you-want on needs the creator's supporter stats for it.
+pub fn set_pay_what_you_want(
+    creator: &Pubkey,
+    content_id: u64,
+    enabled: bool,
+    payment_mint: Option<&Pubkey>,
+) -> Instruction {
+    build(
+        accounts::SetPayWhatYouWant {
+            content_item: pda::content(creator, content_id).0,
+            supporter_stats: pda::supporter_stats(creator, payment_mint).0,
+            creator: *creator,
+        },
+        instruction::SetPayWhatYouWant { content_id, enabled },
+    )
+}
+
+pub fn update_profile(creator: &Pubkey, profile_cid: &str) -> Instruction {
+    build(
+        accounts::UpdateProfile {
+            creator_account: pda::creator(creator).0,
+            creator: *creator,
+            system_program: system_program::ID,
+        },
+        instruction::UpdateProfile { profile_cid: profile_cid.to_string() },
+    )
+}
+
+// Pass `token` for content priced in a mint, `referrer` to pay a registered referrer the
+// content's affiliate share and `coupon` to redeem one of the creator's coupons. If the content
+// or its creator has a revenue split, add its recipients with `add_split_recipients`.
+// For pay-what-you-want content, `max_price` is the amount paid.
+pub fn process_payment(
+    buyer: &Pubkey,
+    creator_wallet: &Pubkey,
+    content_id: u64,
+    max_price: u64,
+    max_fee_bps: Option<u64>,
+    referrer: Option<&Pubkey>,
+    coupon: Option<&CouponPayment>,
+    token: Option<&TokenPayment>,
+) -> Instruction {
+    let payment = PaymentAccountKeys::new(buyer, creator_wallet, token);
+    let payment_mint = token.map(|token| token.mint);
+    build(
+        accounts::ProcessPayment {
+            paid_access_account: pda::receipt(buyer, creator_wallet, content_id).0,
+            protocol_config: pda::config().0,
+            creator_account: pda::creator(creator_wallet).0,
+            content_item: pda::content(creator_wallet, content_id).0,
+            creator_wallet: *creator_wallet,
+            treasury: pda::treasury().0,
+            fee_override: pda::fee_override(creator_wallet).0,
+            content_split: pda::revenue_split(creator_wallet, content_id).0,
+            creator_split: pda::revenue_split(creator_wallet, CREATOR_DEFAULT_SPLIT).0,
+            supporter_stats: pda::supporter_stats(creator_wallet, payment_mint.as_ref()).0,
+            buyer: *buyer,
+            system_program: system_program::ID,
+            payment_mint: payment.payment_mint,
+            buyer_token_account: payment.payer_token_account,
+            creator_token_account: payment.creator_token_account,
+            treasury_token_account: payment.treasury_token_account,
+            token_program: payment.token_program,
+            memo_program: payment.memo_program,
+            referrer_stats: referrer.map(|referrer| pda::referrer_stats(referrer, payment_mint.as_ref()).0),
+            // The wallet is paid for SOL; its associated token account for tokens.
+            referrer: referrer.filter(|_| token.is_none()).copied(),
+            referrer_token_account: referrer.zip(token).map(|(referrer, token)| {
+                get_associated_token_address_with_program_id(referrer, &token.mint, &token.token_program)
+            }),
+            coupon: coupon.map(|coupon| coupon.coupon),
+            coupon_redemption: coupon
+                .filter(|coupon| coupon.per_buyer_limit)
+                .map(|coupon| pda::coupon_redemption(&coupon.coupon, buyer).0),
+        },
+        instruction::ProcessPayment { content_id, max_price, max_fee_bps },
+    )
+}
+
+// Pass `token` for content priced in a mint. If the content or its creator has a revenue
+// split, add its recipients with `add_split_recipients`, as for `process_payment`.
+pub fn rent_content(
+    buyer: &Pubkey,
+    creator_wallet: &Pubkey,
+    content_id: u64,
+    max_price: u64,
+    max_fee_bps: Option<u64>,
+    token: Option<&TokenPayment>,
+) -> Instruction {
+    let payment = PaymentAccountKeys::new(buyer, creator_wallet, token);
+    build(
+        accounts::RentContent {
+            paid_access_account: pda::receipt(buyer, creator_wallet, content_id).0,
+            creator_account: pda::creator(creator_wallet).0,
+            content_item: pda::content(creator_wallet, content_id).0,
+            protocol_config: pda::config().0,
+            creator_wallet: *creator_wallet,
+            treasury: pda::treasury().0,
+            fee_override: pda::fee_override(creator_wallet).0,
+            content_split: pda::revenue_split(creator_wallet, content_id).0,
+            creator_split: pda::revenue_split(creator_wallet, CREATOR_DEFAULT_SPLIT).0,
+            buyer: *buyer,
+            system_program: system_program::ID,
+            payment_mint: payment.payment_mint,
+            buyer_token_account: payment.payer_token_account,
+            creator_token_account: payment.creator_token_account,
+            treasury_token_account: payment.treasury_token_account,
+            token_program: payment.token_program,
+            memo_program: payment.memo_program,
+        },
+        instruction::RentContent { content_id, max_price, max_fee_bps },
+    )
+}
+
+// Extends the buyer's rental, or rents the item again once it has expired.
+pub fn renew_rental(
+    buyer: &Pubkey,
+    creator_wallet: &Pubkey,
+    content_id: u64,
+    max_price: u64,
+    max_fee_bps: Option<u64>,
+    token: Option<&TokenPayment>,
+) -> Instruction {
+    let payment = PaymentAccountKeys::new(buyer, creator_wallet, token);
+    build(
+        accounts::RenewRental {
+            paid_access_account: pda::receipt(buyer, creator_wallet, content_id).0,
+            creator_account: pda::creator(creator_wallet).0,
+            content_item: pda::content(creator_wallet, content_id).0,
+            protocol_config: pda::config().0,
+            creator_wallet: *creator_wallet,
+            treasury: pda::treasury().0,
+            fee_override: pda::fee_override(creator_wallet).0,
+            content_split: pda::revenue_split(creator_wallet, content_id).0,
+            creator_split: pda::revenue_split(creator_wallet, CREATOR_DEFAULT_SPLIT).0,
+            buyer: *buyer,
+            system_program: system_program::ID,
+            payment_mint: payment.payment_mint,
+            buyer_token_account: payment.payer_token_account,
+            creator_token_account: payment.creator_token_account,
+            treasury_token_account: payment.treasury_token_account,
+            token_program: payment.token_program,
+            memo_program: payment.memo_program,
+        },
+        instruction::RenewRental { content_id, max_price, max_fee_bps },
+    )
+}
+
+// Appends the recipients of the revenue split a purchase pays, in the split's order.
+// Pass the same `token` as the purchase: recipients are paid at their associated token accounts.
+pub fn add_split_recipients(instruction: &mut Instruction, recipients: &[SplitRecipient], token: Option<&TokenPayment>) {
+    instruction.accounts.extend(recipients.iter().map(|recipient| {
+        let destination = match token {
+            Some(token) => get_associated_token_address_with_program_id(
+                &recipient.wallet,
+                &token.mint,
+                &token.token_program,
+            ),
+            None => recipient.wallet,
+        };
+        AccountMeta::new(destination, false)
+    }));
+}
+
+// `affiliate_bps` of each sale goes to its referrer, unless the content item sets its own share.
+pub fn set_affiliate_share(creator: &Pubkey, affiliate_bps: u64) -> Instruction {
+    build(
+        accounts::SetAffiliateShare { creator_account: pda::creator(creator).0, creator: *creator },
+        instruction::SetAffiliateShare { affiliate_bps },
+    )
+}
+
+// Pass None to fall back to the creator's affiliate share.
+pub fn set_content_affiliate_share(creator: &Pubkey, content_id: u64, affiliate_bps: Option<u64>) -> Instruction {
+    build(
+        accounts::SetContentAffiliateShare {
+            content_item: pda::content(creator, content_id).0,
+            creator: *creator,
+        },
+        instruction::SetContentAffiliateShare { content_id, affiliate_bps },
+    )
+}
+
+// Creates `creator`'s supporter stats for `payment_mint`, or for SOL when None.
+pub fn create_supporter_stats(creator: &Pubkey, payer: &Pubkey, payment_mint: Option<Pubkey>) -> Instruction {
+    build(
+        accounts::CreateSupporterStats {
+            supporter_stats: pda::supporter_stats(creator, payment_mint.as_ref()).0,
+            creator: *creator,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::CreateSupporterStats { payment_mint },
+    )
+}
+
+// Pass `token` to tip in a mint rather than SOL. The creator must have supporter stats for it.
+pub fn tip_creator(
+    tipper: &Pubkey,
+    creator_wallet: &Pubkey,
+    amount: u64,
+    max_fee_bps: Option<u64>,
+    message: Option<&str>,
+    token: Option<&TokenPayment>,
+) -> Instruction {
+    let payment = PaymentAccountKeys::new(tipper, creator_wallet, token);
+    let payment_mint = token.map(|token| token.mint);
+    build(
+        accounts::TipCreator {
+            protocol_config: pda::config().0,
+            creator_account: pda::creator(creator_wallet).0,
+            creator_wallet: *creator_wallet,
+            treasury: pda::treasury().0,
+            fee_override: pda::fee_override(creator_wallet).0,
+            supporter_stats: pda::supporter_stats(creator_wallet, payment_mint.as_ref()).0,
+            tipper: *tipper,
+            system_program: system_program::ID,
+            payment_mint: payment.payment_mint,
+            tipper_token_account: payment.payer_token_account,
+            creator_token_account: payment.creator_token_account,
+            treasury_token_account: payment.treasury_token_account,
+            token_program: payment.token_program,
+            memo_program: payment.memo_program,
+        },
+        instruction::TipCreator { amount, max_fee_bps, message: message.map(str::to_string) },
+    )
+}
+
+// Registers `referrer` for referral payouts in `payment_mint`, or in SOL when None.
+pub fn register_referrer(referrer: &Pubkey, payer: &Pubkey, payment_mint: Option<Pubkey>) -> Instruction {
+    build(
+        accounts::RegisterReferrer {
+            referrer_stats: pda::referrer_stats(referrer, payment_mint.as_ref()).0,
+            referrer: *referrer,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterReferrer { payment_mint },
+    )
+}
+
+// Arguments for `create_coupon`.
+#[derive(Clone, Copy, Debug)]
+pub struct CouponArgs {
+    pub discount: CouponDiscount,
+    pub max_redemptions: Option<u64>,
+    pub max_per_buyer: Option<u64>,
+    pub expires_at: Option<i64>,
+}
+
+// Pass `payment_mint` for an amount-off coupon in a token; amounts are in lamports otherwise.
+pub fn create_coupon(
+    creator: &Pubkey,
+    payer: &Pubkey,
+    code: &str,
+    args: CouponArgs,
+    payment_mint: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::CreateCoupon {
+            coupon: pda::coupon(creator, code).0,
+            creator: *creator,
+            payer: *payer,
+            payment_mint,
+            system_program: system_program::ID,
+        },
+        instruction::CreateCoupon {
+            code: code.to_string(),
+            discount: args.discount,
+            max_redemptions: args.max_redemptions,
+            max_per_buyer: args.max_per_buyer,
+            expires_at: args.expires_at,
+        },
+    )
+}
+
+pub fn close_coupon(creator: &Pubkey, code: &str) -> Instruction {
+    build(
+        accounts::CloseCoupon { coupon: pda::coupon(creator, code).0, creator: *creator },
+        instruction::CloseCoupon { code: code.to_string() },
+    )
+}
+
+pub fn create_coupon_redemption(buyer: &Pubkey, payer: &Pubkey, coupon: &Pubkey) -> Instruction {
+    build(
+        accounts::CreateCouponRedemption {
+            coupon_redemption: pda::coupon_redemption(coupon, buyer).0,
+            coupon: *coupon,
+            buyer: *buyer,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::CreateCouponRedemption {},
+    )
+}
+
+// `content_id` is a content item's ID, or `CREATOR_DEFAULT_SPLIT` for the creator's default split.
+pub fn create_revenue_split(
+    creator: &Pubkey,
+    payer: &Pubkey,
+    content_id: u64,
+    recipients: Vec<SplitRecipient>,
+) -> Instruction {
+    build(
+        accounts::CreateRevenueSplit {
+            revenue_split: pda::revenue_split(creator, content_id).0,
+            creator_account: pda::creator(creator).0,
+            creator: *creator,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::CreateRevenueSplit { content_id, recipients },
+    )
+}
+
+pub fn update_revenue_split(creator: &Pubkey, content_id: u64, recipients: Vec<SplitRecipient>) -> Instruction {
+    build(
+        accounts::UpdateRevenueSplit {
+            revenue_split: pda::revenue_split(creator, content_id).0,
+            creator: *creator,
+        },
+        instruction::UpdateRevenueSplit { content_id, recipients },
+    )
+}
+
+pub fn close_revenue_split(creator: &Pubkey, content_id: u64) -> Instruction {
+    build(
+        accounts::CloseRevenueSplit {
+            revenue_split: pda::revenue_split(creator, content_id).0,
+            creator: *creator,
+        },
+        instruction::CloseRevenueSplit { content_id },
+    )
+}
+
+pub fn migrate_receipt(buyer: &Pubkey, payer: &Pubkey, creator_wallet: &Pubkey, content_id: u64) -> Instruction {
+    build(
+        accounts::MigrateReceipt {
+            legacy_access_account: pda::legacy_receipt(buyer, content_id).0,
+            paid_access_account: pda::receipt(buyer, creator_wallet, content_id).0,
+            buyer: *buyer,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateReceipt { content_id, creator: *creator_wallet },
+    )
+}
+
+// Arguments for `create_bundle` and `update_bundle`.
+#[derive(Clone, Debug)]
+pub struct BundleArgs {
+    pub content_ids: Vec<u64>,
+    pub price: u64,
+    pub discount_bps: Option<u64>,
+}
+
+// The content item accounts a bundle's items are checked against, in the order of its content IDs.
+fn bundle_content_items(creator: &Pubkey, content_ids: &[u64]) -> Vec<AccountMeta> {
+    content_ids
+        .iter()
+        .map(|&content_id| AccountMeta::new_readonly(pda::content(creator, content_id).0, false))
+        .collect()
+}
+
+pub fn create_bundle(
+    creator: &Pubkey,
+    payer: &Pubkey,
+    bundle_id: u8,
+    args: BundleArgs,
+    payment_mint: Option<Pubkey>,
+    transfer_fee_payer: TransferFeePayer,
+) -> Instruction {
+    let content_items = bundle_content_items(creator, &args.content_ids);
+    let mut instruction = build(
+        accounts::CreateBundle {
+            bundle: pda::bundle(creator, bundle_id).0,
+            creator_account: pda::creator(creator).0,
+            creator: *creator,
+            payer: *payer,
+            payment_mint,
+            system_program: system_program::ID,
+        },
+        instruction::CreateBundle {
+            bundle_id,
+            content_ids: args.content_ids,
+            price: args.price,
+            discount_bps: args.discount_bps,
+            transfer_fee_payer,
+        },
+    );
+    instruction.accounts.extend(content_items);
+    instruction
+}
+
+pub fn update_bundle(creator: &Pubkey, bundle_id: u8, args: BundleArgs) -> Instruction {
+    let content_items = bundle_content_items(creator, &args.content_ids);
+    let mut instruction = build(
+        accounts::UpdateBundle {
+            bundle: pda::bundle(creator, bundle_id).0,
+            creator_account: pda::creator(creator).0,
+            creator: *creator,
+        },
+        instruction::UpdateBundle {
+            bundle_id,
+            content_ids: args.content_ids,
+            price: args.price,
+            discount_bps: args.discount_bps,
+        },
+    );
+    instruction.accounts.extend(content_items);
+    instruction
+}
+
+pub fn close_bundle(creator: &Pubkey, bundle_id: u8) -> Instruction {
+    build(
+        accounts::CloseBundle { bundle: pda::bundle(creator, bundle_id).0, creator: *creator },
+        instruction::CloseBundle { bundle_id },
+    )
+}
+
+// `content_ids` are the bundle's current content IDs. Pass `token` for bundles priced in a
+// mint. If the creator has a default revenue split, add its recipients with `add_split_recipients`.
+pub fn purchase_bundle(
+    buyer: &Pubkey,
+    creator_wallet: &Pubkey,
+    bundle_id: u8,
+    content_ids: &[u64],
+    max_price: u64,
+    max_fee_bps: Option<u64>,
+    token: Option<&TokenPayment>,
+) -> Instruction {
+    let payment = PaymentAccountKeys::new(buyer, creator_wallet, token);
+    let mut instruction = build(
+        accounts::PurchaseBundle {
+            bundle_receipt: pda::bundle_receipt(buyer, creator_wallet, bundle_id).0,
+            bundle: pda::bundle(creator_wallet, bundle_id).0,
+            protocol_config: pda::config().0,
+            creator_account: pda::creator(creator_wallet).0,
+            creator_wallet: *creator_wallet,
+            treasury: pda::treasury().0,
+            fee_override: pda::fee_override(creator_wallet).0,
+            creator_split: pda::revenue_split(creator_wallet, CREATOR_DEFAULT_SPLIT).0,
+            buyer: *buyer,
+            system_program: system_program::ID,
+            payment_mint: payment.payment_mint,
+            buyer_token_account: payment.payer_token_account,
+            creator_token_account: payment.creator_token_account,
+            treasury_token_account: payment.treasury_token_account,
+            token_program: payment.token_program,
+            memo_program: payment.memo_program,
+        },
+        instruction::PurchaseBundle { bundle_id, max_price, max_fee_bps },
+    );
+    instruction.accounts.extend(bundle_content_items(creator_wallet, content_ids));
+    instruction
+}
+
+// Arguments for `create_subscription_tier` and `update_subscription_tier`.
+#[derive(Clone, Debug)]
+pub struct SubscriptionTierArgs {
+    pub price: u64,
+    pub period_seconds: i64,
+    pub content_ids: Vec<u64>, // Empty = all of the creator's content
+}
+
+pub fn create_subscription_tier(
+    creator: &Pubkey,
+    payer: &Pubkey,
+    tier_id: u8,
+    args: SubscriptionTierArgs,
+    payment_mint: Option<Pubkey>,
+    transfer_fee_payer: TransferFeePayer,
+) -> Instruction {
+    build(
+        accounts::CreateSubscriptionTier {
+            subscription_tier: pda::subscription_tier(creator, tier_id).0,
+            creator_account: pda::creator(creator).0,
+            creator: *creator,
+            payer: *payer,
+            payment_mint,
+            system_program: system_program::ID,
+        },
+        instruction::CreateSubscriptionTier {
+            tier_id,
+            price: args.price,
+            period_seconds: args.period_seconds,
+            content_ids: args.content_ids,
+            transfer_fee_payer,
+        },
+    )
+}
+
+pub fn update_subscription_tier(creator: &Pubkey, tier_id: u8, args: SubscriptionTierArgs) -> Instruction {
+    build(
+        accounts::UpdateSubscriptionTier {
+            subscription_tier: pda::subscription_tier(creator, tier_id).0,
+            creator: *creator,
+        },
+        instruction::UpdateSubscriptionTier {
+            tier_id,
+            price: args.price,
+            period_seconds: args.period_seconds,
+            content_ids: args.content_ids,
+        },
+    )
+}
+
+// `max_price` is the most to pay for all `periods` periods together.
+pub fn subscribe(
+    subscriber: &Pubkey,
+    creator_wallet: &Pubkey,
+    tier_id: u8,
+    periods: u32,
+    max_price: u64,
+    max_fee_bps: Option<u64>,
+    token: Option<&TokenPayment>,
+) -> Instruction {
+    let payment = PaymentAccountKeys::new(subscriber, creator_wallet, token);
+    build(
+        accounts::Subscribe {
+            subscription: pda::subscription(subscriber, creator_wallet).0,
+            subscription_tier: pda::subscription_tier(creator_wallet, tier_id).0,
+            protocol_config: pda::config().0,
+            creator_account: pda::creator(creator_wallet).0,
+            creator_wallet: *creator_wallet,
+            treasury: pda::treasury().0,
+            fee_override: pda::fee_override(creator_wallet).0,
+            subscriber: *subscriber,
+            system_program: system_program::ID,
+            payment_mint: payment.payment_mint,
+            subscriber_token_account: payment.payer_token_account,
+            creator_token_account: payment.creator_token_account,
+            treasury_token_account: payment.treasury_token_account,
+            token_program: payment.token_program,
+            memo_program: payment.memo_program,
+        },
+        instruction::Subscribe { tier_id, periods, max_price, max_fee_bps },
+    )
+}
+
+pub fn renew_subscription(
+    subscriber: &Pubkey,
+    creator_wallet: &Pubkey,
+    tier_id: u8,
+    periods: u32,
+    max_price: u64,
+    max_fee_bps: Option<u64>,
+    token: Option<&TokenPayment>,
+) -> Instruction {
+    let payment = PaymentAccountKeys::new(subscriber, creator_wallet, token);
+    build(
+        accounts::RenewSubscription {
+            subscription: pda::subscription(subscriber, creator_wallet).0,
+            subscription_tier: pda::subscription_tier(creator_wallet, tier_id).0,
+            protocol_config: pda::config().0,
+            creator_account: pda::creator(creator_wallet).0,
+            creator_wallet: *creator_wallet,
+            treasury: pda::treasury().0,
+            fee_override: pda::fee_override(creator_wallet).0,
+            subscriber: *subscriber,
+            system_program: system_program::ID,
+            payment_mint: payment.payment_mint,
+            subscriber_token_account: payment.payer_token_account,
+            creator_token_account: payment.creator_token_account,
+            treasury_token_account: payment.treasury_token_account,
+            token_program: payment.token_program,
+            memo_program: payment.memo_program,
+        },
+        instruction::RenewSubscription { tier_id, periods, max_price, max_fee_bps },
+    )
+}
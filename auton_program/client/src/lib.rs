@@ -0,0 +1,17 @@
+//! Rust client for `auton_program`.
+//!
+//! - [`pda`] derives every program address from the same seeds the program uses.
+//! - [`instructions`] builds ready-to-sign instructions with the full account list.
+//! - [`accounts`] decodes the program's accounts from raw account data.
+
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+
+pub use auton_program::{
+    Bundle, BundleReceipt, ContentItem, Coupon, CouponDiscount, CouponRedemption, CreatorAccount, FeeOverride,
+    FeeRounding, FeeTier, LegacyCreatorAccount, LegacyPaidAccessAccount, PaidAccessAccount, ProtocolConfig,
+    ReferrerStats, RentalTerms, RevenueSplit, SplitRecipient, Subscription, SubscriptionTier, SupporterStats,
+    TransferFeePayer, UsernameAccount, CREATOR_DEFAULT_SPLIT, ID as PROGRAM_ID, PAUSE_ADD_CONTENT, PAUSE_ALL,
+    PAUSE_INITIALIZE_CREATOR, PAUSE_PAYMENTS, PAUSE_REGISTER_USERNAME,
+};
@@ -0,0 +1,129 @@
+// PDA derivation for every account owned by `auton_program`.
+// Seeds must stay in step with the `seeds = [...]` constraints in the program.
+
+use anchor_lang::prelude::Pubkey;
+use auton_program::ID as PROGRAM_ID;
+
+// The protocol's global configuration.
+pub fn config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &PROGRAM_ID)
+}
+
+// The program-owned treasury that collects platform fees.
+pub fn treasury() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"treasury"], &PROGRAM_ID)
+}
+
+// An admin-set platform fee for one creator.
+pub fn fee_override(creator_wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"fee_override", creator_wallet.as_ref()], &PROGRAM_ID)
+}
+
+// The registry entry for a username.
+pub fn username(username: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"username", username.as_bytes()], &PROGRAM_ID)
+}
+
+// A creator's profile and content counter.
+pub fn creator(creator_wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"creator", creator_wallet.as_ref()], &PROGRAM_ID)
+}
+
+// A single content item, by its per-creator ID.
+pub fn content(creator_wallet: &Pubkey, content_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"content", creator_wallet.as_ref(), &content_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+// A revenue split for one of a creator's content items, or the creator's default split
+// when `content_id` is `CREATOR_DEFAULT_SPLIT`.
+pub fn revenue_split(creator_wallet: &Pubkey, content_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"split", creator_wallet.as_ref(), &content_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+// A referrer's stats for payouts in `payment_mint`, or in SOL when None.
+pub fn referrer_stats(referrer: &Pubkey, payment_mint: Option<&Pubkey>) -> (Pubkey, u8) {
+    let mint = payment_mint.copied().unwrap_or_default();
+    Pubkey::find_program_address(&[b"referrer", referrer.as_ref(), mint.as_ref()], &PROGRAM_ID)
+}
+
+// A creator's supporter stats for tips and pay-what-you-want sales in `payment_mint`,
+// or in SOL when None.
+pub fn supporter_stats(creator_wallet: &Pubkey, payment_mint: Option<&Pubkey>) -> (Pubkey, u8) {
+    let mint = payment_mint.copied().unwrap_or_default();
+    Pubkey::find_program_address(&[b"supporters", creator_wallet.as_ref(), mint.as_ref()], &PROGRAM_ID)
+}
+
+// A buyer's access receipt for one of a creator's content items.
+pub fn receipt(buyer: &Pubkey, creator_wallet: &Pubkey, content_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"access",
+            buyer.as_ref(),
+            creator_wallet.as_ref(),
+            &content_id.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    )
+}
+
+// A receipt created before receipts were scoped per creator. See `migrate_receipt`.
+pub fn legacy_receipt(buyer: &Pubkey, content_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"access", buyer.as_ref(), &content_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+// One of a creator's subscription tiers.
+pub fn subscription_tier(creator_wallet: &Pubkey, tier_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"tier", creator_wallet.as_ref(), &[tier_id]],
+        &PROGRAM_ID,
+    )
+}
+
+// One of a creator's bundles.
+pub fn bundle(creator_wallet: &Pubkey, bundle_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"bundle", creator_wallet.as_ref(), &[bundle_id]],
+        &PROGRAM_ID,
+    )
+}
+
+// A buyer's receipt for one of a creator's bundles.
+pub fn bundle_receipt(buyer: &Pubkey, creator_wallet: &Pubkey, bundle_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"bundle_access", buyer.as_ref(), creator_wallet.as_ref(), &[bundle_id]],
+        &PROGRAM_ID,
+    )
+}
+
+// One of a creator's coupons, by its code.
+pub fn coupon(creator_wallet: &Pubkey, code: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"coupon", creator_wallet.as_ref(), code.as_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+// A buyer's redemption counter for a coupon with a per-buyer limit.
+pub fn coupon_redemption(coupon: &Pubkey, buyer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"coupon_redemption", coupon.as_ref(), buyer.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+// A subscriber's subscription to a creator.
+pub fn subscription(subscriber: &Pubkey, creator_wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"subscription", subscriber.as_ref(), creator_wallet.as_ref()],
+        &PROGRAM_ID,
+    )
+}
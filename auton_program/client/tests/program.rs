@@ -0,0 +1,2540 @@
+// In-process integration tests for auton_program.
+//
+// The compiled program is loaded into LiteSVM, so these run offline under `cargo test`
+// without a validator. Build the program first so target/deploy/auton_program.so exists:
+//
+//     anchor build && cargo test -p auton_client
+
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_2022::spl_token_2022::extension::memo_transfer::MemoTransfer;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensionsMut, ExtensionType, StateWithExtensions, StateWithExtensionsMut,
+};
+use anchor_spl::token_2022::spl_token_2022::state::{
+    Account as SplTokenAccount, AccountState, Mint as SplMint,
+};
+use auton_client::instructions::{
+    self, AddContentArgs, BundleArgs, CouponArgs, CouponPayment, SubscriptionTierArgs, TokenPayment,
+};
+use auton_client::{
+    accounts, pda, CouponDiscount, FeeRounding, FeeTier, RentalTerms, SplitRecipient, TransferFeePayer,
+    CREATOR_DEFAULT_SPLIT, PAUSE_ALL, PAUSE_PAYMENTS, PROGRAM_ID,
+};
+use auton_program::{ContentPurchased, CustomError, PaidAccessAccount};
+use base64::Engine;
+use litesvm::types::TransactionResult;
+use litesvm::LiteSVM;
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+const FEE_BPS: u64 = 500; // 5%
+const PRICE: u64 = LAMPORTS_PER_SOL;
+const FEE_INCREASE_NOTICE: i64 = 7 * 24 * 60 * 60;
+
+// ---------------------------------------------------------------------------
+// Harness
+// ---------------------------------------------------------------------------
+
+struct TestEnv {
+    svm: LiteSVM,
+    admin: Keypair,
+}
+
+impl TestEnv {
+    // A fresh SVM with the program loaded and no config yet.
+    fn uninitialized() -> Self {
+        let mut svm = LiteSVM::new();
+        let program_path = concat!(env!("CARGO_MANIFEST_DIR"), "/../target/deploy/auton_program.so");
+        svm.add_program_from_file(PROGRAM_ID, program_path)
+            .expect("target/deploy/auton_program.so is missing; run `anchor build` first");
+        let admin = Keypair::new();
+        svm.airdrop(&admin.pubkey(), 100 * LAMPORTS_PER_SOL).unwrap();
+        Self { svm, admin }
+    }
+
+    // A fresh SVM with the protocol config initialized at `FEE_BPS`.
+    fn new() -> Self {
+        let mut env = Self::uninitialized();
+        let admin = env.admin.insecure_clone();
+        env.send(&[instructions::initialize_config(&admin.pubkey(), FEE_BPS)], &[&admin])
+            .unwrap();
+        env
+    }
+
+    fn funded_wallet(&mut self) -> Keypair {
+        let wallet = Keypair::new();
+        self.svm.airdrop(&wallet.pubkey(), 100 * LAMPORTS_PER_SOL).unwrap();
+        wallet
+    }
+
+    // Sends `instructions` signed by `signers`; the first signer pays the transaction fee.
+    fn send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> TransactionResult {
+        // A new blockhash for every transaction so identical retries aren't rejected as duplicates.
+        self.svm.expire_blockhash();
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&signers[0].pubkey()),
+            signers,
+            self.svm.latest_blockhash(),
+        );
+        self.svm.send_transaction(transaction)
+    }
+
+    fn balance(&self, address: &Pubkey) -> u64 {
+        self.svm.get_account(address).map_or(0, |account| account.lamports)
+    }
+
+    fn fetch<T: anchor_lang::AccountDeserialize>(&self, address: &Pubkey) -> T {
+        let account = self.svm.get_account(address).expect("account does not exist");
+        accounts::decode(&account.data).unwrap()
+    }
+
+    fn exists(&self, address: &Pubkey) -> bool {
+        self.svm
+            .get_account(address)
+            .is_some_and(|account| account.lamports > 0)
+    }
+
+    fn warp_by(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn now(&self) -> i64 {
+        self.svm.get_sysvar::<Clock>().unix_timestamp
+    }
+
+    // A creator with an initialized creator account.
+    fn creator(&mut self) -> Keypair {
+        let creator = self.funded_wallet();
+        self.send(&[instructions::initialize_creator(&creator.pubkey(), &creator.pubkey())], &[&creator])
+            .unwrap();
+        creator
+    }
+
+    // Adds a SOL-priced content item for `creator` and returns its ID.
+    fn add_content(&mut self, creator: &Keypair, price: u64) -> u64 {
+        self.add_content_with(creator, sol_content(price)).unwrap()
+    }
+
+    fn add_content_with(&mut self, creator: &Keypair, args: AddContentArgs) -> Result<u64, TransactionError> {
+        let creator_account: auton_client::CreatorAccount = self.fetch(&pda::creator(&creator.pubkey()).0);
+        let content_id = creator_account.last_content_id + 1;
+        self.send(
+            &[instructions::add_content(&creator.pubkey(), &creator.pubkey(), content_id, args)],
+            &[creator],
+        )
+        .map(|_| content_id)
+        .map_err(|failed| failed.err)
+    }
+
+    fn purchase(&mut self, buyer: &Keypair, creator: &Pubkey, content_id: u64, max_price: u64) -> TransactionResult {
+        self.send(
+            &[instructions::process_payment(&buyer.pubkey(), creator, content_id, max_price, None, None, None, None)],
+            &[buyer],
+        )
+    }
+
+    // -- Tokens ------------------------------------------------------------
+    // Mints and token accounts are written straight into the SVM rather than created
+    // through the token program, which keeps each test focused on auton_program.
+
+    fn create_mint(&mut self, token_program: &Pubkey, decimals: u8) -> Pubkey {
+        let mint = Pubkey::new_unique();
+        let mut data = vec![0; SplMint::LEN];
+        SplMint {
+            mint_authority: COption::Some(self.admin.pubkey()),
+            supply: u64::MAX / 2,
+            decimals,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        self.set_program_account(mint, data, *token_program);
+        mint
+    }
+
+    // Creates `owner`'s associated token account for `token`, optionally requiring incoming memos.
+    fn create_token_account(&mut self, token: &TokenPayment, owner: &Pubkey, amount: u64, require_memo: bool) -> Pubkey {
+        let address = get_associated_token_address_with_program_id(owner, &token.mint, &token.token_program);
+        let base = SplTokenAccount {
+            mint: token.mint,
+            owner: *owner,
+            amount,
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+
+        let data = if require_memo {
+            let len = ExtensionType::try_calculate_account_len::<SplTokenAccount>(&[ExtensionType::MemoTransfer])
+                .unwrap();
+            let mut data = vec![0; len];
+            let mut state = StateWithExtensionsMut::<SplTokenAccount>::unpack_uninitialized(&mut data).unwrap();
+            state.base = base;
+            state.pack_base();
+            state.init_account_type().unwrap();
+            state.init_extension::<MemoTransfer>(true).unwrap().require_incoming_transfer_memos = true.into();
+            data
+        } else {
+            let mut data = vec![0; SplTokenAccount::LEN];
+            base.pack_into_slice(&mut data);
+            data
+        };
+        self.set_program_account(address, data, token.token_program);
+        address
+    }
+
+    fn token_balance(&self, address: &Pubkey) -> u64 {
+        let account = self.svm.get_account(address).unwrap();
+        StateWithExtensions::<SplTokenAccount>::unpack(&account.data).unwrap().base.amount
+    }
+
+    fn set_program_account(&mut self, address: Pubkey, data: Vec<u8>, owner: Pubkey) {
+        let lamports = self.svm.minimum_balance_for_rent_exemption(data.len());
+        self.svm
+            .set_account(address, Account { lamports, data, owner, executable: false, rent_epoch: 0 })
+            .unwrap();
+    }
+}
+
+fn sol_content(price: u64) -> AddContentArgs {
+    AddContentArgs {
+        title: "Episode".to_string(),
+        price,
+        encrypted_cid: vec![7; 44],
+        payment_mint: None,
+        transfer_fee_payer: TransferFeePayer::Buyer,
+    }
+}
+
+fn tier_terms(price: u64, period_seconds: i64, content_ids: Vec<u64>) -> SubscriptionTierArgs {
+    SubscriptionTierArgs { price, period_seconds, content_ids }
+}
+
+fn fee_of(price: u64) -> u64 {
+    price * FEE_BPS / 10_000
+}
+
+// The Anchor error code a failed transaction's first failing instruction returned.
+fn error_code(result: TransactionResult) -> u32 {
+    match result {
+        Ok(_) => panic!("transaction succeeded but was expected to fail"),
+        Err(failed) => match failed.err {
+            TransactionError::InstructionError(_, InstructionError::Custom(code)) => code,
+            other => panic!("expected a custom program error, got {other:?}\n{:#?}", failed.meta.logs),
+        },
+    }
+}
+
+fn assert_error(result: TransactionResult, expected: CustomError) {
+    assert_eq!(error_code(result), u32::from(expected));
+}
+
+fn assert_anchor_error(result: TransactionResult, expected: anchor_lang::error::ErrorCode) {
+    assert_eq!(error_code(result), u32::from(expected));
+}
+
+// ---------------------------------------------------------------------------
+// Protocol config
+// ---------------------------------------------------------------------------
+
+#[test]
+fn initialize_config_sets_admin_and_fee() {
+    let env = TestEnv::new();
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.admin_wallet, env.admin.pubkey());
+    assert_eq!(config.fee_percentage, FEE_BPS);
+    assert_eq!(config.fee_rounding, FeeRounding::Down);
+}
+
+#[test]
+fn initialize_config_rejects_fee_above_100_percent() {
+    let mut env = TestEnv::uninitialized();
+    let admin = env.admin.insecure_clone();
+    let result = env.send(&[instructions::initialize_config(&admin.pubkey(), 10_001)], &[&admin]);
+    assert_error(result, CustomError::InvalidFeePercentage);
+}
+
+#[test]
+fn update_config_changes_the_fee_but_not_the_admin() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+
+    env.send(&[instructions::update_config(&admin.pubkey(), Some(250), None)], &[&admin])
+        .unwrap();
+
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.fee_percentage, 250);
+    assert_eq!(config.admin_wallet, admin.pubkey());
+}
+
+#[test]
+fn admin_handover_requires_the_new_admin_to_accept() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let new_admin = env.funded_wallet();
+
+    env.send(&[instructions::propose_admin(&admin.pubkey(), &new_admin.pubkey())], &[&admin])
+        .unwrap();
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.admin_wallet, admin.pubkey());
+    assert_eq!(config.pending_admin, Some(new_admin.pubkey()));
+
+    // Only the proposed key can accept
+    let result = env.send(&[instructions::accept_admin(&admin.pubkey())], &[&admin]);
+    assert_error(result, CustomError::Unauthorized);
+
+    env.send(&[instructions::accept_admin(&new_admin.pubkey())], &[&new_admin])
+        .unwrap();
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.admin_wallet, new_admin.pubkey());
+    assert_eq!(config.pending_admin, None);
+
+    // The old admin has lost control
+    let result = env.send(&[instructions::update_config(&admin.pubkey(), Some(0), None)], &[&admin]);
+    assert_error(result, CustomError::Unauthorized);
+}
+
+#[test]
+fn admin_proposals_can_be_cancelled() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let typo = env.funded_wallet();
+
+    let result = env.send(&[instructions::cancel_admin_proposal(&admin.pubkey())], &[&admin]);
+    assert_error(result, CustomError::NoPendingAdmin);
+
+    env.send(&[instructions::propose_admin(&admin.pubkey(), &typo.pubkey())], &[&admin])
+        .unwrap();
+    let result = env.send(&[instructions::cancel_admin_proposal(&typo.pubkey())], &[&typo]);
+    assert_error(result, CustomError::Unauthorized);
+    env.send(&[instructions::cancel_admin_proposal(&admin.pubkey())], &[&admin])
+        .unwrap();
+
+    let result = env.send(&[instructions::accept_admin(&typo.pubkey())], &[&typo]);
+    assert_error(result, CustomError::NoPendingAdmin);
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.admin_wallet, admin.pubkey());
+}
+
+#[test]
+fn propose_admin_rejects_non_admin() {
+    let mut env = TestEnv::new();
+    let intruder = env.funded_wallet();
+    let result = env.send(&[instructions::propose_admin(&intruder.pubkey(), &intruder.pubkey())], &[&intruder]);
+    assert_error(result, CustomError::Unauthorized);
+}
+
+#[test]
+fn update_config_rejects_non_admin() {
+    let mut env = TestEnv::new();
+    let intruder = env.funded_wallet();
+    let result = env.send(&[instructions::update_config(&intruder.pubkey(), Some(0), None)], &[&intruder]);
+    assert_error(result, CustomError::Unauthorized);
+}
+
+#[test]
+fn update_config_rejects_fee_above_100_percent() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let result = env.send(&[instructions::update_config(&admin.pubkey(), Some(10_001), None)], &[&admin]);
+    assert_error(result, CustomError::InvalidFeePercentage);
+}
+
+#[test]
+fn update_config_sets_the_fee_rounding_used_by_purchases() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    // 5% of 30 lamports is 1.5 lamports
+    let content_id = env.add_content(&creator, 30);
+
+    env.send(&[instructions::update_config(&admin.pubkey(), None, Some(FeeRounding::Up))], &[&admin])
+        .unwrap();
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.fee_rounding, FeeRounding::Up);
+
+    env.purchase(&buyer, &creator.pubkey(), content_id, 30).unwrap();
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0);
+    assert_eq!(receipt.fee_amount, 2);
+    assert_eq!(receipt.creator_amount, 28);
+}
+
+#[test]
+fn fee_increases_take_effect_after_the_notice_period() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let first = env.add_content(&creator, PRICE);
+    let second = env.add_content(&creator, PRICE);
+
+    env.send(&[instructions::update_config(&admin.pubkey(), Some(800), None)], &[&admin])
+        .unwrap();
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.fee_percentage, FEE_BPS);
+    assert_eq!(config.pending_fee_percentage, Some(800));
+    assert_eq!(config.fee_effective_at, env.now() + FEE_INCREASE_NOTICE);
+
+    // Purchases before the effective time still pay the old fee
+    env.purchase(&buyer, &creator.pubkey(), first, PRICE).unwrap();
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), first).0);
+    assert_eq!(receipt.fee_amount, fee_of(PRICE));
+
+    // Once due, the new fee applies even though the config still stores it as pending
+    env.warp_by(FEE_INCREASE_NOTICE);
+    let result = env.send(
+        &[instructions::process_payment(&buyer.pubkey(), &creator.pubkey(), second, PRICE, Some(FEE_BPS), None, None, None)],
+        &[&buyer],
+    );
+    assert_error(result, CustomError::FeeAboveMaximum);
+    env.purchase(&buyer, &creator.pubkey(), second, PRICE).unwrap();
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), second).0);
+    assert_eq!(receipt.fee_amount, PRICE * 800 / 10_000);
+
+    // The next config update writes the pending fee back
+    env.send(&[instructions::update_config(&admin.pubkey(), None, Some(FeeRounding::Down))], &[&admin])
+        .unwrap();
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.fee_percentage, 800);
+    assert_eq!(config.pending_fee_percentage, None);
+}
+
+#[test]
+fn fee_cuts_apply_immediately_and_cancel_pending_increases() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+
+    env.send(&[instructions::update_config(&admin.pubkey(), Some(800), None)], &[&admin])
+        .unwrap();
+    env.send(&[instructions::update_config(&admin.pubkey(), Some(FEE_BPS), None)], &[&admin])
+        .unwrap();
+
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.fee_percentage, FEE_BPS);
+    assert_eq!(config.pending_fee_percentage, None);
+}
+
+#[test]
+fn only_governance_can_raise_the_fee_cap() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let governance = env.funded_wallet();
+
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    let starting_cap = config.max_fee_percentage;
+    let result = env.send(&[instructions::update_config(&admin.pubkey(), Some(starting_cap + 1), None)], &[&admin]);
+    assert_error(result, CustomError::FeeAboveCap);
+
+    // Governance starts as the admin and hands itself to a separate key
+    env.send(&[instructions::set_governance(&admin.pubkey(), &governance.pubkey())], &[&admin])
+        .unwrap();
+    let result = env.send(&[instructions::set_max_fee_percentage(&admin.pubkey(), starting_cap + 1)], &[&admin]);
+    assert_error(result, CustomError::Unauthorized);
+
+    env.send(&[instructions::set_max_fee_percentage(&governance.pubkey(), 5_000)], &[&governance])
+        .unwrap();
+    env.send(&[instructions::update_config(&admin.pubkey(), Some(starting_cap + 1), None)], &[&admin])
+        .unwrap();
+
+    // The admin can still lower the cap, which pulls the fee down with it
+    env.send(&[instructions::set_max_fee_percentage(&admin.pubkey(), 100)], &[&admin])
+        .unwrap();
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.max_fee_percentage, 100);
+    assert_eq!(config.fee_percentage, 100);
+    assert_eq!(config.pending_fee_percentage, None);
+}
+
+// Writes a config in the original admin-and-fee layout, as deployments before the
+// config grew still hold it.
+fn write_legacy_config(env: &mut TestEnv, admin: &Pubkey, fee_percentage: u64) {
+    let mut data = auton_client::ProtocolConfig::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(admin.as_ref());
+    data.extend_from_slice(&fee_percentage.to_le_bytes());
+    env.set_program_account(pda::config().0, data, PROGRAM_ID);
+}
+
+#[test]
+fn migrate_config_upgrades_a_legacy_config() {
+    let mut env = TestEnv::uninitialized();
+    let admin = env.admin.insecure_clone();
+    write_legacy_config(&mut env, &admin.pubkey(), FEE_BPS);
+
+    let stranger = env.funded_wallet();
+    let result = env.send(&[instructions::migrate_config(&stranger.pubkey())], &[&stranger]);
+    assert_error(result, CustomError::Unauthorized);
+
+    env.send(&[instructions::migrate_config(&admin.pubkey())], &[&admin])
+        .unwrap();
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.admin_wallet, admin.pubkey());
+    assert_eq!(config.fee_percentage, FEE_BPS);
+    assert_eq!(config.fee_rounding, FeeRounding::Down);
+    assert_eq!(config.fee_recipient, admin.pubkey());
+    assert_eq!(config.guardian, admin.pubkey());
+    assert_eq!(config.governance, admin.pubkey());
+    assert_eq!(config.paused, 0);
+    assert!(config.fee_tiers.is_empty());
+    assert!(env.exists(&pda::treasury().0));
+
+    // Purchases work against the migrated config, and it can't be migrated twice
+    let creator = env.creator();
+    let content_id = env.add_content(&creator, 1_000_000);
+    let buyer = env.funded_wallet();
+    env.purchase(&buyer, &creator.pubkey(), content_id, 1_000_000).unwrap();
+    assert!(env.send(&[instructions::migrate_config(&admin.pubkey())], &[&admin]).is_err());
+}
+
+#[test]
+fn migrate_config_rejects_a_missing_config() {
+    let mut env = TestEnv::uninitialized();
+    let admin = env.admin.insecure_clone();
+    let result = env.send(&[instructions::migrate_config(&admin.pubkey())], &[&admin]);
+    assert_error(result, CustomError::InvalidLegacyConfig);
+}
+
+// ---------------------------------------------------------------------------
+// Treasury
+// ---------------------------------------------------------------------------
+
+#[test]
+fn withdraw_treasury_pays_collected_fees_to_the_fee_recipient() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let cold_wallet = Keypair::new();
+    let content_id = env.add_content(&creator, PRICE);
+    env.purchase(&buyer, &creator.pubkey(), content_id, PRICE).unwrap();
+
+    // The admin starts as the fee recipient and hands the role to a cold wallet.
+    env.send(&[instructions::set_fee_recipient(&admin.pubkey(), &cold_wallet.pubkey())], &[&admin])
+        .unwrap();
+
+    // Withdrawals can't be pointed anywhere but the configured recipient.
+    let result = env.send(&[instructions::withdraw_treasury(&admin.pubkey(), &admin.pubkey(), 1, None)], &[&admin]);
+    assert!(result.is_err());
+
+    env.send(
+        &[instructions::withdraw_treasury(&admin.pubkey(), &cold_wallet.pubkey(), fee_of(PRICE), None)],
+        &[&admin],
+    )
+    .unwrap();
+    assert_eq!(env.balance(&cold_wallet.pubkey()), fee_of(PRICE));
+
+    // Only the rent-exempt minimum is left, which can't be withdrawn.
+    let result = env.send(
+        &[instructions::withdraw_treasury(&admin.pubkey(), &cold_wallet.pubkey(), 1, None)],
+        &[&admin],
+    );
+    assert_error(result, CustomError::InsufficientTreasuryBalance);
+}
+
+#[test]
+fn withdraw_treasury_rejects_non_admin() {
+    let mut env = TestEnv::new();
+    let intruder = env.funded_wallet();
+    let fee_recipient = env.admin.pubkey();
+    let result = env.send(
+        &[instructions::withdraw_treasury(&intruder.pubkey(), &fee_recipient, 0, None)],
+        &[&intruder],
+    );
+    assert_error(result, CustomError::Unauthorized);
+}
+
+#[test]
+fn set_fee_recipient_requires_the_current_fee_recipient() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let cold_wallet = env.funded_wallet();
+
+    env.send(&[instructions::set_fee_recipient(&admin.pubkey(), &cold_wallet.pubkey())], &[&admin])
+        .unwrap();
+
+    // The admin can no longer move the fee recipient once it has been handed over.
+    let result = env.send(&[instructions::set_fee_recipient(&admin.pubkey(), &admin.pubkey())], &[&admin]);
+    assert_error(result, CustomError::Unauthorized);
+
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.fee_recipient, cold_wallet.pubkey());
+}
+
+#[test]
+fn withdraw_treasury_pays_out_tokens() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let mint = env.create_mint(&anchor_spl::token::ID, 6);
+    let token = TokenPayment { mint, token_program: anchor_spl::token::ID };
+    let treasury_ata = env.create_token_account(&token, &pda::treasury().0, 500, false);
+    let recipient_ata = env.create_token_account(&token, &admin.pubkey(), 0, false);
+
+    env.send(&[instructions::withdraw_treasury(&admin.pubkey(), &admin.pubkey(), 300, Some(&token))], &[&admin])
+        .unwrap();
+    assert_eq!(env.token_balance(&treasury_ata), 200);
+    assert_eq!(env.token_balance(&recipient_ata), 300);
+
+    let result = env.send(
+        &[instructions::withdraw_treasury(&admin.pubkey(), &admin.pubkey(), 201, Some(&token))],
+        &[&admin],
+    );
+    assert_error(result, CustomError::InsufficientTreasuryBalance);
+}
+
+// ---------------------------------------------------------------------------
+// Pausing
+// ---------------------------------------------------------------------------
+
+#[test]
+fn paused_payments_block_purchases_until_resumed() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    env.send(&[instructions::set_paused(&admin.pubkey(), PAUSE_PAYMENTS)], &[&admin])
+        .unwrap();
+    let result = env.purchase(&buyer, &creator.pubkey(), content_id, PRICE);
+    assert_error(result, CustomError::ProtocolPaused);
+
+    // Other instruction groups keep working
+    env.add_content(&creator, PRICE);
+
+    env.send(&[instructions::set_paused(&admin.pubkey(), 0)], &[&admin])
+        .unwrap();
+    env.purchase(&buyer, &creator.pubkey(), content_id, PRICE).unwrap();
+}
+
+#[test]
+fn pausing_everything_blocks_sign_ups_and_new_content() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let creator = env.creator();
+    let newcomer = env.funded_wallet();
+
+    env.send(&[instructions::set_paused(&admin.pubkey(), PAUSE_ALL)], &[&admin])
+        .unwrap();
+
+    let result = env.send(
+        &[instructions::register_username(&newcomer.pubkey(), &newcomer.pubkey(), "newcomer")],
+        &[&newcomer],
+    );
+    assert_error(result, CustomError::ProtocolPaused);
+    let result = env.send(
+        &[instructions::initialize_creator(&newcomer.pubkey(), &newcomer.pubkey())],
+        &[&newcomer],
+    );
+    assert_error(result, CustomError::ProtocolPaused);
+    let err = env.add_content_with(&creator, sol_content(PRICE)).unwrap_err();
+    assert_eq!(err, TransactionError::InstructionError(0, InstructionError::Custom(CustomError::ProtocolPaused.into())));
+}
+
+#[test]
+fn the_guardian_can_pause_but_not_manage_the_config() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let guardian = env.funded_wallet();
+    let intruder = env.funded_wallet();
+
+    let result = env.send(&[instructions::set_paused(&intruder.pubkey(), PAUSE_ALL)], &[&intruder]);
+    assert_error(result, CustomError::Unauthorized);
+    let result = env.send(&[instructions::set_guardian(&guardian.pubkey(), &guardian.pubkey())], &[&guardian]);
+    assert_error(result, CustomError::Unauthorized);
+
+    env.send(&[instructions::set_guardian(&admin.pubkey(), &guardian.pubkey())], &[&admin])
+        .unwrap();
+    env.send(&[instructions::set_paused(&guardian.pubkey(), PAUSE_PAYMENTS)], &[&guardian])
+        .unwrap();
+    let config: auton_client::ProtocolConfig = env.fetch(&pda::config().0);
+    assert_eq!(config.guardian, guardian.pubkey());
+    assert_eq!(config.paused, PAUSE_PAYMENTS);
+
+    let result = env.send(&[instructions::update_config(&guardian.pubkey(), Some(0), None)], &[&guardian]);
+    assert_error(result, CustomError::Unauthorized);
+}
+
+#[test]
+fn set_paused_rejects_unknown_flags() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let result = env.send(&[instructions::set_paused(&admin.pubkey(), 1 << 7)], &[&admin]);
+    assert_error(result, CustomError::InvalidPauseFlags);
+}
+
+// ---------------------------------------------------------------------------
+// Fee overrides and tiers
+// ---------------------------------------------------------------------------
+
+fn receipt_fee(env: &TestEnv, buyer: &Keypair, creator: &Keypair, content_id: u64) -> u64 {
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0);
+    receipt.fee_amount
+}
+
+#[test]
+fn fee_overrides_apply_to_one_creator_until_they_expire() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let partner = env.creator();
+    let other = env.creator();
+    let buyer = env.funded_wallet();
+    let first = env.add_content(&partner, PRICE);
+    let second = env.add_content(&partner, PRICE);
+    let other_item = env.add_content(&other, PRICE);
+
+    let expires_at = env.now() + 100;
+    env.send(&[instructions::create_fee_override(&admin.pubkey(), &partner.pubkey(), 100, Some(expires_at))], &[&admin])
+        .unwrap();
+
+    env.purchase(&buyer, &partner.pubkey(), first, PRICE).unwrap();
+    env.purchase(&buyer, &other.pubkey(), other_item, PRICE).unwrap();
+    assert_eq!(receipt_fee(&env, &buyer, &partner, first), PRICE * 100 / 10_000);
+    assert_eq!(receipt_fee(&env, &buyer, &other, other_item), fee_of(PRICE));
+
+    env.warp_by(100);
+    env.purchase(&buyer, &partner.pubkey(), second, PRICE).unwrap();
+    assert_eq!(receipt_fee(&env, &buyer, &partner, second), fee_of(PRICE));
+}
+
+#[test]
+fn fee_overrides_never_raise_the_fee_and_can_be_removed() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let first = env.add_content(&creator, PRICE);
+    let second = env.add_content(&creator, PRICE);
+
+    env.send(&[instructions::create_fee_override(&admin.pubkey(), &creator.pubkey(), 900, None)], &[&admin])
+        .unwrap();
+    env.purchase(&buyer, &creator.pubkey(), first, PRICE).unwrap();
+    assert_eq!(receipt_fee(&env, &buyer, &creator, first), fee_of(PRICE));
+
+    env.send(&[instructions::update_fee_override(&admin.pubkey(), &creator.pubkey(), 0, None)], &[&admin])
+        .unwrap();
+    let fee_override: auton_client::FeeOverride = env.fetch(&pda::fee_override(&creator.pubkey()).0);
+    assert_eq!(fee_override.fee_bps, 0);
+
+    env.send(&[instructions::close_fee_override(&admin.pubkey(), &creator.pubkey())], &[&admin])
+        .unwrap();
+    assert!(!env.exists(&pda::fee_override(&creator.pubkey()).0));
+    env.purchase(&buyer, &creator.pubkey(), second, PRICE).unwrap();
+    assert_eq!(receipt_fee(&env, &buyer, &creator, second), fee_of(PRICE));
+}
+
+#[test]
+fn fee_tiers_follow_the_creators_lifetime_sales() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let first = env.add_content(&creator, PRICE);
+    let second = env.add_content(&creator, PRICE);
+
+    let tiers = vec![FeeTier { min_sales: 1, fee_bps: 300 }, FeeTier { min_sales: 10, fee_bps: 100 }];
+    env.send(&[instructions::set_fee_tiers(&admin.pubkey(), tiers)], &[&admin])
+        .unwrap();
+
+    env.purchase(&buyer, &creator.pubkey(), first, PRICE).unwrap();
+    env.purchase(&buyer, &creator.pubkey(), second, PRICE).unwrap();
+    assert_eq!(receipt_fee(&env, &buyer, &creator, first), fee_of(PRICE));
+    assert_eq!(receipt_fee(&env, &buyer, &creator, second), PRICE * 300 / 10_000);
+
+    let creator_account: auton_client::CreatorAccount = env.fetch(&pda::creator(&creator.pubkey()).0);
+    assert_eq!(creator_account.sales_count, 2);
+}
+
+#[test]
+fn fee_overrides_and_tiers_are_admin_only_and_validated() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let intruder = env.funded_wallet();
+
+    let result = env.send(
+        &[instructions::create_fee_override(&intruder.pubkey(), &intruder.pubkey(), 0, None)],
+        &[&intruder],
+    );
+    assert_error(result, CustomError::Unauthorized);
+    let result = env.send(&[instructions::set_fee_tiers(&intruder.pubkey(), vec![])], &[&intruder]);
+    assert_error(result, CustomError::Unauthorized);
+
+    let unordered = vec![FeeTier { min_sales: 10, fee_bps: 100 }, FeeTier { min_sales: 10, fee_bps: 50 }];
+    let result = env.send(&[instructions::set_fee_tiers(&admin.pubkey(), unordered)], &[&admin]);
+    assert_error(result, CustomError::InvalidFeeTiers);
+
+    let too_many = (1..=5).map(|min_sales| FeeTier { min_sales, fee_bps: 100 }).collect();
+    let result = env.send(&[instructions::set_fee_tiers(&admin.pubkey(), too_many)], &[&admin]);
+    assert_error(result, CustomError::TooManyFeeTiers);
+}
+
+// ---------------------------------------------------------------------------
+// Revenue splits
+// ---------------------------------------------------------------------------
+
+fn recipient(wallet: &Keypair, share_bps: u64) -> SplitRecipient {
+    SplitRecipient { wallet: wallet.pubkey(), share_bps }
+}
+
+fn create_split(env: &mut TestEnv, creator: &Keypair, content_id: u64, recipients: Vec<SplitRecipient>) -> TransactionResult {
+    env.send(
+        &[instructions::create_revenue_split(&creator.pubkey(), &creator.pubkey(), content_id, recipients)],
+        &[creator],
+    )
+}
+
+// Buys a SOL-priced item, passing the recipients of the split that applies to it.
+fn split_purchase(env: &mut TestEnv, buyer: &Keypair, creator: &Pubkey, content_id: u64, recipients: &[SplitRecipient]) -> TransactionResult {
+    let mut instruction = instructions::process_payment(&buyer.pubkey(), creator, content_id, PRICE, None, None, None, None);
+    instructions::add_split_recipients(&mut instruction, recipients, None);
+    env.send(&[instruction], &[buyer])
+}
+
+#[test]
+fn content_splits_divide_the_creators_share_between_recipients() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let editor = env.funded_wallet();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    let recipients = vec![recipient(&creator, 3_333), recipient(&editor, 6_667)];
+    create_split(&mut env, &creator, content_id, recipients.clone()).unwrap();
+
+    let creator_before = env.balance(&creator.pubkey());
+    let editor_before = env.balance(&editor.pubkey());
+    split_purchase(&mut env, &buyer, &creator.pubkey(), content_id, &recipients).unwrap();
+
+    let creator_amount = PRICE - fee_of(PRICE);
+    let editor_share = creator_amount * 6_667 / 10_000;
+    assert_eq!(env.balance(&editor.pubkey()), editor_before + editor_share);
+    // The first recipient also receives the rounding remainder.
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + creator_amount - editor_share);
+}
+
+#[test]
+fn the_creator_default_split_applies_to_content_without_its_own() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let label = env.funded_wallet();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    let recipients = vec![recipient(&label, 10_000)];
+    create_split(&mut env, &creator, CREATOR_DEFAULT_SPLIT, recipients.clone()).unwrap();
+
+    let creator_before = env.balance(&creator.pubkey());
+    let label_before = env.balance(&label.pubkey());
+    split_purchase(&mut env, &buyer, &creator.pubkey(), content_id, &recipients).unwrap();
+
+    assert_eq!(env.balance(&label.pubkey()), label_before + PRICE - fee_of(PRICE));
+    assert_eq!(env.balance(&creator.pubkey()), creator_before);
+}
+
+#[test]
+fn split_purchases_require_the_recipients_accounts() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let editor = env.funded_wallet();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+    create_split(&mut env, &creator, content_id, vec![recipient(&creator, 5_000), recipient(&editor, 5_000)])
+        .unwrap();
+
+    assert_error(env.purchase(&buyer, &creator.pubkey(), content_id, PRICE), CustomError::SplitRecipientMismatch);
+    let swapped = [recipient(&editor, 5_000), recipient(&creator, 5_000)];
+    assert_error(
+        split_purchase(&mut env, &buyer, &creator.pubkey(), content_id, &swapped),
+        CustomError::SplitRecipientMismatch,
+    );
+}
+
+#[test]
+fn revenue_splits_are_validated() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let editor = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    let short = vec![recipient(&creator, 5_000), recipient(&editor, 4_999)];
+    assert_error(create_split(&mut env, &creator, content_id, short), CustomError::InvalidRevenueSplit);
+    let zero_share = vec![recipient(&creator, 10_000), recipient(&editor, 0)];
+    assert_error(create_split(&mut env, &creator, content_id, zero_share), CustomError::InvalidRevenueSplit);
+    let too_many = (0..9).map(|_| SplitRecipient { wallet: Pubkey::new_unique(), share_bps: 1 }).collect();
+    assert_error(create_split(&mut env, &creator, content_id, too_many), CustomError::TooManySplitRecipients);
+    let whole = vec![recipient(&editor, 10_000)];
+    assert_error(create_split(&mut env, &creator, content_id + 1, whole), CustomError::ContentNotFound);
+}
+
+#[test]
+fn removing_a_split_pays_the_creator_again() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let editor = env.funded_wallet();
+    let buyer = env.funded_wallet();
+    let first = env.add_content(&creator, PRICE);
+    let second = env.add_content(&creator, PRICE);
+
+    create_split(&mut env, &creator, first, vec![recipient(&editor, 10_000)]).unwrap();
+    env.send(
+        &[instructions::update_revenue_split(&creator.pubkey(), first, vec![recipient(&creator, 2_000), recipient(&editor, 8_000)])],
+        &[&creator],
+    )
+    .unwrap();
+    let split: auton_client::RevenueSplit = env.fetch(&pda::revenue_split(&creator.pubkey(), first).0);
+    assert_eq!(split.recipients.len(), 2);
+
+    env.send(&[instructions::close_revenue_split(&creator.pubkey(), first)], &[&creator])
+        .unwrap();
+    assert!(!env.exists(&pda::revenue_split(&creator.pubkey(), first).0));
+
+    let creator_before = env.balance(&creator.pubkey());
+    env.purchase(&buyer, &creator.pubkey(), first, PRICE).unwrap();
+    env.purchase(&buyer, &creator.pubkey(), second, PRICE).unwrap();
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + 2 * (PRICE - fee_of(PRICE)));
+}
+
+// ---------------------------------------------------------------------------
+// Usernames
+// ---------------------------------------------------------------------------
+
+#[test]
+fn register_username_with_a_relayer_paying_rent() {
+    let mut env = TestEnv::new();
+    let creator = Keypair::new(); // Unfunded: the relayer pays for everything
+    let relayer = env.funded_wallet();
+
+    env.send(
+        &[instructions::register_username(&creator.pubkey(), &relayer.pubkey(), "alice_01")],
+        &[&relayer, &creator],
+    )
+    .unwrap();
+
+    let account: auton_client::UsernameAccount = env.fetch(&pda::username("alice_01").0);
+    assert_eq!(account.authority, creator.pubkey());
+    assert_eq!(account.username, "alice_01");
+}
+
+#[test]
+fn register_username_rejects_invalid_names() {
+    let mut env = TestEnv::new();
+    let creator = env.funded_wallet();
+
+    for username in ["ab", "bad-name!", "has space"] {
+        let result = env.send(
+            &[instructions::register_username(&creator.pubkey(), &creator.pubkey(), username)],
+            &[&creator],
+        );
+        assert_error(result, CustomError::InvalidUsername);
+    }
+}
+
+#[test]
+fn register_username_rejects_duplicates() {
+    let mut env = TestEnv::new();
+    let first = env.funded_wallet();
+    let second = env.funded_wallet();
+
+    env.send(&[instructions::register_username(&first.pubkey(), &first.pubkey(), "taken")], &[&first])
+        .unwrap();
+    let result = env.send(&[instructions::register_username(&second.pubkey(), &second.pubkey(), "taken")], &[&second]);
+    assert!(result.is_err());
+}
+
+// ---------------------------------------------------------------------------
+// Creators and content
+// ---------------------------------------------------------------------------
+
+#[test]
+fn add_content_assigns_sequential_ids() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+
+    assert_eq!(env.add_content(&creator, PRICE), 1);
+    assert_eq!(env.add_content(&creator, 2 * PRICE), 2);
+
+    let item: auton_client::ContentItem = env.fetch(&pda::content(&creator.pubkey(), 2).0);
+    assert_eq!(item.creator, creator.pubkey());
+    assert_eq!(item.id, 2);
+    assert_eq!(item.price, 2 * PRICE);
+    assert!(item.listed);
+    assert_eq!(item.payment_mint, None);
+
+    let creator_account: auton_client::CreatorAccount = env.fetch(&pda::creator(&creator.pubkey()).0);
+    assert_eq!(creator_account.last_content_id, 2);
+}
+
+#[test]
+fn add_content_rejects_oversized_fields() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+
+    let long_title = AddContentArgs { title: "t".repeat(129), ..sol_content(PRICE) };
+    let err = env.add_content_with(&creator, long_title).unwrap_err();
+    assert_eq!(err, TransactionError::InstructionError(0, InstructionError::Custom(CustomError::TitleTooLong.into())));
+
+    let long_cid = AddContentArgs { encrypted_cid: vec![0; 129], ..sol_content(PRICE) };
+    let err = env.add_content_with(&creator, long_cid).unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(CustomError::EncryptedCidTooLong.into()))
+    );
+}
+
+#[test]
+fn update_profile_resizes_the_creator_account() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let creator_pda = pda::creator(&creator.pubkey()).0;
+
+    env.send(&[instructions::update_profile(&creator.pubkey(), &"q".repeat(59))], &[&creator])
+        .unwrap();
+    let long_len = env.svm.get_account(&creator_pda).unwrap().data.len();
+    env.send(&[instructions::update_profile(&creator.pubkey(), "short")], &[&creator])
+        .unwrap();
+    let short_len = env.svm.get_account(&creator_pda).unwrap().data.len();
+
+    assert_eq!(long_len, auton_program::CreatorAccount::space(59));
+    assert_eq!(short_len, auton_program::CreatorAccount::space(5));
+    let creator_account: auton_client::CreatorAccount = env.fetch(&creator_pda);
+    assert_eq!(creator_account.profile_cid, "short");
+
+    let result = env.send(&[instructions::update_profile(&creator.pubkey(), &"q".repeat(101))], &[&creator]);
+    assert_error(result, CustomError::ProfileCidTooLong);
+}
+
+#[test]
+fn update_content_edits_only_the_given_fields() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let content_id = env.add_content(&creator, PRICE);
+
+    env.send(
+        &[instructions::update_content(&creator.pubkey(), content_id, Some("Director's cut".into()), None, None)],
+        &[&creator],
+    )
+    .unwrap();
+
+    let item: auton_client::ContentItem = env.fetch(&pda::content(&creator.pubkey(), content_id).0);
+    assert_eq!(item.title, "Director's cut");
+    assert_eq!(item.price, PRICE);
+
+    let result = env.send(
+        &[instructions::update_content(&creator.pubkey(), content_id, Some("t".repeat(129)), None, None)],
+        &[&creator],
+    );
+    assert_error(result, CustomError::TitleTooLong);
+    let result = env.send(
+        &[instructions::update_content(&creator.pubkey(), content_id, None, None, Some(vec![0; 129]))],
+        &[&creator],
+    );
+    assert_error(result, CustomError::EncryptedCidTooLong);
+}
+
+#[test]
+fn update_content_rejects_another_creator() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let other = env.creator();
+    let content_id = env.add_content(&creator, PRICE);
+
+    // Point the instruction at the first creator's item while `other` signs.
+    let mut instruction = instructions::update_content(&other.pubkey(), content_id, None, Some(1), None);
+    instruction.accounts[0].pubkey = pda::content(&creator.pubkey(), content_id).0;
+    assert!(env.send(&[instruction], &[&other]).is_err());
+
+    let item: auton_client::ContentItem = env.fetch(&pda::content(&creator.pubkey(), content_id).0);
+    assert_eq!(item.price, PRICE);
+}
+
+#[test]
+fn unlisted_content_cannot_be_bought_until_relisted() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    env.send(&[instructions::unlist_content(&creator.pubkey(), content_id)], &[&creator])
+        .unwrap();
+    assert_error(env.purchase(&buyer, &creator.pubkey(), content_id, PRICE), CustomError::ContentUnlisted);
+
+    env.send(&[instructions::relist_content(&creator.pubkey(), content_id)], &[&creator])
+        .unwrap();
+    env.purchase(&buyer, &creator.pubkey(), content_id, PRICE).unwrap();
+}
+
+// ---------------------------------------------------------------------------
+// Purchases
+// ---------------------------------------------------------------------------
+
+#[test]
+fn process_payment_splits_the_fee_and_writes_a_receipt() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    let creator_before = env.balance(&creator.pubkey());
+    let treasury_before = env.balance(&pda::treasury().0);
+    env.purchase(&buyer, &creator.pubkey(), content_id, PRICE).unwrap();
+
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + PRICE - fee_of(PRICE));
+    assert_eq!(env.balance(&pda::treasury().0), treasury_before + fee_of(PRICE));
+
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0);
+    assert_eq!(receipt.buyer, buyer.pubkey());
+    assert_eq!(receipt.creator, creator.pubkey());
+    assert_eq!(receipt.content_id, content_id);
+    assert_eq!(receipt.price, PRICE);
+    assert_eq!(receipt.fee_amount, fee_of(PRICE));
+    assert_eq!(receipt.creator_amount, PRICE - fee_of(PRICE));
+}
+
+#[test]
+fn process_payment_emits_content_purchased() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    let logs = env.purchase(&buyer, &creator.pubkey(), content_id, PRICE).unwrap().logs;
+    let event = logs
+        .iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .map(|data| base64::engine::general_purpose::STANDARD.decode(data).unwrap())
+        .find(|data| data.starts_with(ContentPurchased::DISCRIMINATOR))
+        .map(|data| ContentPurchased::try_from_slice(&data[ContentPurchased::DISCRIMINATOR.len()..]).unwrap())
+        .expect("ContentPurchased was not emitted");
+
+    assert_eq!(event.buyer, buyer.pubkey());
+    assert_eq!(event.creator, creator.pubkey());
+    assert_eq!(event.content_id, content_id);
+    assert_eq!(event.fee_bps, FEE_BPS);
+    assert_eq!(event.fee_amount, fee_of(PRICE));
+    assert_eq!(event.creator_amount, PRICE - fee_of(PRICE));
+    assert_eq!(event.buyer_total, PRICE);
+    assert_eq!(event.edition, 1);
+}
+
+#[test]
+fn the_same_content_id_can_be_bought_from_different_creators() {
+    let mut env = TestEnv::new();
+    let buyer = env.funded_wallet();
+    for _ in 0..2 {
+        let creator = env.creator();
+        let content_id = env.add_content(&creator, PRICE);
+        env.purchase(&buyer, &creator.pubkey(), content_id, PRICE).unwrap();
+    }
+}
+
+#[test]
+fn a_receipt_cannot_be_bought_twice() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    env.purchase(&buyer, &creator.pubkey(), content_id, PRICE).unwrap();
+    assert_error(env.purchase(&buyer, &creator.pubkey(), content_id, PRICE), CustomError::AlreadyPurchased);
+}
+
+#[test]
+fn lamports_sent_to_a_receipt_address_do_not_block_the_purchase() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+    let receipt_pda = pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0;
+
+    env.svm.airdrop(&receipt_pda, 1).unwrap();
+    env.purchase(&buyer, &creator.pubkey(), content_id, PRICE).unwrap();
+    let receipt: PaidAccessAccount = env.fetch(&receipt_pda);
+    assert_eq!(receipt.buyer, buyer.pubkey());
+    assert_eq!(env.balance(&receipt_pda), env.svm.minimum_balance_for_rent_exemption(PaidAccessAccount::LEN));
+}
+
+#[test]
+fn process_payment_rejects_missing_content() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    assert_anchor_error(
+        env.purchase(&buyer, &creator.pubkey(), 42, PRICE),
+        anchor_lang::error::ErrorCode::AccountNotInitialized,
+    );
+}
+
+#[test]
+fn process_payment_enforces_the_buyers_limits() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+    assert_error(env.purchase(&buyer, &creator.pubkey(), content_id, PRICE - 1), CustomError::PriceAboveMaximum);
+
+    let result = env.send(
+        &[instructions::process_payment(&buyer.pubkey(), &creator.pubkey(), content_id, PRICE, Some(FEE_BPS - 1), None, None, None)],
+        &[&buyer],
+    );
+    assert_error(result, CustomError::FeeAboveMaximum);
+
+    env.send(
+        &[instructions::process_payment(&buyer.pubkey(), &creator.pubkey(), content_id, PRICE, Some(FEE_BPS), None, None, None)],
+        &[&buyer],
+    )
+    .unwrap();
+}
+
+#[test]
+fn process_payment_rejects_a_fee_destination_other_than_the_treasury() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    let mut instruction = instructions::process_payment(&buyer.pubkey(), &creator.pubkey(), content_id, PRICE, None, None, None, None);
+    for meta in instruction.accounts.iter_mut().filter(|meta| meta.pubkey == pda::treasury().0) {
+        meta.pubkey = buyer.pubkey();
+    }
+    assert!(env.send(&[instruction], &[&buyer]).is_err());
+}
+
+// ---------------------------------------------------------------------------
+// Token payments
+// ---------------------------------------------------------------------------
+
+struct TokenSetup {
+    token: TokenPayment,
+    creator: Keypair,
+    buyer: Keypair,
+    content_id: u64,
+}
+
+// A creator with a content item priced in a fresh mint, and a buyer holding enough of it.
+fn token_setup(env: &mut TestEnv, token_program: Pubkey, creator_requires_memo: bool) -> TokenSetup {
+    let mint = env.create_mint(&token_program, 6);
+    let token = TokenPayment { mint, token_program };
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+
+    env.create_token_account(&token, &buyer.pubkey(), 10 * PRICE, false);
+    env.create_token_account(&token, &creator.pubkey(), 0, creator_requires_memo);
+    env.create_token_account(&token, &pda::treasury().0, 0, false);
+
+    let args = AddContentArgs { payment_mint: Some(mint), ..sol_content(PRICE) };
+    let content_id = env.add_content_with(&creator, args).unwrap();
+    TokenSetup { token, creator, buyer, content_id }
+}
+
+fn token_purchase(setup: &TokenSetup, token: Option<&TokenPayment>) -> Instruction {
+    instructions::process_payment(
+        &setup.buyer.pubkey(),
+        &setup.creator.pubkey(),
+        setup.content_id,
+        PRICE,
+        None,
+        None,
+        None,
+        token,
+    )
+}
+
+#[test]
+fn token_priced_content_is_paid_in_tokens() {
+    for token_program in [anchor_spl::token::ID, anchor_spl::token_2022::ID] {
+        let mut env = TestEnv::new();
+        let setup = token_setup(&mut env, token_program, false);
+
+        let instruction = token_purchase(&setup, Some(&setup.token));
+        env.send(&[instruction], &[&setup.buyer]).unwrap();
+
+        let ata = |wallet: &Pubkey| {
+            get_associated_token_address_with_program_id(wallet, &setup.token.mint, &token_program)
+        };
+        assert_eq!(env.token_balance(&ata(&setup.creator.pubkey())), PRICE - fee_of(PRICE));
+        assert_eq!(env.token_balance(&ata(&pda::treasury().0)), fee_of(PRICE));
+        assert_eq!(env.token_balance(&ata(&setup.buyer.pubkey())), 9 * PRICE);
+
+        let receipt: PaidAccessAccount =
+            env.fetch(&pda::receipt(&setup.buyer.pubkey(), &setup.creator.pubkey(), setup.content_id).0);
+        assert_eq!(receipt.payment_mint, Some(setup.token.mint));
+    }
+}
+
+#[test]
+fn token_payment_requires_the_token_accounts() {
+    let mut env = TestEnv::new();
+    let setup = token_setup(&mut env, anchor_spl::token::ID, false);
+    let instruction = token_purchase(&setup, None);
+    assert_error(env.send(&[instruction], &[&setup.buyer]), CustomError::MissingTokenAccounts);
+}
+
+#[test]
+fn token_payment_rejects_another_mint() {
+    let mut env = TestEnv::new();
+    let setup = token_setup(&mut env, anchor_spl::token::ID, false);
+    let other_mint = env.create_mint(&anchor_spl::token::ID, 6);
+    let other = TokenPayment { mint: other_mint, token_program: anchor_spl::token::ID };
+    env.create_token_account(&other, &setup.buyer.pubkey(), PRICE, false);
+    env.create_token_account(&other, &setup.creator.pubkey(), 0, false);
+    env.create_token_account(&other, &pda::treasury().0, 0, false);
+
+    let instruction = token_purchase(&setup, Some(&other));
+    assert_error(env.send(&[instruction], &[&setup.buyer]), CustomError::PaymentMintMismatch);
+}
+
+#[test]
+fn token_payment_rejects_a_non_associated_creator_account() {
+    let mut env = TestEnv::new();
+    let setup = token_setup(&mut env, anchor_spl::token::ID, false);
+
+    // Swap the creator's ATA for another account of the same mint owned by someone else.
+    let stray = env.create_token_account(&setup.token, &Pubkey::new_unique(), 0, false);
+    let mut instruction = token_purchase(&setup, Some(&setup.token));
+    let creator_ata = get_associated_token_address_with_program_id(
+        &setup.creator.pubkey(),
+        &setup.token.mint,
+        &setup.token.token_program,
+    );
+    for meta in instruction.accounts.iter_mut().filter(|meta| meta.pubkey == creator_ata) {
+        meta.pubkey = stray;
+    }
+    assert_error(env.send(&[instruction], &[&setup.buyer]), CustomError::InvalidTokenAccount);
+}
+
+#[test]
+fn memo_required_accounts_need_the_memo_program() {
+    let mut env = TestEnv::new();
+    let setup = token_setup(&mut env, anchor_spl::token_2022::ID, true);
+
+    // The memo program is the last account; the program ID stands in for an omitted optional account.
+    let mut without_memo = token_purchase(&setup, Some(&setup.token));
+    without_memo.accounts.last_mut().unwrap().pubkey = PROGRAM_ID;
+    assert_error(env.send(&[without_memo], &[&setup.buyer]), CustomError::MemoProgramRequired);
+
+    let with_memo = token_purchase(&setup, Some(&setup.token));
+    env.send(&[with_memo], &[&setup.buyer]).unwrap();
+}
+
+// ---------------------------------------------------------------------------
+// Referrals
+// ---------------------------------------------------------------------------
+
+// A registered SOL referrer, and a creator paying referrers `affiliate_bps` of their sales.
+fn referral_setup(env: &mut TestEnv, affiliate_bps: u64) -> (Keypair, Keypair) {
+    let creator = env.creator();
+    env.send(&[instructions::set_affiliate_share(&creator.pubkey(), affiliate_bps)], &[&creator])
+        .unwrap();
+    let referrer = env.funded_wallet();
+    env.send(&[instructions::register_referrer(&referrer.pubkey(), &referrer.pubkey(), None)], &[&referrer])
+        .unwrap();
+    (creator, referrer)
+}
+
+fn referred_purchase(env: &mut TestEnv, buyer: &Keypair, creator: &Pubkey, content_id: u64, referrer: &Pubkey) -> TransactionResult {
+    env.send(
+        &[instructions::process_payment(&buyer.pubkey(), creator, content_id, PRICE, None, Some(referrer), None, None)],
+        &[buyer],
+    )
+}
+
+#[test]
+fn referrers_are_paid_from_the_creators_share_and_credited() {
+    let mut env = TestEnv::new();
+    let (creator, referrer) = referral_setup(&mut env, 1_000);
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    let creator_before = env.balance(&creator.pubkey());
+    let referrer_before = env.balance(&referrer.pubkey());
+    referred_purchase(&mut env, &buyer, &creator.pubkey(), content_id, &referrer.pubkey()).unwrap();
+
+    let referral = (PRICE - fee_of(PRICE)) / 10;
+    assert_eq!(env.balance(&referrer.pubkey()), referrer_before + referral);
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + PRICE - fee_of(PRICE) - referral);
+
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0);
+    assert_eq!(receipt.referrer, Some(referrer.pubkey()));
+    assert_eq!(receipt.referral_amount, referral);
+    assert_eq!(receipt.creator_amount, PRICE - fee_of(PRICE) - referral);
+
+    let stats: auton_client::ReferrerStats = env.fetch(&pda::referrer_stats(&referrer.pubkey(), None).0);
+    assert_eq!(stats.referral_count, 1);
+    assert_eq!(stats.total_earned, referral);
+}
+
+#[test]
+fn a_content_items_affiliate_share_overrides_the_creators() {
+    let mut env = TestEnv::new();
+    let (creator, referrer) = referral_setup(&mut env, 1_000);
+    let buyer = env.funded_wallet();
+    let first = env.add_content(&creator, PRICE);
+    let second = env.add_content(&creator, PRICE);
+
+    env.send(&[instructions::set_content_affiliate_share(&creator.pubkey(), first, Some(0))], &[&creator])
+        .unwrap();
+    referred_purchase(&mut env, &buyer, &creator.pubkey(), first, &referrer.pubkey()).unwrap();
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), first).0);
+    assert_eq!(receipt.referral_amount, 0);
+
+    env.send(&[instructions::set_content_affiliate_share(&creator.pubkey(), second, Some(2_500))], &[&creator])
+        .unwrap();
+    referred_purchase(&mut env, &buyer, &creator.pubkey(), second, &referrer.pubkey()).unwrap();
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), second).0);
+    assert_eq!(receipt.referral_amount, (PRICE - fee_of(PRICE)) / 4);
+
+    let stats: auton_client::ReferrerStats = env.fetch(&pda::referrer_stats(&referrer.pubkey(), None).0);
+    assert_eq!(stats.referral_count, 2);
+}
+
+#[test]
+fn referrals_require_a_registered_referrer_other_than_the_buyer() {
+    let mut env = TestEnv::new();
+    let (creator, referrer) = referral_setup(&mut env, 1_000);
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    let unregistered = env.funded_wallet();
+    assert_anchor_error(
+        referred_purchase(&mut env, &buyer, &creator.pubkey(), content_id, &unregistered.pubkey()),
+        anchor_lang::error::ErrorCode::AccountNotInitialized,
+    );
+    assert_error(
+        referred_purchase(&mut env, &referrer, &creator.pubkey(), content_id, &referrer.pubkey()),
+        CustomError::SelfReferral,
+    );
+
+    // Paying someone other than the registered referrer
+    let mut instruction = instructions::process_payment(
+        &buyer.pubkey(),
+        &creator.pubkey(),
+        content_id,
+        PRICE,
+        None,
+        Some(&referrer.pubkey()),
+        None,
+        None,
+    );
+    for meta in instruction.accounts.iter_mut().filter(|meta| meta.pubkey == referrer.pubkey()) {
+        meta.pubkey = buyer.pubkey();
+    }
+    assert_error(env.send(&[instruction], &[&buyer]), CustomError::InvalidReferrer);
+
+    let result = env.send(&[instructions::set_affiliate_share(&creator.pubkey(), 10_001)], &[&creator]);
+    assert_error(result, CustomError::InvalidAffiliateShare);
+}
+
+#[test]
+fn token_referrals_are_paid_to_the_referrers_token_account() {
+    let mut env = TestEnv::new();
+    let setup = token_setup(&mut env, anchor_spl::token::ID, false);
+    env.send(&[instructions::set_affiliate_share(&setup.creator.pubkey(), 1_000)], &[&setup.creator])
+        .unwrap();
+    let referrer = env.funded_wallet();
+    let referrer_account = env.create_token_account(&setup.token, &referrer.pubkey(), 0, false);
+
+    // SOL stats don't count for token sales
+    env.send(&[instructions::register_referrer(&referrer.pubkey(), &referrer.pubkey(), None)], &[&referrer])
+        .unwrap();
+    let sol_stats = pda::referrer_stats(&referrer.pubkey(), None).0;
+    let token_stats = pda::referrer_stats(&referrer.pubkey(), Some(&setup.token.mint)).0;
+    let instruction = instructions::process_payment(
+        &setup.buyer.pubkey(),
+        &setup.creator.pubkey(),
+        setup.content_id,
+        PRICE,
+        None,
+        Some(&referrer.pubkey()),
+        None,
+        Some(&setup.token),
+    );
+    let mut with_sol_stats = instruction.clone();
+    for meta in with_sol_stats.accounts.iter_mut().filter(|meta| meta.pubkey == token_stats) {
+        meta.pubkey = sol_stats;
+    }
+    assert_error(env.send(&[with_sol_stats], &[&setup.buyer]), CustomError::InvalidReferrer);
+
+    env.send(
+        &[instructions::register_referrer(&referrer.pubkey(), &referrer.pubkey(), Some(setup.token.mint))],
+        &[&referrer],
+    )
+    .unwrap();
+    env.send(&[instruction], &[&setup.buyer]).unwrap();
+
+    let referral = (PRICE - fee_of(PRICE)) / 10;
+    assert_eq!(env.token_balance(&referrer_account), referral);
+    let stats: auton_client::ReferrerStats = env.fetch(&token_stats);
+    assert_eq!(stats.payment_mint, Some(setup.token.mint));
+    assert_eq!(stats.total_earned, referral);
+}
+
+// ---------------------------------------------------------------------------
+// Legacy receipts
+// ---------------------------------------------------------------------------
+
+// Writes a receipt in the pre-creator-scoped layout at its legacy address.
+fn write_legacy_receipt(env: &mut TestEnv, buyer: &Pubkey, creator: &Pubkey, content_id: u64, created_at: i64) -> Pubkey {
+    let address = pda::legacy_receipt(buyer, content_id).0;
+    let mut data = PaidAccessAccount::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(buyer.as_ref());
+    data.extend_from_slice(&content_id.to_le_bytes());
+    data.extend_from_slice(creator.as_ref());
+    data.extend_from_slice(&created_at.to_le_bytes());
+    env.set_program_account(address, data, PROGRAM_ID);
+    address
+}
+
+#[test]
+fn migrate_receipt_moves_a_legacy_receipt_and_refunds_its_rent() {
+    let mut env = TestEnv::new();
+    let buyer = Keypair::new();
+    let creator = Pubkey::new_unique();
+    let relayer = env.funded_wallet();
+    let legacy = write_legacy_receipt(&mut env, &buyer.pubkey(), &creator, 3, 1_700_000_000);
+    let legacy_rent = env.balance(&legacy);
+    let decoded = accounts::decode_legacy_receipt(&env.svm.get_account(&legacy).unwrap().data).unwrap();
+    assert_eq!((decoded.buyer, decoded.creator, decoded.content_id), (buyer.pubkey(), creator, 3));
+
+    env.send(&[instructions::migrate_receipt(&buyer.pubkey(), &relayer.pubkey(), &creator, 3)], &[&relayer])
+        .unwrap();
+
+    assert!(!env.exists(&legacy));
+    assert_eq!(env.balance(&buyer.pubkey()), legacy_rent);
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator, 3).0);
+    assert_eq!(receipt.buyer, buyer.pubkey());
+    assert_eq!(receipt.creator, creator);
+    assert_eq!(receipt.content_id, 3);
+    assert_eq!(receipt.created_at, 1_700_000_000);
+}
+
+#[test]
+fn migrate_receipt_rejects_missing_or_mismatched_receipts() {
+    let mut env = TestEnv::new();
+    let buyer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let relayer = env.funded_wallet();
+
+    let result = env.send(&[instructions::migrate_receipt(&buyer, &relayer.pubkey(), &creator, 1)], &[&relayer]);
+    assert_error(result, CustomError::InvalidLegacyReceipt);
+
+    write_legacy_receipt(&mut env, &buyer, &creator, 1, 0);
+    let wrong_creator = Pubkey::new_unique();
+    let result = env.send(&[instructions::migrate_receipt(&buyer, &relayer.pubkey(), &wrong_creator, 1)], &[&relayer]);
+    assert_error(result, CustomError::InvalidLegacyReceipt);
+}
+
+// ---------------------------------------------------------------------------
+// Legacy creators
+// ---------------------------------------------------------------------------
+
+// Writes a creator account in the layout that kept content in a list, with the zeroed slack
+// older program versions left when growing it, at the creator's address.
+fn write_legacy_creator(env: &mut TestEnv, creator: &Pubkey, items: &[(u64, &str, u64)], profile_cid: &str) {
+    let mut data = auton_client::CreatorAccount::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(creator.as_ref());
+    data.extend_from_slice(&items.last().map_or(0, |item| item.0).to_le_bytes());
+    data.extend_from_slice(&(items.len() as u32).to_le_bytes());
+    for (id, title, price) in items {
+        data.extend_from_slice(&id.to_le_bytes());
+        data.extend_from_slice(&(title.len() as u32).to_le_bytes());
+        data.extend_from_slice(title.as_bytes());
+        data.extend_from_slice(&price.to_le_bytes());
+        data.extend_from_slice(&44u32.to_le_bytes());
+        data.extend_from_slice(&[7; 44]);
+    }
+    data.extend_from_slice(&(profile_cid.len() as u32).to_le_bytes());
+    data.extend_from_slice(profile_cid.as_bytes());
+    data.extend_from_slice(&[0; 64]);
+    env.set_program_account(pda::creator(creator).0, data, PROGRAM_ID);
+}
+
+#[test]
+fn migrate_creator_content_moves_legacy_content_in_batches() {
+    let mut env = TestEnv::new();
+    let creator = env.funded_wallet();
+    write_legacy_creator(&mut env, &creator.pubkey(), &[(1, "Pilot", 1_000_000), (2, "Finale", 2_000_000)], "QmProfile");
+
+    env.send(&[instructions::migrate_creator_content(&creator.pubkey(), &[1])], &[&creator])
+        .unwrap();
+    let pilot: auton_client::ContentItem = env.fetch(&pda::content(&creator.pubkey(), 1).0);
+    assert_eq!(pilot.title, "Pilot");
+    assert_eq!(pilot.price, 1_000_000);
+    assert_eq!(pilot.encrypted_cid, vec![7; 44]);
+    assert_eq!(pilot.payment_mint, None);
+    assert!(pilot.listed);
+    assert!(!env.exists(&pda::content(&creator.pubkey(), 2).0));
+
+    // Items have to be moved in list order
+    let result = env.send(&[instructions::migrate_creator_content(&creator.pubkey(), &[3])], &[&creator]);
+    assert_error(result, CustomError::InvalidLegacyCreator);
+
+    env.send(&[instructions::migrate_creator_content(&creator.pubkey(), &[2])], &[&creator])
+        .unwrap();
+    let creator_account: auton_client::CreatorAccount = env.fetch(&pda::creator(&creator.pubkey()).0);
+    assert_eq!(creator_account.last_content_id, 2);
+    assert_eq!(creator_account.profile_cid, "QmProfile");
+    assert_eq!(creator_account.sales_count, 0);
+
+    // The migrated creator works like any other, and can't be migrated again
+    let buyer = env.funded_wallet();
+    env.purchase(&buyer, &creator.pubkey(), 2, 2_000_000).unwrap();
+    assert_eq!(env.add_content(&creator, 500_000), 3);
+    let result = env.send(&[instructions::migrate_creator_content(&creator.pubkey(), &[])], &[&creator]);
+    assert_error(result, CustomError::InvalidLegacyCreator);
+}
+
+#[test]
+fn part_migrated_creators_are_rejected_until_fully_migrated() {
+    let mut env = TestEnv::new();
+    let creator = env.funded_wallet();
+    let buyer = env.funded_wallet();
+    write_legacy_creator(&mut env, &creator.pubkey(), &[(1, "Pilot", 1_000_000), (2, "Finale", 2_000_000)], "");
+    env.send(&[instructions::migrate_creator_content(&creator.pubkey(), &[1])], &[&creator])
+        .unwrap();
+
+    // The still-legacy creator account decodes as the current layout, but isn't sized like one.
+    assert_error(env.purchase(&buyer, &creator.pubkey(), 1, 1_000_000), CustomError::InvalidLegacyCreator);
+    let result = env.send(&[instructions::update_profile(&creator.pubkey(), "QmProfile")], &[&creator]);
+    assert_error(result, CustomError::InvalidLegacyCreator);
+    let add_content = instructions::add_content(&creator.pubkey(), &creator.pubkey(), 3, sol_content(PRICE));
+    assert_error(env.send(&[add_content], &[&creator]), CustomError::InvalidLegacyCreator);
+
+    env.send(&[instructions::migrate_creator_content(&creator.pubkey(), &[2])], &[&creator])
+        .unwrap();
+    env.purchase(&buyer, &creator.pubkey(), 1, 1_000_000).unwrap();
+    let creator_account: auton_client::CreatorAccount = env.fetch(&pda::creator(&creator.pubkey()).0);
+    assert_eq!(creator_account.sales_count, 1);
+}
+
+#[test]
+fn migrate_creator_content_requires_the_creator() {
+    let mut env = TestEnv::new();
+    let creator = Pubkey::new_unique();
+    write_legacy_creator(&mut env, &creator, &[(1, "Pilot", 1_000_000)], "");
+    let stranger = env.funded_wallet();
+
+    let mut instruction = instructions::migrate_creator_content(&stranger.pubkey(), &[]);
+    instruction.accounts[0].pubkey = pda::creator(&creator).0;
+    let result = env.send(&[instruction], &[&stranger]);
+    assert!(result.is_err());
+    assert!(!env.exists(&pda::content(&creator, 1).0));
+}
+
+// ---------------------------------------------------------------------------
+// Subscriptions
+// ---------------------------------------------------------------------------
+
+const DAY: i64 = 24 * 60 * 60;
+
+fn create_tier(env: &mut TestEnv, creator: &Keypair, tier_id: u8, terms: SubscriptionTierArgs) -> TransactionResult {
+    env.send(
+        &[instructions::create_subscription_tier(
+            &creator.pubkey(),
+            &creator.pubkey(),
+            tier_id,
+            terms,
+            None,
+            TransferFeePayer::Buyer,
+        )],
+        &[creator],
+    )
+}
+
+// Accepts any price; the price limit itself is tested separately.
+fn subscribe(env: &mut TestEnv, subscriber: &Keypair, creator: &Pubkey, tier_id: u8, periods: u32) -> TransactionResult {
+    env.send(
+        &[instructions::subscribe(&subscriber.pubkey(), creator, tier_id, periods, u64::MAX, None, None)],
+        &[subscriber],
+    )
+}
+
+fn renew(env: &mut TestEnv, subscriber: &Keypair, creator: &Pubkey, tier_id: u8, periods: u32) -> TransactionResult {
+    env.send(
+        &[instructions::renew_subscription(&subscriber.pubkey(), creator, tier_id, periods, u64::MAX, None, None)],
+        &[subscriber],
+    )
+}
+
+#[test]
+fn subscribe_and_renew_extend_from_the_current_expiry() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let subscriber = env.funded_wallet();
+    create_tier(&mut env, &creator, 1, tier_terms(PRICE, 30 * DAY, vec![])).unwrap();
+
+    let creator_before = env.balance(&creator.pubkey());
+    subscribe(&mut env, &subscriber, &creator.pubkey(), 1, 2).unwrap();
+    let subscription_pda = pda::subscription(&subscriber.pubkey(), &creator.pubkey()).0;
+    let subscription: auton_client::Subscription = env.fetch(&subscription_pda);
+    assert_eq!(subscription.expires_at, env.now() + 60 * DAY);
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + 2 * (PRICE - fee_of(PRICE)));
+
+    env.warp_by(10 * DAY);
+    renew(&mut env, &subscriber, &creator.pubkey(), 1, 1).unwrap();
+    let renewed: auton_client::Subscription = env.fetch(&subscription_pda);
+    assert_eq!(renewed.expires_at, subscription.expires_at + 30 * DAY);
+    assert_eq!(renewed.started_at, subscription.started_at);
+}
+
+#[test]
+fn an_active_subscription_cannot_switch_tiers() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let subscriber = env.funded_wallet();
+    create_tier(&mut env, &creator, 1, tier_terms(PRICE, DAY, vec![])).unwrap();
+    create_tier(&mut env, &creator, 2, tier_terms(2 * PRICE, DAY, vec![])).unwrap();
+
+    subscribe(&mut env, &subscriber, &creator.pubkey(), 1, 1).unwrap();
+    assert_error(renew(&mut env, &subscriber, &creator.pubkey(), 2, 1), CustomError::SubscriptionTierMismatch);
+
+    // Once lapsed, renewing restarts from now and may pick another tier.
+    env.warp_by(2 * DAY);
+    renew(&mut env, &subscriber, &creator.pubkey(), 2, 1).unwrap();
+    let subscription: auton_client::Subscription =
+        env.fetch(&pda::subscription(&subscriber.pubkey(), &creator.pubkey()).0);
+    assert_eq!(subscription.tier_id, 2);
+    assert_eq!(subscription.started_at, env.now());
+    assert_eq!(subscription.expires_at, env.now() + DAY);
+}
+
+#[test]
+fn subscription_tiers_validate_their_terms() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+
+    assert_error(create_tier(&mut env, &creator, 1, tier_terms(PRICE, 0, vec![])), CustomError::InvalidSubscriptionPeriod);
+    assert_error(
+        create_tier(&mut env, &creator, 1, tier_terms(PRICE, DAY, (1..=33).collect())),
+        CustomError::TooManyTierContentIds,
+    );
+
+    create_tier(&mut env, &creator, 1, tier_terms(PRICE, DAY, vec![1])).unwrap();
+    let result = env.send(
+        &[instructions::update_subscription_tier(&creator.pubkey(), 1, tier_terms(PRICE, -1, vec![]))],
+        &[&creator],
+    );
+    assert_error(result, CustomError::InvalidSubscriptionPeriod);
+
+    env.send(
+        &[instructions::update_subscription_tier(&creator.pubkey(), 1, tier_terms(3 * PRICE, 7 * DAY, vec![1, 2]))],
+        &[&creator],
+    )
+    .unwrap();
+    let tier: auton_client::SubscriptionTier = env.fetch(&pda::subscription_tier(&creator.pubkey(), 1).0);
+    assert_eq!(tier.price, 3 * PRICE);
+    assert_eq!(tier.period_seconds, 7 * DAY);
+    assert_eq!(tier.content_ids, vec![1, 2]);
+}
+
+#[test]
+fn subscribe_rejects_zero_periods_and_overflowing_totals() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let subscriber = env.funded_wallet();
+    create_tier(&mut env, &creator, 1, tier_terms(u64::MAX / 2, DAY, vec![])).unwrap();
+
+    assert_error(subscribe(&mut env, &subscriber, &creator.pubkey(), 1, 0), CustomError::InvalidSubscriptionPeriod);
+    assert_error(subscribe(&mut env, &subscriber, &creator.pubkey(), 1, 3), CustomError::MathOverflow);
+}
+
+#[test]
+fn subscriptions_pay_the_creators_fee_and_count_towards_fee_tiers() {
+    let mut env = TestEnv::new();
+    let admin = env.admin.insecure_clone();
+    let creator = env.creator();
+    let subscriber = env.funded_wallet();
+    create_tier(&mut env, &creator, 1, tier_terms(PRICE, DAY, vec![])).unwrap();
+    let tiers = vec![FeeTier { min_sales: 1, fee_bps: 300 }];
+    env.send(&[instructions::set_fee_tiers(&admin.pubkey(), tiers)], &[&admin])
+        .unwrap();
+
+    let creator_before = env.balance(&creator.pubkey());
+    subscribe(&mut env, &subscriber, &creator.pubkey(), 1, 1).unwrap();
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + PRICE - fee_of(PRICE));
+    let creator_account: auton_client::CreatorAccount = env.fetch(&pda::creator(&creator.pubkey()).0);
+    assert_eq!(creator_account.sales_count, 1);
+
+    let creator_before = env.balance(&creator.pubkey());
+    renew(&mut env, &subscriber, &creator.pubkey(), 1, 1).unwrap();
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + PRICE - PRICE * 300 / 10_000);
+
+    env.send(&[instructions::create_fee_override(&admin.pubkey(), &creator.pubkey(), 100, None)], &[&admin])
+        .unwrap();
+    let creator_before = env.balance(&creator.pubkey());
+    renew(&mut env, &subscriber, &creator.pubkey(), 1, 1).unwrap();
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + PRICE - PRICE * 100 / 10_000);
+    let creator_account: auton_client::CreatorAccount = env.fetch(&pda::creator(&creator.pubkey()).0);
+    assert_eq!(creator_account.sales_count, 3);
+}
+
+#[test]
+fn subscriptions_enforce_the_subscribers_price_and_fee_limits() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let subscriber = env.funded_wallet();
+    create_tier(&mut env, &creator, 1, tier_terms(PRICE, DAY, vec![])).unwrap();
+    let subscriber_key = subscriber.pubkey();
+    let creator_key = creator.pubkey();
+
+    // The limit covers every period paid for.
+    let over_limit = instructions::subscribe(&subscriber_key, &creator_key, 1, 2, 2 * PRICE - 1, None, None);
+    assert_error(env.send(&[over_limit], &[&subscriber]), CustomError::PriceAboveMaximum);
+    let fee_too_high = instructions::subscribe(&subscriber_key, &creator_key, 1, 2, 2 * PRICE, Some(FEE_BPS - 1), None);
+    assert_error(env.send(&[fee_too_high], &[&subscriber]), CustomError::FeeAboveMaximum);
+    let within_limits = instructions::subscribe(&subscriber_key, &creator_key, 1, 2, 2 * PRICE, Some(FEE_BPS), None);
+    env.send(&[within_limits], &[&subscriber]).unwrap();
+
+    // A tier repriced after the renewal was signed is rejected.
+    env.send(
+        &[instructions::update_subscription_tier(&creator_key, 1, tier_terms(2 * PRICE, DAY, vec![]))],
+        &[&creator],
+    )
+    .unwrap();
+    let renewal = instructions::renew_subscription(&subscriber_key, &creator_key, 1, 1, PRICE, None, None);
+    assert_error(env.send(&[renewal], &[&subscriber]), CustomError::PriceAboveMaximum);
+    let fee_too_high = instructions::renew_subscription(&subscriber_key, &creator_key, 1, 1, 2 * PRICE, Some(0), None);
+    assert_error(env.send(&[fee_too_high], &[&subscriber]), CustomError::FeeAboveMaximum);
+}
+
+#[test]
+fn subscriptions_do_not_unlock_limited_editions_or_unlisted_content() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let subscriber = env.funded_wallet();
+    for _ in 0..3 {
+        env.add_content(&creator, PRICE);
+    }
+    set_max_supply(&mut env, &creator, 2, 10).unwrap();
+    env.send(&[instructions::unlist_content(&creator.pubkey(), 3)], &[&creator]).unwrap();
+    create_tier(&mut env, &creator, 1, tier_terms(PRICE, DAY, vec![])).unwrap();
+    subscribe(&mut env, &subscriber, &creator.pubkey(), 1, 1).unwrap();
+
+    let subscription: auton_client::Subscription =
+        env.fetch(&pda::subscription(&subscriber.pubkey(), &creator.pubkey()).0);
+    let tier: auton_client::SubscriptionTier = env.fetch(&pda::subscription_tier(&creator.pubkey(), 1).0);
+    let grants_access = |env: &TestEnv, content_id: u64| {
+        let item: auton_client::ContentItem = env.fetch(&pda::content(&creator.pubkey(), content_id).0);
+        subscription.grants_access(&tier, &item, env.now())
+    };
+    assert!(grants_access(&env, 1));
+    assert!(!grants_access(&env, 2));
+    assert!(!grants_access(&env, 3));
+
+    env.warp_by(DAY);
+    assert!(!grants_access(&env, 1));
+}
+
+// ---------------------------------------------------------------------------
+// Bundles
+// ---------------------------------------------------------------------------
+
+fn bundle_terms(content_ids: Vec<u64>, price: u64, discount_bps: Option<u64>) -> BundleArgs {
+    BundleArgs { content_ids, price, discount_bps }
+}
+
+fn create_bundle(env: &mut TestEnv, creator: &Keypair, bundle_id: u8, terms: BundleArgs) -> TransactionResult {
+    env.send(
+        &[instructions::create_bundle(&creator.pubkey(), &creator.pubkey(), bundle_id, terms, None, TransferFeePayer::Buyer)],
+        &[creator],
+    )
+}
+
+// Passes the bundle's current content items, or none once the bundle has been closed.
+fn purchase_bundle(env: &mut TestEnv, buyer: &Keypair, creator: &Pubkey, bundle_id: u8, max_price: u64) -> TransactionResult {
+    let bundle_pda = pda::bundle(creator, bundle_id).0;
+    let content_ids = if env.exists(&bundle_pda) {
+        env.fetch::<auton_client::Bundle>(&bundle_pda).content_ids
+    } else {
+        vec![]
+    };
+    env.send(
+        &[instructions::purchase_bundle(&buyer.pubkey(), creator, bundle_id, &content_ids, max_price, None, None)],
+        &[buyer],
+    )
+}
+
+#[test]
+fn bundle_purchases_pay_the_discounted_price_once_for_every_item() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    for _ in 0..3 {
+        env.add_content(&creator, PRICE);
+    }
+    // 20% off three items
+    create_bundle(&mut env, &creator, 1, bundle_terms(vec![1, 2, 3], 3 * PRICE, Some(2_000))).unwrap();
+
+    let sale_price = 3 * PRICE * 8 / 10;
+    let creator_before = env.balance(&creator.pubkey());
+    let treasury_before = env.balance(&pda::treasury().0);
+    purchase_bundle(&mut env, &buyer, &creator.pubkey(), 1, sale_price).unwrap();
+
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + sale_price - fee_of(sale_price));
+    assert_eq!(env.balance(&pda::treasury().0), treasury_before + fee_of(sale_price));
+
+    let receipt: auton_client::BundleReceipt =
+        env.fetch(&pda::bundle_receipt(&buyer.pubkey(), &creator.pubkey(), 1).0);
+    assert_eq!(receipt.content_ids, vec![1, 2, 3]);
+    assert_eq!(receipt.price, sale_price);
+    assert!(receipt.grants_access(&creator.pubkey(), 2));
+    assert!(!receipt.grants_access(&creator.pubkey(), 4));
+    assert!(!receipt.grants_access(&buyer.pubkey(), 2));
+
+    // One bundle is one sale towards the creator's volume tier.
+    let creator_account: auton_client::CreatorAccount = env.fetch(&pda::creator(&creator.pubkey()).0);
+    assert_eq!(creator_account.sales_count, 1);
+    assert!(purchase_bundle(&mut env, &buyer, &creator.pubkey(), 1, sale_price).is_err());
+}
+
+#[test]
+fn bundles_validate_their_content_and_discount() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    env.add_content(&creator, PRICE);
+    env.add_content(&creator, PRICE);
+
+    for content_ids in [vec![], vec![0, 1], vec![1, 3], vec![1, 2, 1]] {
+        assert_error(
+            create_bundle(&mut env, &creator, 1, bundle_terms(content_ids, PRICE, None)),
+            CustomError::InvalidBundle,
+        );
+    }
+    assert_error(
+        create_bundle(&mut env, &creator, 1, bundle_terms(vec![1, 2], PRICE, Some(10_001))),
+        CustomError::InvalidDiscount,
+    );
+
+    create_bundle(&mut env, &creator, 1, bundle_terms(vec![1, 2], PRICE, Some(10_000))).unwrap();
+    let result = env.send(
+        &[instructions::update_bundle(&creator.pubkey(), 1, bundle_terms(vec![2, 3], PRICE, None))],
+        &[&creator],
+    );
+    assert_error(result, CustomError::InvalidBundle);
+}
+
+#[test]
+fn purchase_bundle_enforces_the_buyers_price_limit() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    env.add_content(&creator, PRICE);
+    create_bundle(&mut env, &creator, 1, bundle_terms(vec![1], PRICE, Some(1_000))).unwrap();
+
+    let sale_price = PRICE * 9 / 10;
+    assert_error(
+        purchase_bundle(&mut env, &buyer, &creator.pubkey(), 1, sale_price - 1),
+        CustomError::PriceAboveMaximum,
+    );
+    purchase_bundle(&mut env, &buyer, &creator.pubkey(), 1, sale_price).unwrap();
+}
+
+#[test]
+fn bundle_receipts_keep_the_content_they_were_bought_with() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let late_buyer = env.funded_wallet();
+    for _ in 0..3 {
+        env.add_content(&creator, PRICE);
+    }
+    create_bundle(&mut env, &creator, 1, bundle_terms(vec![1, 2], 2 * PRICE, None)).unwrap();
+    purchase_bundle(&mut env, &buyer, &creator.pubkey(), 1, 2 * PRICE).unwrap();
+
+    env.send(
+        &[instructions::update_bundle(&creator.pubkey(), 1, bundle_terms(vec![3], PRICE, None))],
+        &[&creator],
+    )
+    .unwrap();
+    let bundle: auton_client::Bundle = env.fetch(&pda::bundle(&creator.pubkey(), 1).0);
+    assert_eq!(bundle.content_ids, vec![3]);
+
+    env.send(&[instructions::close_bundle(&creator.pubkey(), 1)], &[&creator])
+        .unwrap();
+    assert!(!env.exists(&pda::bundle(&creator.pubkey(), 1).0));
+    assert_anchor_error(
+        purchase_bundle(&mut env, &late_buyer, &creator.pubkey(), 1, PRICE),
+        anchor_lang::error::ErrorCode::AccountNotInitialized,
+    );
+
+    let receipt: auton_client::BundleReceipt =
+        env.fetch(&pda::bundle_receipt(&buyer.pubkey(), &creator.pubkey(), 1).0);
+    assert_eq!(receipt.content_ids, vec![1, 2]);
+}
+
+#[test]
+fn bundles_exclude_limited_editions_and_unlisted_content() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    for _ in 0..3 {
+        env.add_content(&creator, PRICE);
+    }
+    set_max_supply(&mut env, &creator, 2, 10).unwrap();
+    env.send(&[instructions::unlist_content(&creator.pubkey(), 3)], &[&creator]).unwrap();
+
+    for content_ids in [vec![1, 2], vec![1, 3]] {
+        assert_error(
+            create_bundle(&mut env, &creator, 1, bundle_terms(content_ids, PRICE, None)),
+            CustomError::ContentNotBundleable,
+        );
+    }
+    create_bundle(&mut env, &creator, 1, bundle_terms(vec![1], PRICE, None)).unwrap();
+    let result = env.send(
+        &[instructions::update_bundle(&creator.pubkey(), 1, bundle_terms(vec![1, 2], PRICE, None))],
+        &[&creator],
+    );
+    assert_error(result, CustomError::ContentNotBundleable);
+
+    // The bundle's items must be passed, and are checked again when it is bought.
+    let without_items = instructions::purchase_bundle(&buyer.pubkey(), &creator.pubkey(), 1, &[], PRICE, None, None);
+    assert_error(env.send(&[without_items], &[&buyer]), CustomError::InvalidBundle);
+    env.send(&[instructions::unlist_content(&creator.pubkey(), 1)], &[&creator]).unwrap();
+    assert_error(purchase_bundle(&mut env, &buyer, &creator.pubkey(), 1, PRICE), CustomError::ContentNotBundleable);
+    env.send(&[instructions::relist_content(&creator.pubkey(), 1)], &[&creator]).unwrap();
+    purchase_bundle(&mut env, &buyer, &creator.pubkey(), 1, PRICE).unwrap();
+}
+
+// ---------------------------------------------------------------------------
+// Coupons
+// ---------------------------------------------------------------------------
+
+fn coupon_terms(discount: CouponDiscount) -> CouponArgs {
+    CouponArgs { discount, max_redemptions: None, max_per_buyer: None, expires_at: None }
+}
+
+fn create_coupon(env: &mut TestEnv, creator: &Keypair, code: &str, terms: CouponArgs) -> TransactionResult {
+    env.send(
+        &[instructions::create_coupon(&creator.pubkey(), &creator.pubkey(), code, terms, None)],
+        &[creator],
+    )
+}
+
+// Buys a SOL-priced item with one of its creator's coupons, accepting any price up to `PRICE`.
+fn coupon_purchase(
+    env: &mut TestEnv,
+    buyer: &Keypair,
+    creator: &Pubkey,
+    content_id: u64,
+    code: &str,
+    per_buyer_limit: bool,
+) -> TransactionResult {
+    let coupon = CouponPayment { coupon: pda::coupon(creator, code).0, per_buyer_limit };
+    env.send(
+        &[instructions::process_payment(&buyer.pubkey(), creator, content_id, PRICE, None, None, Some(&coupon), None)],
+        &[buyer],
+    )
+}
+
+#[test]
+fn coupons_discount_the_price_before_the_fee_split() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+    create_coupon(&mut env, &creator, "LAUNCH25", coupon_terms(CouponDiscount::PercentOff { bps: 2_500 })).unwrap();
+
+    let price = PRICE * 3 / 4;
+    let creator_before = env.balance(&creator.pubkey());
+    let treasury_before = env.balance(&pda::treasury().0);
+    coupon_purchase(&mut env, &buyer, &creator.pubkey(), content_id, "LAUNCH25", false).unwrap();
+
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + price - fee_of(price));
+    assert_eq!(env.balance(&pda::treasury().0), treasury_before + fee_of(price));
+
+    let coupon_pda = pda::coupon(&creator.pubkey(), "LAUNCH25").0;
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0);
+    assert_eq!(receipt.price, price);
+    assert_eq!(receipt.discount_amount, PRICE - price);
+    assert_eq!(receipt.coupon, Some(coupon_pda));
+    let coupon: auton_client::Coupon = env.fetch(&coupon_pda);
+    assert_eq!(coupon.redemptions, 1);
+}
+
+#[test]
+fn amount_off_coupons_never_take_the_price_below_zero() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+    create_coupon(&mut env, &creator, "FREE", coupon_terms(CouponDiscount::AmountOff { amount: 2 * PRICE })).unwrap();
+
+    let creator_before = env.balance(&creator.pubkey());
+    coupon_purchase(&mut env, &buyer, &creator.pubkey(), content_id, "FREE", false).unwrap();
+    assert_eq!(env.balance(&creator.pubkey()), creator_before);
+
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0);
+    assert_eq!(receipt.price, 0);
+    assert_eq!(receipt.discount_amount, PRICE);
+}
+
+#[test]
+fn coupons_stop_working_once_used_up_or_expired() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let other_creator = env.creator();
+    let first_buyer = env.funded_wallet();
+    let second_buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+    let other_content_id = env.add_content(&other_creator, PRICE);
+
+    let once = CouponArgs { max_redemptions: Some(1), ..coupon_terms(CouponDiscount::PercentOff { bps: 1_000 }) };
+    create_coupon(&mut env, &creator, "ONCE", once).unwrap();
+    coupon_purchase(&mut env, &first_buyer, &creator.pubkey(), content_id, "ONCE", false).unwrap();
+    assert_error(
+        coupon_purchase(&mut env, &second_buyer, &creator.pubkey(), content_id, "ONCE", false),
+        CustomError::CouponLimitReached,
+    );
+
+    let expiring =
+        CouponArgs { expires_at: Some(env.now() + DAY), ..coupon_terms(CouponDiscount::PercentOff { bps: 1_000 }) };
+    create_coupon(&mut env, &creator, "WEEKEND", expiring).unwrap();
+    env.warp_by(DAY);
+    assert_error(
+        coupon_purchase(&mut env, &second_buyer, &creator.pubkey(), content_id, "WEEKEND", false),
+        CustomError::CouponExpired,
+    );
+
+    // Another creator's coupon doesn't apply.
+    create_coupon(&mut env, &creator, "MINE", coupon_terms(CouponDiscount::PercentOff { bps: 1_000 })).unwrap();
+    let coupon = CouponPayment { coupon: pda::coupon(&creator.pubkey(), "MINE").0, per_buyer_limit: false };
+    let result = env.send(
+        &[instructions::process_payment(
+            &second_buyer.pubkey(),
+            &other_creator.pubkey(),
+            other_content_id,
+            PRICE,
+            None,
+            None,
+            Some(&coupon),
+            None,
+        )],
+        &[&second_buyer],
+    );
+    assert_error(result, CustomError::CouponNotApplicable);
+}
+
+#[test]
+fn per_buyer_limits_count_uses_in_the_buyers_redemption_account() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let first = env.add_content(&creator, PRICE);
+    let second = env.add_content(&creator, PRICE);
+    let per_buyer = CouponArgs { max_per_buyer: Some(1), ..coupon_terms(CouponDiscount::PercentOff { bps: 1_000 }) };
+    create_coupon(&mut env, &creator, "FANS", per_buyer).unwrap();
+    let coupon_pda = pda::coupon(&creator.pubkey(), "FANS").0;
+
+    assert_error(
+        coupon_purchase(&mut env, &buyer, &creator.pubkey(), first, "FANS", false),
+        CustomError::CouponNotApplicable,
+    );
+
+    env.send(&[instructions::create_coupon_redemption(&buyer.pubkey(), &buyer.pubkey(), &coupon_pda)], &[&buyer])
+        .unwrap();
+    coupon_purchase(&mut env, &buyer, &creator.pubkey(), first, "FANS", true).unwrap();
+    let redemption: auton_client::CouponRedemption =
+        env.fetch(&pda::coupon_redemption(&coupon_pda, &buyer.pubkey()).0);
+    assert_eq!(redemption.redemptions, 1);
+    assert_error(
+        coupon_purchase(&mut env, &buyer, &creator.pubkey(), second, "FANS", true),
+        CustomError::CouponLimitReached,
+    );
+
+    // Another buyer can't use someone else's redemption account.
+    let other_buyer = env.funded_wallet();
+    let coupon = CouponPayment { coupon: coupon_pda, per_buyer_limit: true };
+    let mut instruction = instructions::process_payment(
+        &other_buyer.pubkey(),
+        &creator.pubkey(),
+        second,
+        PRICE,
+        None,
+        None,
+        Some(&coupon),
+        None,
+    );
+    let other_redemption = pda::coupon_redemption(&coupon_pda, &other_buyer.pubkey()).0;
+    for meta in instruction.accounts.iter_mut().filter(|meta| meta.pubkey == other_redemption) {
+        meta.pubkey = pda::coupon_redemption(&coupon_pda, &buyer.pubkey()).0;
+    }
+    assert_error(env.send(&[instruction], &[&other_buyer]), CustomError::CouponNotApplicable);
+}
+
+#[test]
+fn coupons_validate_their_terms_and_can_be_removed() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let percent = |bps| coupon_terms(CouponDiscount::PercentOff { bps });
+
+    assert_error(create_coupon(&mut env, &creator, "", percent(1_000)), CustomError::InvalidCoupon);
+    assert_error(create_coupon(&mut env, &creator, "ZERO", percent(0)), CustomError::InvalidCoupon);
+    assert_error(create_coupon(&mut env, &creator, "MORE", percent(10_001)), CustomError::InvalidCoupon);
+    let nothing_off = coupon_terms(CouponDiscount::AmountOff { amount: 0 });
+    assert_error(create_coupon(&mut env, &creator, "NONE", nothing_off), CustomError::InvalidCoupon);
+    let unusable = CouponArgs { max_redemptions: Some(0), ..percent(1_000) };
+    assert_error(create_coupon(&mut env, &creator, "NEVER", unusable), CustomError::InvalidCoupon);
+    let expired = CouponArgs { expires_at: Some(env.now()), ..percent(1_000) };
+    assert_error(create_coupon(&mut env, &creator, "OLD", expired), CustomError::InvalidCoupon);
+
+    create_coupon(&mut env, &creator, "SALE", percent(1_000)).unwrap();
+    env.send(&[instructions::close_coupon(&creator.pubkey(), "SALE")], &[&creator])
+        .unwrap();
+    assert!(!env.exists(&pda::coupon(&creator.pubkey(), "SALE").0));
+}
+
+// ---------------------------------------------------------------------------
+// Pay what you want and tips
+// ---------------------------------------------------------------------------
+
+fn create_supporter_stats(env: &mut TestEnv, creator: &Keypair) {
+    env.send(&[instructions::create_supporter_stats(&creator.pubkey(), &creator.pubkey(), None)], &[creator])
+        .unwrap();
+}
+
+fn tip(env: &mut TestEnv, tipper: &Keypair, creator: &Pubkey, amount: u64, message: Option<&str>) -> TransactionResult {
+    env.send(&[instructions::tip_creator(&tipper.pubkey(), creator, amount, None, message, None)], &[tipper])
+}
+
+#[test]
+fn pay_what_you_want_buyers_pay_their_offer_above_the_minimum() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    let enable = instructions::set_pay_what_you_want(&creator.pubkey(), content_id, true, None);
+    assert_error(env.send(&[enable.clone()], &[&creator]), CustomError::SupporterStatsMissing);
+    create_supporter_stats(&mut env, &creator);
+    env.send(&[enable], &[&creator]).unwrap();
+
+    let below_minimum =
+        instructions::process_payment(&buyer.pubkey(), &creator.pubkey(), content_id, PRICE - 1, None, None, None, None);
+    assert_error(env.send(&[below_minimum], &[&buyer]), CustomError::PriceAboveMaximum);
+
+    let offer = 3 * PRICE;
+    let creator_before = env.balance(&creator.pubkey());
+    env.purchase(&buyer, &creator.pubkey(), content_id, offer).unwrap();
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + offer - fee_of(offer));
+
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0);
+    assert_eq!(receipt.price, offer);
+    let stats: auton_client::SupporterStats = env.fetch(&pda::supporter_stats(&creator.pubkey(), None).0);
+    assert_eq!(stats.pay_what_you_want_count, 1);
+    assert_eq!(stats.total_overpaid, offer - PRICE);
+}
+
+#[test]
+fn fixed_price_content_ignores_a_higher_maximum() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    let creator_before = env.balance(&creator.pubkey());
+    env.purchase(&buyer, &creator.pubkey(), content_id, 3 * PRICE).unwrap();
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + PRICE - fee_of(PRICE));
+}
+
+#[test]
+fn tips_pay_the_creator_through_the_fee_split_and_are_counted() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let tipper = env.funded_wallet();
+
+    // Creators opt into tips by creating their supporter stats.
+    assert_anchor_error(
+        tip(&mut env, &tipper, &creator.pubkey(), PRICE, None),
+        anchor_lang::error::ErrorCode::AccountNotInitialized,
+    );
+    create_supporter_stats(&mut env, &creator);
+
+    let creator_before = env.balance(&creator.pubkey());
+    let treasury_before = env.balance(&pda::treasury().0);
+    tip(&mut env, &tipper, &creator.pubkey(), PRICE, Some("Thanks for the series!")).unwrap();
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + PRICE - fee_of(PRICE));
+    assert_eq!(env.balance(&pda::treasury().0), treasury_before + fee_of(PRICE));
+
+    let stats: auton_client::SupporterStats = env.fetch(&pda::supporter_stats(&creator.pubkey(), None).0);
+    assert_eq!(stats.tip_count, 1);
+    assert_eq!(stats.total_tipped, PRICE);
+    // Tips aren't sales.
+    let creator_account: auton_client::CreatorAccount = env.fetch(&pda::creator(&creator.pubkey()).0);
+    assert_eq!(creator_account.sales_count, 0);
+}
+
+#[test]
+fn tips_are_validated() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let other_creator = env.creator();
+    let tipper = env.funded_wallet();
+    create_supporter_stats(&mut env, &creator);
+    create_supporter_stats(&mut env, &other_creator);
+
+    assert_error(tip(&mut env, &tipper, &creator.pubkey(), 0, None), CustomError::InvalidTipAmount);
+    let long_message = "x".repeat(141);
+    assert_error(
+        tip(&mut env, &tipper, &creator.pubkey(), PRICE, Some(&long_message)),
+        CustomError::TipMessageTooLong,
+    );
+
+    // Another creator's stats
+    let mut instruction = instructions::tip_creator(&tipper.pubkey(), &creator.pubkey(), PRICE, None, None, None);
+    let stats = pda::supporter_stats(&creator.pubkey(), None).0;
+    for meta in instruction.accounts.iter_mut().filter(|meta| meta.pubkey == stats) {
+        meta.pubkey = pda::supporter_stats(&other_creator.pubkey(), None).0;
+    }
+    assert_error(env.send(&[instruction], &[&tipper]), CustomError::InvalidSupporterStats);
+}
+
+// ---------------------------------------------------------------------------
+// Rentals
+// ---------------------------------------------------------------------------
+
+const RENTAL_PRICE: u64 = PRICE / 4;
+const RENTAL_DURATION: i64 = 2 * DAY;
+
+fn set_rental(env: &mut TestEnv, creator: &Keypair, content_id: u64, rental: Option<RentalTerms>) -> TransactionResult {
+    env.send(&[instructions::set_rental_terms(&creator.pubkey(), content_id, rental)], &[creator])
+}
+
+// A SOL-priced content item offered for rent at `RENTAL_PRICE` for `RENTAL_DURATION`.
+fn rentable_content(env: &mut TestEnv, creator: &Keypair) -> u64 {
+    let content_id = env.add_content(creator, PRICE);
+    let rental = RentalTerms { price: RENTAL_PRICE, duration: RENTAL_DURATION };
+    set_rental(env, creator, content_id, Some(rental)).unwrap();
+    content_id
+}
+
+fn rent(env: &mut TestEnv, buyer: &Keypair, creator: &Pubkey, content_id: u64) -> TransactionResult {
+    env.send(
+        &[instructions::rent_content(&buyer.pubkey(), creator, content_id, RENTAL_PRICE, None, None)],
+        &[buyer],
+    )
+}
+
+fn renew_rental(env: &mut TestEnv, buyer: &Keypair, creator: &Pubkey, content_id: u64) -> TransactionResult {
+    env.send(
+        &[instructions::renew_rental(&buyer.pubkey(), creator, content_id, RENTAL_PRICE, None, None)],
+        &[buyer],
+    )
+}
+
+#[test]
+fn rentals_pay_the_rental_price_and_expire() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = rentable_content(&mut env, &creator);
+
+    let creator_before = env.balance(&creator.pubkey());
+    rent(&mut env, &buyer, &creator.pubkey(), content_id).unwrap();
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + RENTAL_PRICE - fee_of(RENTAL_PRICE));
+
+    let receipt_pda = pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0;
+    let receipt: PaidAccessAccount = env.fetch(&receipt_pda);
+    assert_eq!(receipt.price, RENTAL_PRICE);
+    assert_eq!(receipt.expires_at, Some(env.now() + RENTAL_DURATION));
+    assert!(receipt.is_active(env.now()));
+    let creator_account: auton_client::CreatorAccount = env.fetch(&pda::creator(&creator.pubkey()).0);
+    assert_eq!(creator_account.sales_count, 1);
+
+    env.warp_by(RENTAL_DURATION);
+    assert!(!receipt.is_active(env.now()));
+}
+
+#[test]
+fn renewals_extend_an_active_rental_and_restart_an_expired_one() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = rentable_content(&mut env, &creator);
+    let receipt_pda = pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0;
+
+    rent(&mut env, &buyer, &creator.pubkey(), content_id).unwrap();
+    let rented: PaidAccessAccount = env.fetch(&receipt_pda);
+    // The receipt already exists, so renting again goes through `renew_rental`.
+    assert!(rent(&mut env, &buyer, &creator.pubkey(), content_id).is_err());
+
+    env.warp_by(DAY);
+    renew_rental(&mut env, &buyer, &creator.pubkey(), content_id).unwrap();
+    let extended: PaidAccessAccount = env.fetch(&receipt_pda);
+    assert_eq!(extended.expires_at, Some(rented.expires_at.unwrap() + RENTAL_DURATION));
+    assert_eq!(extended.created_at, rented.created_at);
+
+    env.warp_by(10 * DAY);
+    let creator_before = env.balance(&creator.pubkey());
+    renew_rental(&mut env, &buyer, &creator.pubkey(), content_id).unwrap();
+    let rented_again: PaidAccessAccount = env.fetch(&receipt_pda);
+    assert_eq!(rented_again.expires_at, Some(env.now() + RENTAL_DURATION));
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + RENTAL_PRICE - fee_of(RENTAL_PRICE));
+}
+
+#[test]
+fn purchases_are_not_rentals() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = rentable_content(&mut env, &creator);
+
+    env.purchase(&buyer, &creator.pubkey(), content_id, PRICE).unwrap();
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0);
+    assert_eq!(receipt.expires_at, None);
+    assert!(receipt.is_active(env.now() + 100 * RENTAL_DURATION));
+
+    assert!(rent(&mut env, &buyer, &creator.pubkey(), content_id).is_err());
+    assert_error(renew_rental(&mut env, &buyer, &creator.pubkey(), content_id), CustomError::NotARental);
+}
+
+#[test]
+fn renters_can_buy_outright_during_or_after_a_rental() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let renter = env.funded_wallet();
+    let lapsed_renter = env.funded_wallet();
+    let content_id = rentable_content(&mut env, &creator);
+
+    rent(&mut env, &renter, &creator.pubkey(), content_id).unwrap();
+    rent(&mut env, &lapsed_renter, &creator.pubkey(), content_id).unwrap();
+    env.warp_by(DAY);
+
+    // An active rental becomes a permanent receipt, paid at the full price.
+    let creator_before = env.balance(&creator.pubkey());
+    env.purchase(&renter, &creator.pubkey(), content_id, PRICE).unwrap();
+    assert_eq!(env.balance(&creator.pubkey()), creator_before + PRICE - fee_of(PRICE));
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&renter.pubkey(), &creator.pubkey(), content_id).0);
+    assert_eq!(receipt.expires_at, None);
+    assert_eq!(receipt.price, PRICE);
+    assert_eq!(receipt.edition, Some(1));
+    assert!(receipt.is_active(env.now() + 100 * RENTAL_DURATION));
+
+    // So does an expired one.
+    env.warp_by(RENTAL_DURATION);
+    env.purchase(&lapsed_renter, &creator.pubkey(), content_id, PRICE).unwrap();
+    assert_eq!(edition_of(&env, &lapsed_renter, &creator, content_id), Some(2));
+
+    // Once bought, the receipt can't be bought, rented or renewed again.
+    assert_error(env.purchase(&renter, &creator.pubkey(), content_id, PRICE), CustomError::AlreadyPurchased);
+    assert!(rent(&mut env, &renter, &creator.pubkey(), content_id).is_err());
+    assert_error(renew_rental(&mut env, &renter, &creator.pubkey(), content_id), CustomError::NotARental);
+}
+
+#[test]
+fn rentals_need_rental_terms_on_listed_content() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let other_creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    assert_error(rent(&mut env, &buyer, &creator.pubkey(), content_id), CustomError::RentalUnavailable);
+    let no_duration = RentalTerms { price: RENTAL_PRICE, duration: 0 };
+    assert_error(set_rental(&mut env, &creator, content_id, Some(no_duration)), CustomError::InvalidRentalTerms);
+    let rental = RentalTerms { price: RENTAL_PRICE, duration: RENTAL_DURATION };
+    assert!(set_rental(&mut env, &other_creator, content_id, Some(rental)).is_err());
+
+    set_rental(&mut env, &creator, content_id, Some(rental)).unwrap();
+    let over_limit =
+        instructions::rent_content(&buyer.pubkey(), &creator.pubkey(), content_id, RENTAL_PRICE - 1, None, None);
+    assert_error(env.send(&[over_limit], &[&buyer]), CustomError::PriceAboveMaximum);
+
+    env.send(&[instructions::unlist_content(&creator.pubkey(), content_id)], &[&creator]).unwrap();
+    assert_error(rent(&mut env, &buyer, &creator.pubkey(), content_id), CustomError::ContentUnlisted);
+    env.send(&[instructions::relist_content(&creator.pubkey(), content_id)], &[&creator]).unwrap();
+
+    // Taking the terms away stops renewals but leaves existing rentals in place.
+    rent(&mut env, &buyer, &creator.pubkey(), content_id).unwrap();
+    set_rental(&mut env, &creator, content_id, None).unwrap();
+    assert_error(renew_rental(&mut env, &buyer, &creator.pubkey(), content_id), CustomError::RentalUnavailable);
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0);
+    assert!(receipt.is_active(env.now()));
+}
+
+// ---------------------------------------------------------------------------
+// Limited editions
+// ---------------------------------------------------------------------------
+
+fn set_max_supply(env: &mut TestEnv, creator: &Keypair, content_id: u64, max_supply: u64) -> TransactionResult {
+    env.send(&[instructions::set_max_supply(&creator.pubkey(), content_id, max_supply)], &[creator])
+}
+
+fn edition_of(env: &TestEnv, buyer: &Keypair, creator: &Keypair, content_id: u64) -> Option<u64> {
+    let receipt: PaidAccessAccount = env.fetch(&pda::receipt(&buyer.pubkey(), &creator.pubkey(), content_id).0);
+    receipt.edition
+}
+
+#[test]
+fn limited_editions_are_numbered_and_sell_out() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let content_id = env.add_content(&creator, PRICE);
+    set_max_supply(&mut env, &creator, content_id, 2).unwrap();
+
+    let buyers = [env.funded_wallet(), env.funded_wallet(), env.funded_wallet()];
+    env.purchase(&buyers[0], &creator.pubkey(), content_id, PRICE).unwrap();
+    env.purchase(&buyers[1], &creator.pubkey(), content_id, PRICE).unwrap();
+    assert_eq!(edition_of(&env, &buyers[0], &creator, content_id), Some(1));
+    assert_eq!(edition_of(&env, &buyers[1], &creator, content_id), Some(2));
+
+    assert_error(env.purchase(&buyers[2], &creator.pubkey(), content_id, PRICE), CustomError::SoldOut);
+    let item: auton_client::ContentItem = env.fetch(&pda::content(&creator.pubkey(), content_id).0);
+    assert_eq!(item.sold, 2);
+}
+
+#[test]
+fn unlimited_content_numbers_editions_too() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    env.purchase(&buyer, &creator.pubkey(), content_id, PRICE).unwrap();
+    assert_eq!(edition_of(&env, &buyer, &creator, content_id), Some(1));
+    let item: auton_client::ContentItem = env.fetch(&pda::content(&creator.pubkey(), content_id).0);
+    assert_eq!(item.max_supply, None);
+}
+
+#[test]
+fn max_supply_can_only_be_lowered_and_not_below_copies_sold() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let other_creator = env.creator();
+    let buyer = env.funded_wallet();
+    let content_id = env.add_content(&creator, PRICE);
+
+    assert!(set_max_supply(&mut env, &other_creator, content_id, 10).is_err());
+    set_max_supply(&mut env, &creator, content_id, 10).unwrap();
+    env.purchase(&buyer, &creator.pubkey(), content_id, PRICE).unwrap();
+
+    assert_error(set_max_supply(&mut env, &creator, content_id, 11), CustomError::InvalidMaxSupply);
+    assert_error(set_max_supply(&mut env, &creator, content_id, 0), CustomError::InvalidMaxSupply);
+    set_max_supply(&mut env, &creator, content_id, 1).unwrap();
+    let item: auton_client::ContentItem = env.fetch(&pda::content(&creator.pubkey(), content_id).0);
+    assert_eq!(item.max_supply, Some(1));
+}
+
+#[test]
+fn limited_editions_cannot_be_rented() {
+    let mut env = TestEnv::new();
+    let creator = env.creator();
+    let content_id = rentable_content(&mut env, &creator);
+    assert_error(set_max_supply(&mut env, &creator, content_id, 1), CustomError::LimitedEditionRental);
+
+    let limited_id = env.add_content(&creator, PRICE);
+    set_max_supply(&mut env, &creator, limited_id, 1).unwrap();
+    let rental = RentalTerms { price: RENTAL_PRICE, duration: RENTAL_DURATION };
+    assert_error(set_rental(&mut env, &creator, limited_id, Some(rental)), CustomError::LimitedEditionRental);
+    // Taking rental terms away is always allowed, and lets the item be capped.
+    set_rental(&mut env, &creator, limited_id, None).unwrap();
+    set_rental(&mut env, &creator, content_id, None).unwrap();
+    set_max_supply(&mut env, &creator, content_id, 1).unwrap();
+}
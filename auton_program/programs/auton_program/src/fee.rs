@@ -0,0 +1,194 @@
+// Platform fee math.
+// Kept free of accounts and CPIs so every payment path splits prices the same way
+// and the arithmetic can be tested off-chain.
+
+use anchor_lang::prelude::*;
+
+use crate::CustomError;
+
+// 10000 basis points = 100%
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// How the platform fee is rounded when `price * fee_bps` is not a whole multiple of 10000.
+// Whatever is rounded off the fee goes to the creator, so nothing is ever lost.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FeeRounding {
+    #[default]
+    Down,   // Truncate the fee; the creator keeps the remainder
+    Up,     // Any fractional fee rounds up to the next base unit
+    HalfUp, // Round to the nearest base unit, halves rounding up
+}
+
+// A price split into the platform fee and the creator's share.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeSplit {
+    pub fee_amount: u64,
+    pub creator_amount: u64,
+}
+
+// Splits `price` into the platform fee at `fee_bps` and the creator's share.
+// The product is taken in u128, so every u64 price works; `fee_amount + creator_amount`
+// always equals `price`.
+pub fn split_price(price: u64, fee_bps: u64, rounding: FeeRounding) -> Result<FeeSplit> {
+    require!(fee_bps <= BPS_DENOMINATOR, CustomError::InvalidFeePercentage);
+
+    let product = (price as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(CustomError::MathOverflow)?;
+    let denominator = BPS_DENOMINATOR as u128;
+    let bias = match rounding {
+        FeeRounding::Down => 0,
+        FeeRounding::Up => denominator - 1,
+        FeeRounding::HalfUp => denominator / 2,
+    };
+    let fee_amount = product
+        .checked_add(bias)
+        .ok_or(CustomError::MathOverflow)?
+        / denominator;
+
+    // fee_bps <= 10000, so the fee never exceeds the price and fits in a u64.
+    let fee_amount = u64::try_from(fee_amount).map_err(|_| error!(CustomError::MathOverflow))?;
+    let creator_amount = price
+        .checked_sub(fee_amount)
+        .ok_or(CustomError::MathOverflow)?;
+
+    Ok(FeeSplit { fee_amount, creator_amount })
+}
+
+// Splits `amount` between recipients in proportion to `shares_bps`, which must sum to 10000.
+// Each share is rounded down and the leftover base units go to the first recipient, so the
+// parts always add up to `amount`.
+pub fn split_shares(amount: u64, shares_bps: &[u64]) -> Result<Vec<u64>> {
+    let total_bps = shares_bps
+        .iter()
+        .try_fold(0u64, |total, share| total.checked_add(*share))
+        .ok_or(CustomError::MathOverflow)?;
+    require!(total_bps == BPS_DENOMINATOR, CustomError::InvalidRevenueSplit);
+
+    let mut parts = shares_bps
+        .iter()
+        .map(|share| {
+            // share <= 10000, so each part is at most `amount`.
+            (amount as u128 * *share as u128 / BPS_DENOMINATOR as u128) as u64
+        })
+        .collect::<Vec<_>>();
+    let distributed = parts
+        .iter()
+        .try_fold(0u64, |total, part| total.checked_add(*part))
+        .ok_or(CustomError::MathOverflow)?;
+    if let Some(first) = parts.first_mut() {
+        *first += amount - distributed;
+    }
+    Ok(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn rounding() -> impl Strategy<Value = FeeRounding> {
+        prop_oneof![
+            Just(FeeRounding::Down),
+            Just(FeeRounding::Up),
+            Just(FeeRounding::HalfUp),
+        ]
+    }
+
+    #[test]
+    fn rounds_according_to_the_mode() {
+        // 5% of 10 base units is 0.5
+        let split = |rounding| split_price(10, 500, rounding).unwrap().fee_amount;
+        assert_eq!(split(FeeRounding::Down), 0);
+        assert_eq!(split(FeeRounding::Up), 1);
+        assert_eq!(split(FeeRounding::HalfUp), 1);
+
+        // 5% of 29 base units is 1.45
+        let split = |rounding| split_price(29, 500, rounding).unwrap().fee_amount;
+        assert_eq!(split(FeeRounding::Down), 1);
+        assert_eq!(split(FeeRounding::Up), 2);
+        assert_eq!(split(FeeRounding::HalfUp), 1);
+    }
+
+    #[test]
+    fn handles_the_largest_price() {
+        let split = split_price(u64::MAX, BPS_DENOMINATOR, FeeRounding::Up).unwrap();
+        assert_eq!(split.fee_amount, u64::MAX);
+        assert_eq!(split.creator_amount, 0);
+    }
+
+    #[test]
+    fn rejects_fees_above_100_percent() {
+        assert!(split_price(100, BPS_DENOMINATOR + 1, FeeRounding::Down).is_err());
+    }
+
+    #[test]
+    fn split_shares_gives_the_remainder_to_the_first_recipient() {
+        assert_eq!(split_shares(10, &[3_333, 3_333, 3_334]).unwrap(), vec![4, 3, 3]);
+        assert_eq!(split_shares(100, &[BPS_DENOMINATOR]).unwrap(), vec![100]);
+    }
+
+    #[test]
+    fn split_shares_rejects_shares_not_summing_to_100_percent() {
+        assert!(split_shares(100, &[5_000, 4_999]).is_err());
+        assert!(split_shares(100, &[]).is_err());
+        assert!(split_shares(100, &[u64::MAX, 1]).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn fee_plus_creator_amount_equals_price(
+            price in any::<u64>(),
+            fee_bps in 0..=BPS_DENOMINATOR,
+            rounding in rounding(),
+        ) {
+            let split = split_price(price, fee_bps, rounding).unwrap();
+            prop_assert_eq!(split.fee_amount as u128 + split.creator_amount as u128, price as u128);
+        }
+
+        #[test]
+        fn fee_is_within_one_unit_of_the_exact_fee(
+            price in any::<u64>(),
+            fee_bps in 0..=BPS_DENOMINATOR,
+            rounding in rounding(),
+        ) {
+            let split = split_price(price, fee_bps, rounding).unwrap();
+            let exact_floor = price as u128 * fee_bps as u128 / BPS_DENOMINATOR as u128;
+            let fee = split.fee_amount as u128;
+            prop_assert!(fee == exact_floor || fee == exact_floor + 1);
+            if rounding == FeeRounding::Down {
+                prop_assert_eq!(fee, exact_floor);
+            }
+        }
+
+        #[test]
+        fn rounding_modes_are_ordered(price in any::<u64>(), fee_bps in 0..=BPS_DENOMINATOR) {
+            let fee = |rounding| split_price(price, fee_bps, rounding).unwrap().fee_amount;
+            prop_assert!(fee(FeeRounding::Down) <= fee(FeeRounding::HalfUp));
+            prop_assert!(fee(FeeRounding::HalfUp) <= fee(FeeRounding::Up));
+        }
+
+        #[test]
+        fn split_parts_add_up_to_the_amount(
+            amount in any::<u64>(),
+            cuts in proptest::collection::vec(0..=BPS_DENOMINATOR, 0..7),
+        ) {
+            // Turn sorted cut points into shares that sum to 10000.
+            let mut cuts = cuts;
+            cuts.sort_unstable();
+            let shares: Vec<u64> = std::iter::once(0)
+                .chain(cuts.iter().copied())
+                .zip(cuts.iter().copied().chain(std::iter::once(BPS_DENOMINATOR)))
+                .map(|(start, end)| end - start)
+                .collect();
+            let parts = split_shares(amount, &shares).unwrap();
+            prop_assert_eq!(parts.len(), shares.len());
+            prop_assert_eq!(parts.iter().map(|part| *part as u128).sum::<u128>(), amount as u128);
+        }
+
+        #[test]
+        fn rejects_any_fee_above_100_percent(price in any::<u64>(), fee_bps in (BPS_DENOMINATOR + 1)..) {
+            prop_assert!(split_price(price, fee_bps, FeeRounding::Down).is_err());
+        }
+    }
+}
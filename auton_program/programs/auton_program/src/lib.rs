@@ -1,10 +1,42 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::memo::{self, BuildMemo, Memo};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    memo_transfer::MemoTransfer, transfer_fee::TransferFeeConfig, BaseStateWithExtensions,
+    StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::{Account as SplTokenAccount, Mint as SplMint};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+pub mod fee;
+pub use fee::FeeRounding;
 
 // This is the program's on-chain ID.
 // It will be replaced with the real Program ID after deployment.
 declare_id!("9Dpgf1nWom5Psp6vwLs1J6WF7dVbySQwk8HhLSqXx62n");
 // CONSTANTS
 const MAX_PLATFORM_FEE_BPS: u64 = 10000; // Max 100% fee (10000 basis points)
+const DEFAULT_FEE_CAP_BPS: u64 = 2000; // Starting hard cap on the platform fee (20%); only governance can raise it
+const FEE_INCREASE_NOTICE_SECONDS: i64 = 7 * 24 * 60 * 60; // Fee increases take effect a week after they are scheduled
+const MAX_TIER_CONTENT_IDS: usize = 32; // Max content IDs a subscription tier can list
+const MAX_FEE_TIERS: usize = 4; // Max volume-based fee tiers in the protocol config
+const MAX_SPLIT_RECIPIENTS: usize = 8; // Max recipients in a revenue split
+const MAX_BUNDLE_CONTENT_IDS: usize = 32; // Max content IDs in a bundle
+const MAX_COUPON_CODE_LEN: usize = 32; // Max coupon code length in bytes (the code is a PDA seed)
+const MAX_TIP_MESSAGE_LEN: usize = 140; // Max tip message length in bytes
+
+// Content IDs start at 1, so a revenue split stored under ID 0 is the creator's default.
+pub const CREATOR_DEFAULT_SPLIT: u64 = 0;
+const MAX_TITLE_LEN: usize = 128; // Max content title length in bytes
+const MAX_ENCRYPTED_CID_LEN: usize = 128; // Max encrypted CID length (nonce + ciphertext + auth tag)
+const MAX_PROFILE_CID_LEN: usize = 100; // Max profile metadata CID length in bytes
+
+// Bits of `ProtocolConfig::paused`. Each one stops a group of instructions until it is cleared.
+pub const PAUSE_PAYMENTS: u8 = 1 << 0; // Purchases, rentals, bundles, tips and subscriptions
+pub const PAUSE_ADD_CONTENT: u8 = 1 << 1;
+pub const PAUSE_REGISTER_USERNAME: u8 = 1 << 2;
+pub const PAUSE_INITIALIZE_CREATOR: u8 = 1 << 3;
+pub const PAUSE_ALL: u8 = PAUSE_PAYMENTS | PAUSE_ADD_CONTENT | PAUSE_REGISTER_USERNAME | PAUSE_INITIALIZE_CREATOR;
 
 #[program]
 pub mod auton_program {
@@ -19,18 +51,100 @@ pub mod auton_program {
             initial_fee_percentage <= MAX_PLATFORM_FEE_BPS,
             CustomError::InvalidFeePercentage
         );
+        require!(initial_fee_percentage <= DEFAULT_FEE_CAP_BPS, CustomError::FeeAboveCap);
 
         let config = &mut ctx.accounts.protocol_config;
         config.admin_wallet = *ctx.accounts.admin.key;
         config.fee_percentage = initial_fee_percentage;
+        config.fee_rounding = FeeRounding::Down;
+        config.pending_admin = None;
+        // Fees can only be withdrawn to this wallet; it starts as the admin and can hand itself over.
+        config.fee_recipient = *ctx.accounts.admin.key;
+        config.paused = 0;
+        // The guardian can pause and unpause alongside the admin; it starts as the admin.
+        config.guardian = *ctx.accounts.admin.key;
+        config.pending_fee_percentage = None;
+        config.fee_effective_at = 0;
+        config.max_fee_percentage = DEFAULT_FEE_CAP_BPS;
+        // Governance alone can raise the fee cap; it starts as the admin and can hand itself over.
+        config.governance = *ctx.accounts.admin.key;
+        config.fee_tiers = Vec::new();
+
+        ctx.accounts.treasury.bump = ctx.bumps.treasury;
+
+        emit!(ConfigInitialized {
+            admin_wallet: config.admin_wallet,
+            fee_percentage: config.fee_percentage,
+            fee_rounding: config.fee_rounding,
+            fee_recipient: config.fee_recipient,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Upgrades a config created before it held more than the admin wallet and fee to the
+    // current layout. The account is grown in place, with the admin topping up its rent, and
+    // the new fields start at the same defaults `initialize_config` gives them. The treasury,
+    // which legacy deployments don't have yet, is created alongside.
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        let config_info = ctx.accounts.protocol_config.to_account_info();
+        let legacy_config = LegacyProtocolConfig::try_from_account(&config_info)?;
+        require_keys_eq!(legacy_config.admin_wallet, ctx.accounts.admin.key(), CustomError::Unauthorized);
+
+        let admin_info = ctx.accounts.admin.to_account_info();
+        let rent_due = Rent::get()?
+            .minimum_balance(ProtocolConfig::LEN)
+            .saturating_sub(config_info.lamports());
+        if rent_due > 0 {
+            let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
+                admin_info.key,
+                config_info.key,
+                rent_due,
+            );
+            anchor_lang::solana_program::program::invoke(
+                &transfer_ix,
+                &[admin_info.clone(), config_info.clone(), ctx.accounts.system_program.to_account_info()],
+            )?;
+        }
+        config_info.resize(ProtocolConfig::LEN)?;
+
+        let admin = legacy_config.admin_wallet;
+        let config = ProtocolConfig {
+            admin_wallet: admin,
+            fee_percentage: legacy_config.fee_percentage,
+            fee_rounding: FeeRounding::Down,
+            pending_admin: None,
+            fee_recipient: admin,
+            paused: 0,
+            guardian: admin,
+            pending_fee_percentage: None,
+            fee_effective_at: 0,
+            // A legacy fee above the default cap is kept; governance can only lower it from there.
+            max_fee_percentage: DEFAULT_FEE_CAP_BPS.max(legacy_config.fee_percentage),
+            governance: admin,
+            fee_tiers: Vec::new(),
+        };
+        config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+        ctx.accounts.treasury.bump = ctx.bumps.treasury;
+
+        emit!(ConfigMigrated {
+            admin_wallet: admin,
+            fee_percentage: config.fee_percentage,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         Ok(())
     }
 
-    // Update the protocol's global configuration
+    // Update the protocol's global configuration.
+    // The admin wallet is changed separately, with `propose_admin` and `accept_admin`.
+    // Fee cuts apply immediately. Fee increases are scheduled `FEE_INCREASE_NOTICE_SECONDS`
+    // out so creators and buyers get advance notice; scheduling a new fee replaces any
+    // pending change, and setting the current fee again cancels it.
     pub fn update_config(
         ctx: Context<UpdateConfig>,
-        new_admin_wallet: Option<Pubkey>,
         new_fee_percentage: Option<u64>,
+        new_fee_rounding: Option<FeeRounding>,
     ) -> Result<()> {
         let config = &mut ctx.accounts.protocol_config;
 
@@ -40,19 +154,332 @@ pub mod auton_program {
             CustomError::Unauthorized
         );
 
-        if let Some(admin_wallet) = new_admin_wallet {
-            config.admin_wallet = admin_wallet;
-        }
+        let now = Clock::get()?.unix_timestamp;
+        config.apply_pending_fee(now);
+        let previous_fee_percentage = config.fee_percentage;
+
         if let Some(fee_percentage) = new_fee_percentage {
             require!(fee_percentage <= MAX_PLATFORM_FEE_BPS, CustomError::InvalidFeePercentage);
-            config.fee_percentage = fee_percentage;
+            require!(fee_percentage <= config.max_fee_percentage, CustomError::FeeAboveCap);
+            if fee_percentage <= config.fee_percentage {
+                config.fee_percentage = fee_percentage;
+                config.pending_fee_percentage = None;
+                config.fee_effective_at = 0;
+            } else {
+                config.pending_fee_percentage = Some(fee_percentage);
+                config.fee_effective_at = now
+                    .checked_add(FEE_INCREASE_NOTICE_SECONDS)
+                    .ok_or(CustomError::MathOverflow)?;
+            }
+        }
+        if let Some(fee_rounding) = new_fee_rounding {
+            config.fee_rounding = fee_rounding;
+        }
+
+        emit!(ConfigUpdated {
+            admin_wallet: config.admin_wallet,
+            previous_fee_percentage,
+            fee_percentage: config.fee_percentage,
+            fee_rounding: config.fee_rounding,
+            pending_fee_percentage: config.pending_fee_percentage,
+            fee_effective_at: config.fee_effective_at,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    // Changes the hard cap on the platform fee. Governance can set any cap up to 100%;
+    // the admin can only lower it. Lowering the cap below the current or a pending fee
+    // pulls that fee down to the cap.
+    pub fn set_max_fee_percentage(ctx: Context<SetMaxFeePercentage>, new_max_fee_percentage: u64) -> Result<()> {
+        let config = &mut ctx.accounts.protocol_config;
+        let authority = ctx.accounts.authority.key();
+        require!(new_max_fee_percentage <= MAX_PLATFORM_FEE_BPS, CustomError::InvalidFeePercentage);
+        if authority != config.governance {
+            require!(authority == config.admin_wallet, CustomError::Unauthorized);
+            require!(new_max_fee_percentage <= config.max_fee_percentage, CustomError::Unauthorized);
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        config.apply_pending_fee(now);
+        let previous_max_fee_percentage = config.max_fee_percentage;
+        config.max_fee_percentage = new_max_fee_percentage;
+        let fee_percentage = config.fee_percentage.min(new_max_fee_percentage);
+        config.fee_percentage = fee_percentage;
+        config.pending_fee_percentage = config
+            .pending_fee_percentage
+            .map(|pending| pending.min(new_max_fee_percentage))
+            .filter(|pending| *pending > fee_percentage);
+        if config.pending_fee_percentage.is_none() {
+            config.fee_effective_at = 0;
+        }
+
+        emit!(MaxFeePercentageUpdated {
+            authority,
+            previous_max_fee_percentage,
+            max_fee_percentage: new_max_fee_percentage,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    // Hands the governance role to another key. Must be signed by the current governance key.
+    pub fn set_governance(ctx: Context<SetGovernance>, new_governance: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.protocol_config;
+        require_keys_eq!(config.governance, ctx.accounts.governance.key(), CustomError::Unauthorized);
+
+        let previous_governance = config.governance;
+        config.governance = new_governance;
+
+        emit!(GovernanceUpdated {
+            previous_governance,
+            governance: new_governance,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Starts handing the admin role over to `new_admin`. Nothing changes until
+    // `new_admin` signs `accept_admin`, so a mistyped key can simply be replaced
+    // or cancelled. Proposing again overwrites any pending proposal.
+    pub fn propose_admin(ctx: Context<UpdateConfig>, new_admin: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.protocol_config;
+        require!(
+            config.admin_wallet == *ctx.accounts.admin.key,
+            CustomError::Unauthorized
+        );
+
+        config.pending_admin = Some(new_admin);
+
+        emit!(AdminProposed {
+            admin_wallet: config.admin_wallet,
+            pending_admin: new_admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Completes a handover: the proposed admin signs to take over the admin role.
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let config = &mut ctx.accounts.protocol_config;
+        let pending_admin = config.pending_admin.ok_or(CustomError::NoPendingAdmin)?;
+        require_keys_eq!(pending_admin, ctx.accounts.new_admin.key(), CustomError::Unauthorized);
+
+        let previous_admin_wallet = config.admin_wallet;
+        config.admin_wallet = pending_admin;
+        config.pending_admin = None;
+
+        emit!(AdminTransferred {
+            previous_admin_wallet,
+            admin_wallet: config.admin_wallet,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Withdraws a pending admin proposal. Only the current admin can cancel.
+    pub fn cancel_admin_proposal(ctx: Context<UpdateConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.protocol_config;
+        require!(
+            config.admin_wallet == *ctx.accounts.admin.key,
+            CustomError::Unauthorized
+        );
+        let pending_admin = config.pending_admin.take().ok_or(CustomError::NoPendingAdmin)?;
+
+        emit!(AdminProposalCancelled {
+            admin_wallet: config.admin_wallet,
+            cancelled_admin: pending_admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Changes the wallet treasury withdrawals are paid to. Must be signed by the current
+    // fee recipient, so the admin key alone can never redirect collected fees.
+    pub fn set_fee_recipient(ctx: Context<SetFeeRecipient>, new_fee_recipient: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.protocol_config;
+        require_keys_eq!(config.fee_recipient, ctx.accounts.fee_recipient.key(), CustomError::Unauthorized);
+
+        let previous_fee_recipient = config.fee_recipient;
+        config.fee_recipient = new_fee_recipient;
+
+        emit!(FeeRecipientUpdated {
+            previous_fee_recipient,
+            fee_recipient: new_fee_recipient,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Replaces the volume-based fee tiers. A creator whose lifetime sales count has reached
+    // a tier's `min_sales` pays that tier's fee on content sales, unless it is above the
+    // global fee. Tiers must be listed with strictly increasing `min_sales`; pass an empty
+    // list to remove them.
+    pub fn set_fee_tiers(ctx: Context<UpdateConfig>, fee_tiers: Vec<FeeTier>) -> Result<()> {
+        let config = &mut ctx.accounts.protocol_config;
+        require!(
+            config.admin_wallet == *ctx.accounts.admin.key,
+            CustomError::Unauthorized
+        );
+        require!(fee_tiers.len() <= MAX_FEE_TIERS, CustomError::TooManyFeeTiers);
+        require!(
+            fee_tiers.windows(2).all(|pair| pair[0].min_sales < pair[1].min_sales),
+            CustomError::InvalidFeeTiers
+        );
+        require!(
+            fee_tiers.iter().all(|tier| tier.fee_bps <= MAX_PLATFORM_FEE_BPS),
+            CustomError::InvalidFeePercentage
+        );
+
+        config.fee_tiers = fee_tiers;
+
+        emit!(FeeTiersUpdated {
+            fee_tiers: config.fee_tiers.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Gives a creator a custom platform fee on content sales, optionally until `expires_at`.
+    // Overrides take precedence over fee tiers but never raise a creator's fee above the
+    // global fee. Admin only.
+    pub fn create_fee_override(
+        ctx: Context<CreateFeeOverride>,
+        creator: Pubkey,
+        fee_bps: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.protocol_config.admin_wallet == *ctx.accounts.admin.key,
+            CustomError::Unauthorized
+        );
+        require!(fee_bps <= MAX_PLATFORM_FEE_BPS, CustomError::InvalidFeePercentage);
+
+        let fee_override = &mut ctx.accounts.fee_override;
+        fee_override.creator = creator;
+        fee_override.fee_bps = fee_bps;
+        fee_override.expires_at = expires_at;
+
+        emit!(FeeOverrideSet {
+            creator,
+            fee_bps,
+            expires_at,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Changes an existing fee override's rate and expiry. Admin only.
+    pub fn update_fee_override(
+        ctx: Context<UpdateFeeOverride>,
+        creator: Pubkey,
+        fee_bps: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.protocol_config.admin_wallet == *ctx.accounts.admin.key,
+            CustomError::Unauthorized
+        );
+        require!(fee_bps <= MAX_PLATFORM_FEE_BPS, CustomError::InvalidFeePercentage);
+
+        let fee_override = &mut ctx.accounts.fee_override;
+        fee_override.fee_bps = fee_bps;
+        fee_override.expires_at = expires_at;
+
+        emit!(FeeOverrideSet {
+            creator,
+            fee_bps,
+            expires_at,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Removes a creator's fee override, refunding its rent to the admin.
+    pub fn close_fee_override(ctx: Context<CloseFeeOverride>, creator: Pubkey) -> Result<()> {
+        require!(
+            ctx.accounts.protocol_config.admin_wallet == *ctx.accounts.admin.key,
+            CustomError::Unauthorized
+        );
+
+        emit!(FeeOverrideRemoved {
+            creator,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Replaces the set of paused instruction groups with `paused`, a mask of the
+    // `PAUSE_*` bits. Pass 0 to resume everything. Signed by the admin or the guardian.
+    pub fn set_paused(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+        let config = &mut ctx.accounts.protocol_config;
+        let authority = ctx.accounts.authority.key();
+        require!(
+            authority == config.admin_wallet || authority == config.guardian,
+            CustomError::Unauthorized
+        );
+        require!(paused & !PAUSE_ALL == 0, CustomError::InvalidPauseFlags);
+
+        let previous_paused = config.paused;
+        config.paused = paused;
+
+        emit!(PauseUpdated {
+            authority,
+            previous_paused,
+            paused,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Replaces the guardian key. Only the admin can change it.
+    pub fn set_guardian(ctx: Context<UpdateConfig>, new_guardian: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.protocol_config;
+        require!(
+            config.admin_wallet == *ctx.accounts.admin.key,
+            CustomError::Unauthorized
+        );
+
+        let previous_guardian = config.guardian;
+        config.guardian = new_guardian;
+
+        emit!(GuardianUpdated {
+            previous_guardian,
+            guardian: new_guardian,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Moves collected fees from the treasury to the config's fee recipient.
+    // Pass `payment_mint` and the token accounts to withdraw tokens instead of lamports.
+    // The treasury always keeps enough lamports to stay rent-exempt.
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        require!(
+            ctx.accounts.protocol_config.admin_wallet == *ctx.accounts.admin.key,
+            CustomError::Unauthorized
+        );
+
+        if ctx.accounts.payment_mint.is_some() {
+            ctx.accounts.withdraw_tokens(amount)?;
+        } else {
+            ctx.accounts.withdraw_lamports(amount)?;
         }
+
+        emit!(TreasuryWithdrawn {
+            fee_recipient: ctx.accounts.fee_recipient.key(),
+            payment_mint: ctx.accounts.payment_mint.as_ref().map(|mint| mint.key()),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         Ok(())
     }
 
     // NEW: Registers a username for a creator
     // This creates a PDA that maps a username to a wallet address
     pub fn register_username(ctx: Context<RegisterUsername>, username: String) -> Result<()> {
+        ctx.accounts.protocol_config.require_not_paused(PAUSE_REGISTER_USERNAME)?;
+
         // Validate username
         require!(username.len() >= 3 && username.len() <= 32, CustomError::InvalidUsername);
         require!(
@@ -64,335 +491,3403 @@ pub mod auton_program {
         username_account.authority = *ctx.accounts.creator.key;
         username_account.username = username;
 
+        emit!(UsernameRegistered {
+            authority: username_account.authority,
+            username: username_account.username.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         Ok(())
     }
 
-    // Initializes a new account for a creator to hold their content list.
+    // Initializes a new account for a creator to hold their profile and content counter.
     // This only needs to be called once per creator.
     pub fn initialize_creator(ctx: Context<InitializeCreator>) -> Result<()> {
+        ctx.accounts.protocol_config.require_not_paused(PAUSE_INITIALIZE_CREATOR)?;
+
         let creator_account = &mut ctx.accounts.creator_account;
         creator_account.creator_wallet = *ctx.accounts.creator.key;
-        creator_account.content = Vec::new();
         creator_account.last_content_id = 0;
+        creator_account.sales_count = 0;
+        creator_account.affiliate_bps = 0;
+
+        emit!(CreatorInitialized {
+            creator: creator_account.creator_wallet,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         Ok(())
     }
 
-    // Adds a new piece of content to the creator's account.
+    // Adds a new piece of content for the creator, stored in its own
+    // `[b"content", creator, id]` account.
+    // If a `payment_mint` account is passed, the price is in that SPL or Token-2022 token's
+    // base units instead of lamports, and `transfer_fee_payer` decides who absorbs the mint's
+    // transfer fee (if it has one).
     pub fn add_content(
         ctx: Context<AddContent>,
         title: String,
         price: u64,
         encrypted_cid: Vec<u8>,
+        transfer_fee_payer: TransferFeePayer,
     ) -> Result<()> {
+        ctx.accounts.protocol_config.require_not_paused(PAUSE_ADD_CONTENT)?;
+
         let creator_account = &mut ctx.accounts.creator_account;
         
         require!(creator_account.creator_wallet == *ctx.accounts.creator.key, CustomError::Unauthorized);
+        require!(title.len() <= MAX_TITLE_LEN, CustomError::TitleTooLong);
+        require!(encrypted_cid.len() <= MAX_ENCRYPTED_CID_LEN, CustomError::EncryptedCidTooLong);
 
         // Increment the counter to get a new ID
         creator_account.last_content_id += 1;
         let new_id = creator_account.last_content_id;
 
-        let new_content = ContentItem {
-            id: new_id,
-            title,
-            price,
-            encrypted_cid,
-        };
+        let content_item = &mut ctx.accounts.content_item;
+        content_item.creator = creator_account.creator_wallet;
+        content_item.id = new_id;
+        content_item.title = title;
+        content_item.price = price;
+        content_item.encrypted_cid = encrypted_cid;
+        content_item.payment_mint = ctx.accounts.payment_mint.as_ref().map(|mint| mint.key());
+        content_item.transfer_fee_payer = transfer_fee_payer;
+        content_item.listed = true;
+        content_item.affiliate_bps = None;
+        content_item.pay_what_you_want = false;
+        content_item.rental = None;
+        content_item.max_supply = None;
+        content_item.sold = 0;
+
+        emit!(ContentAdded {
+            creator: content_item.creator,
+            content_id: new_id,
+            title: content_item.title.clone(),
+            price: content_item.price,
+            payment_mint: content_item.payment_mint,
+            transfer_fee_payer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Edits an existing content item. Fields passed as None are left unchanged.
+    // The account is resized to fit the new title and encrypted CID exactly.
+    pub fn update_content(
+        ctx: Context<UpdateContent>,
+        content_id: u64,
+        title: Option<String>,
+        price: Option<u64>,
+        encrypted_cid: Option<Vec<u8>>,
+    ) -> Result<()> {
+        let content_item = &mut ctx.accounts.content_item;
+
+        if let Some(title) = title {
+            require!(title.len() <= MAX_TITLE_LEN, CustomError::TitleTooLong);
+            content_item.title = title;
+        }
+        if let Some(price) = price {
+            content_item.price = price;
+        }
+        if let Some(encrypted_cid) = encrypted_cid {
+            require!(encrypted_cid.len() <= MAX_ENCRYPTED_CID_LEN, CustomError::EncryptedCidTooLong);
+            content_item.encrypted_cid = encrypted_cid;
+        }
+
+        msg!("Content {} updated", content_id);
+
+        emit!(ContentUpdated {
+            creator: content_item.creator,
+            content_id,
+            title: content_item.title.clone(),
+            price: content_item.price,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Takes a content item off sale. Buyers who already hold a receipt keep their access.
+    pub fn unlist_content(ctx: Context<SetContentListing>, content_id: u64) -> Result<()> {
+        ctx.accounts.content_item.listed = false;
+        msg!("Content {} unlisted", content_id);
+
+        emit!(ContentListingChanged {
+            creator: ctx.accounts.content_item.creator,
+            content_id,
+            listed: false,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Puts an unlisted content item back on sale.
+    pub fn relist_content(ctx: Context<SetContentListing>, content_id: u64) -> Result<()> {
+        ctx.accounts.content_item.listed = true;
+        msg!("Content {} relisted", content_id);
+
+        emit!(ContentListingChanged {
+            creator: ctx.accounts.content_item.creator,
+            content_id,
+            listed: true,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Turns pay-what-you-want pricing on or off for a content item. While on, the price is a
+    // minimum and buyers pay whatever they pass as `max_price`, as long as it covers it.
+    // Needs the creator's supporter stats for the content's currency, which record what
+    // buyers pay above the minimum.
+    pub fn set_pay_what_you_want(ctx: Context<SetPayWhatYouWant>, content_id: u64, enabled: bool) -> Result<()> {
+        if enabled {
+            require!(
+                load_optional::<SupporterStats>(&ctx.accounts.supporter_stats)?.is_some(),
+                CustomError::SupporterStatsMissing
+            );
+        }
+        ctx.accounts.content_item.pay_what_you_want = enabled;
+
+        emit!(PayWhatYouWantChanged {
+            creator: ctx.accounts.content_item.creator,
+            content_id,
+            enabled,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Caps how many copies of a content item can be bought, turning it into a limited edition.
+    // To keep the edition scarce, a cap can only be lowered, and never below the copies already sold.
+    // Items offered for rent can't be capped, as renting would get round the cap.
+    pub fn set_max_supply(ctx: Context<SetContentListing>, content_id: u64, max_supply: u64) -> Result<()> {
+        let content_item = &mut ctx.accounts.content_item;
+        require!(content_item.rental.is_none(), CustomError::LimitedEditionRental);
+        require!(max_supply >= content_item.sold, CustomError::InvalidMaxSupply);
+        if let Some(current) = content_item.max_supply {
+            require!(max_supply <= current, CustomError::InvalidMaxSupply);
+        }
+        content_item.max_supply = Some(max_supply);
+
+        emit!(MaxSupplySet {
+            creator: content_item.creator,
+            content_id,
+            max_supply,
+            sold: content_item.sold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Offers a content item for rent on the given terms, or stops renting it out with None.
+    // Buyers holding a rental keep it until it expires, but can't renew it while there are no terms.
+    // Limited editions can't be offered for rent.
+    pub fn set_rental_terms(
+        ctx: Context<SetContentListing>,
+        content_id: u64,
+        rental: Option<RentalTerms>,
+    ) -> Result<()> {
+        if let Some(rental) = rental {
+            require!(rental.duration > 0, CustomError::InvalidRentalTerms);
+            require!(ctx.accounts.content_item.max_supply.is_none(), CustomError::LimitedEditionRental);
+        }
+        ctx.accounts.content_item.rental = rental;
+
+        emit!(RentalTermsChanged {
+            creator: ctx.accounts.content_item.creator,
+            content_id,
+            rental,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Moves a legacy creator's content, which older program versions kept in a list on the
+    // creator account, into content item accounts. The accounts for the first items still in
+    // the list are passed as remaining accounts, in list order, so a long list can be moved over
+    // several transactions. Once the list is empty the creator account is rewritten in the
+    // current layout and resized to fit. Moved items keep their ID and stay listed, priced in SOL.
+    pub fn migrate_creator_content<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateCreatorContent<'info>>,
+    ) -> Result<()> {
+        let creator_info = ctx.accounts.creator_account.to_account_info();
+        let mut legacy_creator = LegacyCreatorAccount::try_from_account(&creator_info)?;
+        let creator = legacy_creator.creator_wallet;
+        require_keys_eq!(creator, ctx.accounts.creator.key(), CustomError::Unauthorized);
+        require!(
+            ctx.remaining_accounts.len() <= legacy_creator.content.len(),
+            CustomError::InvalidLegacyCreator
+        );
+
+        let rent = Rent::get()?;
+        let moved: Vec<LegacyContentItem> = legacy_creator.content.drain(..ctx.remaining_accounts.len()).collect();
+        for (legacy_item, content_info) in moved.into_iter().zip(ctx.remaining_accounts) {
+            let id_bytes = legacy_item.id.to_le_bytes();
+            let (address, bump) = Pubkey::find_program_address(&[b"content", creator.as_ref(), &id_bytes], &crate::ID);
+            require_keys_eq!(content_info.key(), address, CustomError::InvalidLegacyCreator);
+
+            let content_item = ContentItem {
+                creator,
+                id: legacy_item.id,
+                title: legacy_item.title,
+                price: legacy_item.price,
+                encrypted_cid: legacy_item.encrypted_cid,
+                payment_mint: None,
+                transfer_fee_payer: TransferFeePayer::Buyer,
+                listed: true,
+                affiliate_bps: None,
+                pay_what_you_want: false,
+                rental: None,
+                max_supply: None,
+                sold: 0,
+            };
+            let space = ContentItem::space(content_item.title.len(), content_item.encrypted_cid.len());
+            anchor_lang::system_program::create_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::CreateAccount {
+                        from: ctx.accounts.creator.to_account_info(),
+                        to: content_info.clone(),
+                    },
+                    &[&[b"content", creator.as_ref(), &id_bytes, &[bump]]],
+                ),
+                rent.minimum_balance(space),
+                space as u64,
+                &crate::ID,
+            )?;
+            content_item.try_serialize(&mut &mut content_info.try_borrow_mut_data()?[..])?;
+        }
+
+        let remaining = legacy_creator.content.len() as u64;
+        if remaining > 0 {
+            // Still legacy: write the shortened list back in place
+            let mut data = creator_info.try_borrow_mut_data()?;
+            data[CreatorAccount::DISCRIMINATOR.len()..].fill(0);
+            legacy_creator.serialize(&mut &mut data[CreatorAccount::DISCRIMINATOR.len()..])?;
+        } else {
+            let creator_account = CreatorAccount {
+                creator_wallet: creator,
+                last_content_id: legacy_creator.last_content_id,
+                profile_cid: legacy_creator.profile_cid,
+                sales_count: 0,
+                affiliate_bps: 0,
+            };
+            // The legacy account was sized for its content list; refund what it no longer needs
+            resize_account(
+                &creator_info,
+                &ctx.accounts.creator.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                CreatorAccount::space(creator_account.profile_cid.len()),
+            )?;
+            creator_account.try_serialize(&mut &mut creator_info.try_borrow_mut_data()?[..])?;
+        }
 
-        creator_account.content.push(new_content);
+        emit!(CreatorContentMigrated {
+            creator,
+            migrated: ctx.remaining_accounts.len() as u64,
+            remaining,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         Ok(())
     }
 
-    // Updates the creator's profile metadata CID
+    // Updates the creator's profile metadata CID.
+    // The account is resized to the new CID's exact length; a shorter CID refunds the freed rent to the creator.
     pub fn update_profile(ctx: Context<UpdateProfile>, profile_cid: String) -> Result<()> {
         let creator_account = &mut ctx.accounts.creator_account;
         require!(creator_account.creator_wallet == *ctx.accounts.creator.key, CustomError::Unauthorized);
+        require!(profile_cid.len() <= MAX_PROFILE_CID_LEN, CustomError::ProfileCidTooLong);
+        // Resize to fit the new profile CID exactly. This is done here rather than with `realloc`
+        // so the account's layout is checked at its original size.
+        resize_account(
+            &creator_account.to_account_info(),
+            &ctx.accounts.creator.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            CreatorAccount::space(profile_cid.len()),
+        )?;
         creator_account.profile_cid = profile_cid;
+
+        emit!(ProfileUpdated {
+            creator: creator_account.creator_wallet,
+            profile_cid: creator_account.profile_cid.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         Ok(())
     }
 
     // Records that a user has paid for a specific piece of content.
-    // This transfers SOL from buyer to creator (minus fee) and admin (fee), then creates an access receipt.
-    pub fn process_payment(ctx: Context<ProcessPayment>, content_id: u64) -> Result<()> {
+    // This transfers SOL (or the content's SPL token) from buyer to creator (minus fee)
+    // and the protocol treasury (fee), then creates an access receipt.
+    // `max_price` and `max_fee_bps` protect the buyer from the price or the platform fee
+    // being raised between signing and execution.
+    // If the content, or else the creator, has a revenue split, the creator's share is divided
+    // between its recipients instead, passed in order as remaining accounts (their wallets
+    // for SOL, their associated token accounts for tokens).
+    // Passing a registered referrer's stats account pays them the content's affiliate share
+    // out of the creator's share, before any split.
+    // Passing one of the creator's coupons takes its discount off the price first; `max_price`
+    // is compared with the discounted price. For pay-what-you-want content the buyer pays
+    // `max_price` itself, which must cover the (discounted) minimum.
+    pub fn process_payment<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessPayment<'info>>,
+        content_id: u64,
+        max_price: u64,
+        max_fee_bps: Option<u64>,
+    ) -> Result<()> {
         let creator_account = &ctx.accounts.creator_account;
         let config = &ctx.accounts.protocol_config;
+        config.require_not_paused(PAUSE_PAYMENTS)?;
+        let now = Clock::get()?.unix_timestamp;
+        // The creator's fee override or volume tier, capped at the global fee. A scheduled
+        // fee increase counts from its effective time, even before it is written back.
+        let fee_override = load_optional::<FeeOverride>(&ctx.accounts.fee_override)?;
+        let fee_bps = config.creator_fee_bps(now, creator_account.sales_count, fee_override.as_ref());
 
-        // Find the content item by its ID. This is much more efficient than hashing.
-        let content_item = creator_account.content.iter().find(|item| {
-            item.id == content_id
-        }).ok_or(CustomError::ContentNotFound)?;
-
-        let total_price = content_item.price;
-        let fee_amount = (total_price * config.fee_percentage) / 10000; // 10000 = 100% in basis points
-        let creator_amount = total_price - fee_amount;
-
-        // 1. Transfer Platform Fee to Admin Wallet
-        if fee_amount > 0 {
-            let fee_transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
-                &ctx.accounts.buyer.key(),
-                &ctx.accounts.admin_wallet.key(), // Use admin wallet from config
-                fee_amount,
-            );
-            anchor_lang::solana_program::program::invoke(
-                &fee_transfer_ix,
+        // The content item is loaded from its own PDA, so only the item being bought is read.
+        // A coupon's discount comes off the price before the fee split.
+        let content_item = &ctx.accounts.content_item;
+        require!(content_item.listed, CustomError::ContentUnlisted);
+        if let Some(max_supply) = content_item.max_supply {
+            require!(content_item.sold < max_supply, CustomError::SoldOut);
+        }
+        // Editions are numbered from 1 in purchase order.
+        let edition = content_item.sold.checked_add(1).ok_or(CustomError::MathOverflow)?;
+        // Pay-what-you-want items take `max_price` as the buyer's offer.
+        let discount_amount = ctx.accounts.coupon_discount(now)?;
+        let min_price = content_item.price - discount_amount;
+        require!(min_price <= max_price, CustomError::PriceAboveMaximum);
+        let price = if content_item.pay_what_you_want { max_price } else { min_price };
+        if let Some(max_fee_bps) = max_fee_bps {
+            require!(fee_bps <= max_fee_bps, CustomError::FeeAboveMaximum);
+        }
+
+        // Work out whether this is paid in lamports or tokens, and where each share goes,
+        // then transfer the platform fee to the treasury and the remainder to the creator
+        // or the split's recipients.
+        let revenue_split = ctx.accounts.revenue_split()?;
+        let mut route = ctx.accounts.payment_accounts().route(
+            content_item.payment_mint,
+            revenue_split.as_ref().map(|(_, split)| (split, ctx.remaining_accounts)),
+        )?;
+        route.referral = ctx.accounts.referral()?;
+        let settlement = route.settle(
+            price,
+            fee_bps,
+            config.fee_rounding,
+            content_item.transfer_fee_payer,
+        )?;
+
+        // Create the access receipt (scoped to this creator's content ID). A rental receipt, active
+        // or expired, is overwritten in place so the renter can buy the item outright.
+        let receipt_info = ctx.accounts.paid_access_account.to_account_info();
+        match load_optional::<PaidAccessAccount>(&receipt_info)? {
+            Some(existing) => require!(existing.expires_at.is_some(), CustomError::AlreadyPurchased),
+            None => create_pda_account(
+                &ctx.accounts.buyer.to_account_info(),
+                &receipt_info,
+                &ctx.accounts.system_program.to_account_info(),
+                PaidAccessAccount::LEN,
                 &[
-                    ctx.accounts.buyer.to_account_info(),
-                    ctx.accounts.admin_wallet.to_account_info(), // Passed in account
-                    ctx.accounts.system_program.to_account_info(),
+                    b"access",
+                    ctx.accounts.buyer.key.as_ref(),
+                    creator_account.creator_wallet.as_ref(),
+                    &content_id.to_le_bytes(),
+                    &[ctx.bumps.paid_access_account],
                 ],
-            )?;
+            )?,
         }
+        let access_account = PaidAccessAccount {
+            buyer: *ctx.accounts.buyer.key,
+            content_id,
+            creator: creator_account.creator_wallet,
+            created_at: now,
+            price: settlement.price,
+            fee_amount: settlement.fee_amount,
+            creator_amount: settlement.creator_amount,
+            payment_mint: content_item.payment_mint,
+            referrer: ctx.accounts.referrer_stats.as_ref().map(|stats| stats.referrer),
+            referral_amount: settlement.referral_amount,
+            coupon: ctx.accounts.coupon.as_ref().map(|coupon| coupon.key()),
+            discount_amount,
+            expires_at: None,
+            edition: Some(edition),
+        };
+        access_account.try_serialize(&mut &mut receipt_info.try_borrow_mut_data()?[..])?;
         
-        msg!("Collected {} lamports in platform fees", fee_amount);
+        msg!("Payment processed: {} (fee: {}, creator: {}, edition: {})", 
+             settlement.price, settlement.fee_amount, settlement.creator_amount, edition);
 
+        // Count the coupon's use, and the buyer's if it limits uses per buyer.
+        if let Some(coupon) = ctx.accounts.coupon.as_mut() {
+            coupon.redemptions = coupon.redemptions.checked_add(1).ok_or(CustomError::MathOverflow)?;
+            if coupon.max_per_buyer.is_some() {
+                let redemption = ctx
+                    .accounts
+                    .coupon_redemption
+                    .as_mut()
+                    .ok_or(CustomError::CouponNotApplicable)?;
+                redemption.redemptions = redemption
+                    .redemptions
+                    .checked_add(1)
+                    .ok_or(CustomError::MathOverflow)?;
+            }
+        }
 
-        // 2. Transfer Remaining Amount to Creator's Wallet
-        let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
-            &ctx.accounts.buyer.key(),
-            &ctx.accounts.creator_wallet.key(),
-            creator_amount,
-        );
+        // Credit the sale to the referrer's stats.
+        if let Some(referrer_stats) = ctx.accounts.referrer_stats.as_mut() {
+            referrer_stats.referral_count = referrer_stats
+                .referral_count
+                .checked_add(1)
+                .ok_or(CustomError::MathOverflow)?;
+            referrer_stats.total_earned = referrer_stats
+                .total_earned
+                .checked_add(settlement.referral_amount)
+                .ok_or(CustomError::MathOverflow)?;
+        }
+
+        // Record what a pay-what-you-want buyer paid above the minimum.
+        if content_item.pay_what_you_want {
+            let stats_info = ctx.accounts.supporter_stats.to_account_info();
+            let mut stats = load_optional::<SupporterStats>(&stats_info)?.ok_or(CustomError::SupporterStatsMissing)?;
+            stats.pay_what_you_want_count = stats
+                .pay_what_you_want_count
+                .checked_add(1)
+                .ok_or(CustomError::MathOverflow)?;
+            stats.total_overpaid = stats
+                .total_overpaid
+                .checked_add(price - min_price)
+                .ok_or(CustomError::MathOverflow)?;
+            stats.try_serialize(&mut &mut stats_info.try_borrow_mut_data()?[..])?;
+        }
+
+        // Count the sale towards the creator's volume tier and the item's supply.
+        let creator_account = &mut ctx.accounts.creator_account;
+        creator_account.sales_count = creator_account
+            .sales_count
+            .checked_add(1)
+            .ok_or(CustomError::MathOverflow)?;
+        ctx.accounts.content_item.sold = edition;
+
+        emit!(ContentPurchased {
+            buyer: access_account.buyer,
+            creator: access_account.creator,
+            content_id,
+            payment_mint: access_account.payment_mint,
+            price: settlement.price,
+            fee_bps,
+            fee_amount: settlement.fee_amount,
+            creator_amount: settlement.creator_amount,
+            buyer_total: settlement.buyer_total,
+            revenue_split: revenue_split.map(|(address, _)| address),
+            referrer: access_account.referrer,
+            referral_amount: settlement.referral_amount,
+            coupon: access_account.coupon,
+            discount_amount,
+            edition,
+            timestamp: access_account.created_at,
+        });
         
-        anchor_lang::solana_program::program::invoke(
-            &transfer_instruction,
-            &[
-                ctx.accounts.buyer.to_account_info(),
-                ctx.accounts.creator_wallet.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            ],
+        Ok(())
+    }
+
+    // Rents a content item on its rental terms. The receipt is created at the same address as
+    // a purchase receipt, but only grants access until `expires_at`; `renew_rental` extends it
+    // or, once it has expired, rents the item again, and buying the item with `process_payment`
+    // makes it permanent. The fee and revenue split work as for `process_payment`; coupons,
+    // referrals and pay-what-you-want pricing don't apply. Limited editions can't be rented.
+    pub fn rent_content<'info>(
+        ctx: Context<'_, '_, '_, 'info, RentContent<'info>>,
+        content_id: u64,
+        max_price: u64,
+        max_fee_bps: Option<u64>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.protocol_config;
+        config.require_not_paused(PAUSE_PAYMENTS)?;
+        let now = Clock::get()?.unix_timestamp;
+        let fee_override = load_optional::<FeeOverride>(&ctx.accounts.fee_override)?;
+        let fee_bps = config.creator_fee_bps(now, ctx.accounts.creator_account.sales_count, fee_override.as_ref());
+
+        let content_item = &ctx.accounts.content_item;
+        let rental = content_item.rental_terms()?;
+        require!(rental.price <= max_price, CustomError::PriceAboveMaximum);
+        if let Some(max_fee_bps) = max_fee_bps {
+            require!(fee_bps <= max_fee_bps, CustomError::FeeAboveMaximum);
+        }
+        let expires_at = now.checked_add(rental.duration).ok_or(CustomError::MathOverflow)?;
+
+        let revenue_split = load_revenue_split(&ctx.accounts.content_split, &ctx.accounts.creator_split)?;
+        let route = ctx.accounts.payment_accounts().route(
+            content_item.payment_mint,
+            revenue_split.as_ref().map(|(_, split)| (split, ctx.remaining_accounts)),
         )?;
+        let settlement = route.settle(rental.price, fee_bps, config.fee_rounding, content_item.transfer_fee_payer)?;
 
-        // Create the access receipt
         let access_account = &mut ctx.accounts.paid_access_account;
         access_account.buyer = *ctx.accounts.buyer.key;
         access_account.content_id = content_id;
-        access_account.creator = creator_account.creator_wallet;
-        access_account.created_at = Clock::get()?.unix_timestamp;
-        
-        msg!("Payment processed: {} lamports (fee: {}, creator: {})", 
-             total_price, fee_amount, creator_amount);
-        
+        access_account.creator = content_item.creator;
+        access_account.created_at = now;
+        access_account.price = settlement.price;
+        access_account.fee_amount = settlement.fee_amount;
+        access_account.creator_amount = settlement.creator_amount;
+        access_account.payment_mint = content_item.payment_mint;
+        access_account.referrer = None;
+        access_account.referral_amount = 0;
+        access_account.coupon = None;
+        access_account.discount_amount = 0;
+        access_account.expires_at = Some(expires_at);
+        access_account.edition = None;
+
+        msg!("Content {} rented until {}: {} (fee: {}, creator: {})",
+             content_id, expires_at, settlement.price, settlement.fee_amount, settlement.creator_amount);
+
+        let creator_account = &mut ctx.accounts.creator_account;
+        creator_account.sales_count = creator_account
+            .sales_count
+            .checked_add(1)
+            .ok_or(CustomError::MathOverflow)?;
+
+        emit!(ContentRented {
+            buyer: access_account.buyer,
+            creator: access_account.creator,
+            content_id,
+            payment_mint: access_account.payment_mint,
+            price: settlement.price,
+            fee_bps,
+            fee_amount: settlement.fee_amount,
+            creator_amount: settlement.creator_amount,
+            buyer_total: settlement.buyer_total,
+            revenue_split: revenue_split.map(|(address, _)| address),
+            expires_at,
+            renewal: false,
+            timestamp: now,
+        });
         Ok(())
     }
-}
 
-// 1. ACCOUNTS (State)
-// These structs define the shape of the data we store on-chain.
+    // Pays for another rental period on the content's current terms. An active rental is
+    // extended from its expiry; an expired one is rented again from now. The receipt's amounts
+    // are replaced with what this payment split into.
+    pub fn renew_rental<'info>(
+        ctx: Context<'_, '_, '_, 'info, RenewRental<'info>>,
+        content_id: u64,
+        max_price: u64,
+        max_fee_bps: Option<u64>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.protocol_config;
+        config.require_not_paused(PAUSE_PAYMENTS)?;
+        let now = Clock::get()?.unix_timestamp;
+        let fee_override = load_optional::<FeeOverride>(&ctx.accounts.fee_override)?;
+        let fee_bps = config.creator_fee_bps(now, ctx.accounts.creator_account.sales_count, fee_override.as_ref());
 
-// Global configuration for the protocol (admin wallet, fee percentage)
-#[account]
-pub struct ProtocolConfig {
-    pub admin_wallet: Pubkey,
-    pub fee_percentage: u64, // Basis points (e.g., 500 = 5%)
-}
+        let current_expiry = ctx.accounts.paid_access_account.expires_at.ok_or(CustomError::NotARental)?;
+        let content_item = &ctx.accounts.content_item;
+        let rental = content_item.rental_terms()?;
+        require!(rental.price <= max_price, CustomError::PriceAboveMaximum);
+        if let Some(max_fee_bps) = max_fee_bps {
+            require!(fee_bps <= max_fee_bps, CustomError::FeeAboveMaximum);
+        }
+        let expires_at = current_expiry
+            .max(now)
+            .checked_add(rental.duration)
+            .ok_or(CustomError::MathOverflow)?;
 
-// NEW: Username registry entry - maps username to wallet address
-#[account]
-pub struct UsernameAccount {
-    pub authority: Pubkey,  // The creator's wallet address
-    pub username: String,   // The username itself
-}
+        let revenue_split = load_revenue_split(&ctx.accounts.content_split, &ctx.accounts.creator_split)?;
+        let route = ctx.accounts.payment_accounts().route(
+            content_item.payment_mint,
+            revenue_split.as_ref().map(|(_, split)| (split, ctx.remaining_accounts)),
+        )?;
+        let settlement = route.settle(rental.price, fee_bps, config.fee_rounding, content_item.transfer_fee_payer)?;
 
-#[account]
-pub struct CreatorAccount {
-    pub creator_wallet: Pubkey,
-    pub last_content_id: u64, // Counter for generating unique content IDs
-    pub content: Vec<ContentItem>,
-    pub profile_cid: String, // IPFS CID for profile metadata (bio, avatar, etc.)
-}
+        let access_account = &mut ctx.accounts.paid_access_account;
+        access_account.price = settlement.price;
+        access_account.fee_amount = settlement.fee_amount;
+        access_account.creator_amount = settlement.creator_amount;
+        access_account.payment_mint = content_item.payment_mint;
+        access_account.expires_at = Some(expires_at);
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
-pub struct ContentItem {
-    pub id: u64, // Unique ID for the content
-    pub title: String,
-    pub price: u64, // Price in lamports
+        msg!("Rental of content {} renewed until {}: {} (fee: {}, creator: {})",
+             content_id, expires_at, settlement.price, settlement.fee_amount, settlement.creator_amount);
+
+        let creator_account = &mut ctx.accounts.creator_account;
+        creator_account.sales_count = creator_account
+            .sales_count
+            .checked_add(1)
+            .ok_or(CustomError::MathOverflow)?;
+
+        emit!(ContentRented {
+            buyer: access_account.buyer,
+            creator: access_account.creator,
+            content_id,
+            payment_mint: access_account.payment_mint,
+            price: settlement.price,
+            fee_bps,
+            fee_amount: settlement.fee_amount,
+            creator_amount: settlement.creator_amount,
+            buyer_total: settlement.buyer_total,
+            revenue_split: revenue_split.map(|(address, _)| address),
+            expires_at,
+            renewal: true,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    // Moves a receipt created under the legacy `[b"access", buyer, content_id]` seeds
+    // to the creator-scoped layout and closes the old account, refunding its rent to the buyer.
+    // `creator` must match the creator recorded on the legacy receipt.
+    // Anyone can submit this: the new receipt is a copy of the old one, so no rights change hands.
+    pub fn migrate_receipt(ctx: Context<MigrateReceipt>, content_id: u64, creator: Pubkey) -> Result<()> {
+        let legacy_info = ctx.accounts.legacy_access_account.to_account_info();
+        let legacy_account = LegacyPaidAccessAccount::try_from_account(&legacy_info)?;
+        require_keys_eq!(legacy_account.buyer, ctx.accounts.buyer.key(), CustomError::InvalidLegacyReceipt);
+        require_keys_eq!(legacy_account.creator, creator, CustomError::InvalidLegacyReceipt);
+
+        // Legacy receipts did not record what was paid, so the amounts are left at zero.
+        let access_account = &mut ctx.accounts.paid_access_account;
+        access_account.buyer = legacy_account.buyer;
+        access_account.content_id = content_id;
+        access_account.creator = legacy_account.creator;
+        access_account.created_at = legacy_account.created_at;
+
+        // Close the legacy receipt: refund its rent to the buyer and hand it back to the system program.
+        let buyer_info = ctx.accounts.buyer.to_account_info();
+        let buyer_lamports = buyer_info
+            .lamports()
+            .checked_add(legacy_info.lamports())
+            .ok_or(CustomError::MathOverflow)?;
+        **buyer_info.try_borrow_mut_lamports()? = buyer_lamports;
+        **legacy_info.try_borrow_mut_lamports()? = 0;
+        legacy_info.assign(&System::id());
+        legacy_info.resize(0)?;
+
+        msg!("Receipt migrated: buyer {} creator {} content {}",
+             access_account.buyer, access_account.creator, content_id);
+
+        emit!(ReceiptMigrated {
+            buyer: access_account.buyer,
+            creator: access_account.creator,
+            content_id,
+            created_at: access_account.created_at,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Creates the creator's supporter stats for one currency: `payment_mint`, or None for SOL.
+    // Creators need them to accept tips, or to sell pay-what-you-want content, in that currency.
+    pub fn create_supporter_stats(ctx: Context<CreateSupporterStats>, payment_mint: Option<Pubkey>) -> Result<()> {
+        let supporter_stats = &mut ctx.accounts.supporter_stats;
+        supporter_stats.creator = ctx.accounts.creator.key();
+        supporter_stats.payment_mint = payment_mint;
+        supporter_stats.tip_count = 0;
+        supporter_stats.total_tipped = 0;
+        supporter_stats.pay_what_you_want_count = 0;
+        supporter_stats.total_overpaid = 0;
+
+        emit!(SupporterStatsCreated {
+            creator: supporter_stats.creator,
+            payment_mint,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Sends a creator a tip of `amount` in SOL, or in the token of the `payment_mint` account,
+    // with an optional short message. The platform fee is taken as for a purchase, and the
+    // tipper pays exactly `amount` (a mint's transfer fee comes out of the creator's share).
+    // Tips go to the creator wallet, bypassing revenue splits, and don't count as sales.
+    pub fn tip_creator(
+        ctx: Context<TipCreator>,
+        amount: u64,
+        max_fee_bps: Option<u64>,
+        message: Option<String>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.protocol_config;
+        config.require_not_paused(PAUSE_PAYMENTS)?;
+        require!(amount > 0, CustomError::InvalidTipAmount);
+        if let Some(message) = &message {
+            require!(message.len() <= MAX_TIP_MESSAGE_LEN, CustomError::TipMessageTooLong);
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        let fee_override = load_optional::<FeeOverride>(&ctx.accounts.fee_override)?;
+        let fee_bps = config.creator_fee_bps(now, ctx.accounts.creator_account.sales_count, fee_override.as_ref());
+        if let Some(max_fee_bps) = max_fee_bps {
+            require!(fee_bps <= max_fee_bps, CustomError::FeeAboveMaximum);
+        }
+
+        let payment_mint = ctx.accounts.payment_mint.as_ref().map(|mint| mint.key());
+        require!(
+            ctx.accounts.supporter_stats.payment_mint == payment_mint,
+            CustomError::InvalidSupporterStats
+        );
+        let route = ctx.accounts.payment_accounts().route(payment_mint, None)?;
+        let settlement = route.settle(amount, fee_bps, config.fee_rounding, TransferFeePayer::Creator)?;
+
+        let supporter_stats = &mut ctx.accounts.supporter_stats;
+        supporter_stats.tip_count = supporter_stats.tip_count.checked_add(1).ok_or(CustomError::MathOverflow)?;
+        supporter_stats.total_tipped = supporter_stats
+            .total_tipped
+            .checked_add(amount)
+            .ok_or(CustomError::MathOverflow)?;
+
+        emit!(CreatorTipped {
+            tipper: ctx.accounts.tipper.key(),
+            creator: supporter_stats.creator,
+            payment_mint,
+            amount,
+            fee_bps,
+            fee_amount: settlement.fee_amount,
+            creator_amount: settlement.creator_amount,
+            message,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    // Sets up a revenue split for one of the creator's content items, or for all of them
+    // with `CREATOR_DEFAULT_SPLIT`. A content item's own split takes precedence over the
+    // creator's default. Shares are in basis points and must sum to 10000.
+    pub fn create_revenue_split(
+        ctx: Context<CreateRevenueSplit>,
+        content_id: u64,
+        recipients: Vec<SplitRecipient>,
+    ) -> Result<()> {
+        require!(
+            content_id <= ctx.accounts.creator_account.last_content_id,
+            CustomError::ContentNotFound
+        );
+        RevenueSplit::validate(&recipients)?;
+
+        let revenue_split = &mut ctx.accounts.revenue_split;
+        revenue_split.creator = *ctx.accounts.creator.key;
+        revenue_split.content_id = content_id;
+        revenue_split.recipients = recipients;
+
+        emit!(RevenueSplitSet {
+            creator: revenue_split.creator,
+            content_id,
+            recipients: revenue_split.recipients.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Replaces the recipients of an existing revenue split.
+    pub fn update_revenue_split(
+        ctx: Context<UpdateRevenueSplit>,
+        content_id: u64,
+        recipients: Vec<SplitRecipient>,
+    ) -> Result<()> {
+        RevenueSplit::validate(&recipients)?;
+
+        let revenue_split = &mut ctx.accounts.revenue_split;
+        revenue_split.recipients = recipients;
+
+        emit!(RevenueSplitSet {
+            creator: revenue_split.creator,
+            content_id,
+            recipients: revenue_split.recipients.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Removes a revenue split, refunding its rent to the creator. Sales then pay the creator
+    // wallet again, or the creator's default split if a content split was removed.
+    pub fn close_revenue_split(ctx: Context<CloseRevenueSplit>, content_id: u64) -> Result<()> {
+        emit!(RevenueSplitRemoved {
+            creator: ctx.accounts.creator.key(),
+            content_id,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Sets the share of the creator's proceeds, in basis points, paid to the referrer of a
+    // sale. Applies to all of the creator's content without a share of its own; 0 turns
+    // referral payouts off.
+    pub fn set_affiliate_share(ctx: Context<SetAffiliateShare>, affiliate_bps: u64) -> Result<()> {
+        require!(affiliate_bps <= fee::BPS_DENOMINATOR, CustomError::InvalidAffiliateShare);
+        let creator_account = &mut ctx.accounts.creator_account;
+        creator_account.affiliate_bps = affiliate_bps;
+
+        emit!(AffiliateShareUpdated {
+            creator: creator_account.creator_wallet,
+            content_id: None,
+            affiliate_bps: Some(affiliate_bps),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Sets a content item's own affiliate share, or clears it with None so the creator's
+    // share applies again.
+    pub fn set_content_affiliate_share(
+        ctx: Context<SetContentAffiliateShare>,
+        content_id: u64,
+        affiliate_bps: Option<u64>,
+    ) -> Result<()> {
+        if let Some(affiliate_bps) = affiliate_bps {
+            require!(affiliate_bps <= fee::BPS_DENOMINATOR, CustomError::InvalidAffiliateShare);
+        }
+        ctx.accounts.content_item.affiliate_bps = affiliate_bps;
+
+        emit!(AffiliateShareUpdated {
+            creator: ctx.accounts.content_item.creator,
+            content_id: Some(content_id),
+            affiliate_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Creates a referrer's stats account for one currency: `payment_mint`, or None for SOL.
+    // Purchases can only credit referrers that have one for the content's currency; it
+    // accumulates their referred sales and earnings so affiliates can prove what they made.
+    pub fn register_referrer(ctx: Context<RegisterReferrer>, payment_mint: Option<Pubkey>) -> Result<()> {
+        let referrer_stats = &mut ctx.accounts.referrer_stats;
+        referrer_stats.referrer = ctx.accounts.referrer.key();
+        referrer_stats.payment_mint = payment_mint;
+        referrer_stats.referral_count = 0;
+        referrer_stats.total_earned = 0;
+
+        emit!(ReferrerRegistered {
+            referrer: referrer_stats.referrer,
+            payment_mint,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Creates a bundle of the creator's content items, sold together for one price with an
+    // optional discount off it. If a `payment_mint` account is passed, the price is in that
+    // token. Buyers get a single bundle receipt granting access to every item.
+    // The items' accounts are passed as remaining accounts, in the order of `content_ids`;
+    // limited editions and unlisted items can't be bundled.
+    pub fn create_bundle(
+        ctx: Context<CreateBundle>,
+        bundle_id: u8,
+        content_ids: Vec<u64>,
+        price: u64,
+        discount_bps: Option<u64>,
+        transfer_fee_payer: TransferFeePayer,
+    ) -> Result<()> {
+        Bundle::validate(&content_ids, discount_bps, ctx.accounts.creator_account.last_content_id)?;
+        Bundle::check_content_items(ctx.accounts.creator.key, &content_ids, ctx.remaining_accounts)?;
+
+        let bundle = &mut ctx.accounts.bundle;
+        bundle.creator = *ctx.accounts.creator.key;
+        bundle.bundle_id = bundle_id;
+        bundle.price = price;
+        bundle.discount_bps = discount_bps;
+        bundle.payment_mint = ctx.accounts.payment_mint.as_ref().map(|mint| mint.key());
+        bundle.transfer_fee_payer = transfer_fee_payer;
+        bundle.content_ids = content_ids;
+
+        emit!(BundleSet {
+            creator: bundle.creator,
+            bundle_id,
+            content_ids: bundle.content_ids.clone(),
+            price,
+            discount_bps,
+            payment_mint: bundle.payment_mint,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Changes a bundle's content, price and discount. Existing bundle receipts keep the
+    // content they were bought with. The items' accounts are passed as for `create_bundle`.
+    pub fn update_bundle(
+        ctx: Context<UpdateBundle>,
+        bundle_id: u8,
+        content_ids: Vec<u64>,
+        price: u64,
+        discount_bps: Option<u64>,
+    ) -> Result<()> {
+        Bundle::validate(&content_ids, discount_bps, ctx.accounts.creator_account.last_content_id)?;
+        Bundle::check_content_items(ctx.accounts.creator.key, &content_ids, ctx.remaining_accounts)?;
+
+        let bundle = &mut ctx.accounts.bundle;
+        bundle.content_ids = content_ids;
+        bundle.price = price;
+        bundle.discount_bps = discount_bps;
+
+        emit!(BundleSet {
+            creator: bundle.creator,
+            bundle_id,
+            content_ids: bundle.content_ids.clone(),
+            price,
+            discount_bps,
+            payment_mint: bundle.payment_mint,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Takes a bundle off sale, refunding its rent to the creator. Bundle receipts keep working.
+    pub fn close_bundle(ctx: Context<CloseBundle>, bundle_id: u8) -> Result<()> {
+        emit!(BundleRemoved {
+            creator: ctx.accounts.creator.key(),
+            bundle_id,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Buys every item in a bundle with one payment. The fee is worked out as for
+    // `process_payment`, and the creator's default revenue split applies (content splits
+    // don't, as the price covers several items). The remaining accounts are the bundle's content
+    // items, in order, followed by the split's recipients. An item that has since become a
+    // limited edition or been unlisted stops the bundle from selling.
+    // Creates one bundle receipt listing the bundle's content at the time of purchase.
+    pub fn purchase_bundle<'info>(
+        ctx: Context<'_, '_, '_, 'info, PurchaseBundle<'info>>,
+        bundle_id: u8,
+        max_price: u64,
+        max_fee_bps: Option<u64>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.protocol_config;
+        config.require_not_paused(PAUSE_PAYMENTS)?;
+        let now = Clock::get()?.unix_timestamp;
+        let fee_override = load_optional::<FeeOverride>(&ctx.accounts.fee_override)?;
+        let fee_bps = config.creator_fee_bps(now, ctx.accounts.creator_account.sales_count, fee_override.as_ref());
+
+        let bundle = &ctx.accounts.bundle;
+        let price = bundle.sale_price()?;
+        require!(price <= max_price, CustomError::PriceAboveMaximum);
+        if let Some(max_fee_bps) = max_fee_bps {
+            require!(fee_bps <= max_fee_bps, CustomError::FeeAboveMaximum);
+        }
+
+        Bundle::check_content_items(&bundle.creator, &bundle.content_ids, ctx.remaining_accounts)?;
+        let split_recipients = &ctx.remaining_accounts[bundle.content_ids.len()..];
+
+        let revenue_split = load_optional::<RevenueSplit>(&ctx.accounts.creator_split)?;
+        let route = ctx.accounts.payment_accounts().route(
+            bundle.payment_mint,
+            revenue_split.as_ref().map(|split| (split, split_recipients)),
+        )?;
+        let settlement = route.settle(price, fee_bps, config.fee_rounding, bundle.transfer_fee_payer)?;
+
+        let bundle_receipt = &mut ctx.accounts.bundle_receipt;
+        bundle_receipt.buyer = *ctx.accounts.buyer.key;
+        bundle_receipt.creator = bundle.creator;
+        bundle_receipt.bundle_id = bundle_id;
+        bundle_receipt.content_ids = bundle.content_ids.clone();
+        bundle_receipt.created_at = now;
+        bundle_receipt.price = settlement.price;
+        bundle_receipt.fee_amount = settlement.fee_amount;
+        bundle_receipt.creator_amount = settlement.creator_amount;
+        bundle_receipt.payment_mint = bundle.payment_mint;
+
+        msg!("Bundle {} purchased: {} (fee: {}, creator: {})",
+             bundle_id, settlement.price, settlement.fee_amount, settlement.creator_amount);
+
+        // A bundle counts as one sale towards the creator's volume tier.
+        let creator_account = &mut ctx.accounts.creator_account;
+        creator_account.sales_count = creator_account
+            .sales_count
+            .checked_add(1)
+            .ok_or(CustomError::MathOverflow)?;
+
+        emit!(BundlePurchased {
+            buyer: bundle_receipt.buyer,
+            creator: bundle_receipt.creator,
+            bundle_id,
+            content_ids: bundle_receipt.content_ids.clone(),
+            payment_mint: bundle_receipt.payment_mint,
+            price: settlement.price,
+            fee_bps,
+            fee_amount: settlement.fee_amount,
+            creator_amount: settlement.creator_amount,
+            buyer_total: settlement.buyer_total,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    // Creates a promo code for the creator's content. The discount is a percentage or a fixed
+    // amount off; a fixed amount is in the currency of the `payment_mint` account, if passed,
+    // else lamports, and only applies to content priced in it. Redemptions can be capped in
+    // total and per buyer, and the coupon can expire.
+    pub fn create_coupon(
+        ctx: Context<CreateCoupon>,
+        code: String,
+        discount: CouponDiscount,
+        max_redemptions: Option<u64>,
+        max_per_buyer: Option<u64>,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(!code.is_empty() && code.len() <= MAX_COUPON_CODE_LEN, CustomError::InvalidCoupon);
+        require!(discount.is_valid(), CustomError::InvalidCoupon);
+        require!(max_redemptions != Some(0) && max_per_buyer != Some(0), CustomError::InvalidCoupon);
+        require!(expires_at.map_or(true, |expires_at| expires_at > now), CustomError::InvalidCoupon);
+
+        let coupon = &mut ctx.accounts.coupon;
+        coupon.creator = *ctx.accounts.creator.key;
+        coupon.code = code;
+        coupon.discount = discount;
+        coupon.payment_mint = ctx.accounts.payment_mint.as_ref().map(|mint| mint.key());
+        coupon.max_redemptions = max_redemptions;
+        coupon.redemptions = 0;
+        coupon.max_per_buyer = max_per_buyer;
+        coupon.expires_at = expires_at;
+
+        emit!(CouponCreated {
+            creator: coupon.creator,
+            coupon: coupon.key(),
+            code: coupon.code.clone(),
+            discount,
+            payment_mint: coupon.payment_mint,
+            max_redemptions,
+            max_per_buyer,
+            expires_at,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    // Withdraws a coupon, refunding its rent to the creator. Receipts bought with it are unaffected.
+    pub fn close_coupon(ctx: Context<CloseCoupon>, code: String) -> Result<()> {
+        emit!(CouponRemoved {
+            creator: ctx.accounts.creator.key(),
+            code,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Creates a buyer's redemption counter for a coupon. Coupons with a per-buyer limit can
+    // only be redeemed by buyers that have one; `process_payment` counts their uses in it.
+    pub fn create_coupon_redemption(ctx: Context<CreateCouponRedemption>) -> Result<()> {
+        let redemption = &mut ctx.accounts.coupon_redemption;
+        redemption.coupon = ctx.accounts.coupon.key();
+        redemption.buyer = ctx.accounts.buyer.key();
+        redemption.redemptions = 0;
+        Ok(())
+    }
+
+    // Creates a subscription tier for the creator. Subscribers pay `price` every
+    // `period_seconds` and get access to the listed content IDs, or to all of the
+    // creator's content when `content_ids` is empty. Either way, limited editions and
+    // unlisted items aren't unlocked by a subscription.
+    pub fn create_subscription_tier(
+        ctx: Context<CreateSubscriptionTier>,
+        tier_id: u8,
+        price: u64,
+        period_seconds: i64,
+        content_ids: Vec<u64>,
+        transfer_fee_payer: TransferFeePayer,
+    ) -> Result<()> {
+        require!(period_seconds > 0, CustomError::InvalidSubscriptionPeriod);
+        require!(content_ids.len() <= MAX_TIER_CONTENT_IDS, CustomError::TooManyTierContentIds);
+
+        let tier = &mut ctx.accounts.subscription_tier;
+        tier.creator = *ctx.accounts.creator.key;
+        tier.tier_id = tier_id;
+        tier.price = price;
+        tier.period_seconds = period_seconds;
+        tier.payment_mint = ctx.accounts.payment_mint.as_ref().map(|mint| mint.key());
+        tier.transfer_fee_payer = transfer_fee_payer;
+        tier.content_ids = content_ids;
+
+        emit!(SubscriptionTierCreated {
+            creator: tier.creator,
+            tier_id,
+            price,
+            period_seconds,
+            payment_mint: tier.payment_mint,
+            content_ids: tier.content_ids.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Changes a tier's price, period and covered content. Existing subscriptions keep
+    // their current expiry; the new terms apply from their next renewal.
+    pub fn update_subscription_tier(
+        ctx: Context<UpdateSubscriptionTier>,
+        tier_id: u8,
+        price: u64,
+        period_seconds: i64,
+        content_ids: Vec<u64>,
+    ) -> Result<()> {
+        require!(period_seconds > 0, CustomError::InvalidSubscriptionPeriod);
+        require!(content_ids.len() <= MAX_TIER_CONTENT_IDS, CustomError::TooManyTierContentIds);
+
+        let tier = &mut ctx.accounts.subscription_tier;
+        tier.price = price;
+        tier.period_seconds = period_seconds;
+        tier.content_ids = content_ids;
+
+        msg!("Subscription tier {} updated", tier_id);
+
+        emit!(SubscriptionTierUpdated {
+            creator: tier.creator,
+            tier_id,
+            price,
+            period_seconds,
+            content_ids: tier.content_ids.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    // Subscribes to one of a creator's tiers, paying for `periods` periods up front
+    // with the same fee split as `process_payment`, and counts as one sale towards the
+    // creator's volume tier. `max_price` (for all the periods) and
+    // `max_fee_bps` protect the subscriber from the tier or the fee being repriced first.
+    pub fn subscribe(
+        ctx: Context<Subscribe>,
+        tier_id: u8,
+        periods: u32,
+        max_price: u64,
+        max_fee_bps: Option<u64>,
+    ) -> Result<()> {
+        let tier = &ctx.accounts.subscription_tier;
+        let config = &ctx.accounts.protocol_config;
+        config.require_not_paused(PAUSE_PAYMENTS)?;
+        let (price, duration) = tier.terms_for(periods)?;
+        require!(price <= max_price, CustomError::PriceAboveMaximum);
+
+        let now = Clock::get()?.unix_timestamp;
+        let fee_override = load_optional::<FeeOverride>(&ctx.accounts.fee_override)?;
+        let fee_bps = config.creator_fee_bps(now, ctx.accounts.creator_account.sales_count, fee_override.as_ref());
+        if let Some(max_fee_bps) = max_fee_bps {
+            require!(fee_bps <= max_fee_bps, CustomError::FeeAboveMaximum);
+        }
+        let route = ctx.accounts.payment_accounts().route(tier.payment_mint, None)?;
+        let settlement = route.settle(price, fee_bps, config.fee_rounding, tier.transfer_fee_payer)?;
+
+        let subscription = &mut ctx.accounts.subscription;
+        subscription.subscriber = *ctx.accounts.subscriber.key;
+        subscription.creator = tier.creator;
+        subscription.tier_id = tier_id;
+        subscription.started_at = now;
+        subscription.expires_at = now.checked_add(duration).ok_or(CustomError::MathOverflow)?;
+
+        let creator_account = &mut ctx.accounts.creator_account;
+        creator_account.sales_count = creator_account
+            .sales_count
+            .checked_add(1)
+            .ok_or(CustomError::MathOverflow)?;
+
+        msg!("Subscribed to tier {} until {}: {} (fee: {}, creator: {})",
+             tier_id, subscription.expires_at,
+             settlement.price, settlement.fee_amount, settlement.creator_amount);
+
+        emit!(SubscriptionPaid {
+            subscriber: subscription.subscriber,
+            creator: subscription.creator,
+            tier_id,
+            periods,
+            payment_mint: tier.payment_mint,
+            price: settlement.price,
+            fee_bps,
+            fee_amount: settlement.fee_amount,
+            creator_amount: settlement.creator_amount,
+            buyer_total: settlement.buyer_total,
+            started_at: subscription.started_at,
+            expires_at: subscription.expires_at,
+            renewal: false,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    // Extends a subscription by `periods` periods. An active subscription is extended
+    // from its current expiry and must stay on the same tier; a lapsed one restarts
+    // from now and may switch to another of the creator's tiers. Each renewal counts as a sale,
+    // and `max_price` and `max_fee_bps` work as for `subscribe`.
+    pub fn renew_subscription(
+        ctx: Context<RenewSubscription>,
+        tier_id: u8,
+        periods: u32,
+        max_price: u64,
+        max_fee_bps: Option<u64>,
+    ) -> Result<()> {
+        let tier = &ctx.accounts.subscription_tier;
+        let config = &ctx.accounts.protocol_config;
+        config.require_not_paused(PAUSE_PAYMENTS)?;
+        let (price, duration) = tier.terms_for(periods)?;
+        require!(price <= max_price, CustomError::PriceAboveMaximum);
+
+        let now = Clock::get()?.unix_timestamp;
+        let fee_override = load_optional::<FeeOverride>(&ctx.accounts.fee_override)?;
+        let fee_bps = config.creator_fee_bps(now, ctx.accounts.creator_account.sales_count, fee_override.as_ref());
+        if let Some(max_fee_bps) = max_fee_bps {
+            require!(fee_bps <= max_fee_bps, CustomError::FeeAboveMaximum);
+        }
+        let subscription = &mut ctx.accounts.subscription;
+        let extend_from = if subscription.is_active(now) {
+            require!(subscription.tier_id == tier_id, CustomError::SubscriptionTierMismatch);
+            subscription.expires_at
+        } else {
+            subscription.tier_id = tier_id;
+            subscription.started_at = now;
+            now
+        };
+        subscription.expires_at = extend_from
+            .checked_add(duration)
+            .ok_or(CustomError::MathOverflow)?;
+
+        let route = ctx.accounts.payment_accounts().route(tier.payment_mint, None)?;
+        let settlement = route.settle(price, fee_bps, config.fee_rounding, tier.transfer_fee_payer)?;
+
+        let creator_account = &mut ctx.accounts.creator_account;
+        creator_account.sales_count = creator_account
+            .sales_count
+            .checked_add(1)
+            .ok_or(CustomError::MathOverflow)?;
+
+        msg!("Subscription renewed on tier {} until {}: {} (fee: {}, creator: {})",
+             tier_id, ctx.accounts.subscription.expires_at,
+             settlement.price, settlement.fee_amount, settlement.creator_amount);
+
+        let subscription = &ctx.accounts.subscription;
+        emit!(SubscriptionPaid {
+            subscriber: subscription.subscriber,
+            creator: subscription.creator,
+            tier_id,
+            periods,
+            payment_mint: tier.payment_mint,
+            price: settlement.price,
+            fee_bps,
+            fee_amount: settlement.fee_amount,
+            creator_amount: settlement.creator_amount,
+            buyer_total: settlement.buyer_total,
+            started_at: subscription.started_at,
+            expires_at: subscription.expires_at,
+            renewal: true,
+            timestamp: now,
+        });
+        Ok(())
+    }
+}
+
+// 1. ACCOUNTS (State)
+// These structs define the shape of the data we store on-chain.
+
+// Global configuration for the protocol (admin wallet, fee percentage)
+#[account]
+pub struct ProtocolConfig {
+    pub admin_wallet: Pubkey,
+    pub fee_percentage: u64, // Basis points (e.g., 500 = 5%)
+    pub fee_rounding: FeeRounding, // How fractional fees are rounded, see `fee::split_price`
+    pub pending_admin: Option<Pubkey>, // Proposed next admin, waiting to accept
+    pub fee_recipient: Pubkey, // The only wallet treasury withdrawals can be paid to
+    pub paused: u8, // Mask of `PAUSE_*` bits for the instruction groups currently stopped
+    pub guardian: Pubkey, // Emergency key that can pause and unpause besides the admin
+    pub pending_fee_percentage: Option<u64>, // Scheduled fee increase, in basis points
+    pub fee_effective_at: i64, // When the pending fee replaces `fee_percentage`
+    pub max_fee_percentage: u64, // Hard cap on the platform fee, in basis points
+    pub governance: Pubkey, // The only key that can raise `max_fee_percentage`
+    pub fee_tiers: Vec<FeeTier>, // Volume-based fee discounts, by increasing `min_sales`
+}
+
+impl ProtocolConfig {
+    // discriminator + admin_wallet pubkey + fee_percentage u64 + fee_rounding + pending_admin
+    // + fee_recipient + paused + guardian + pending_fee_percentage + fee_effective_at
+    // + max_fee_percentage + governance + fee_tiers
+    pub const LEN: usize =
+        8 + 32 + 8 + 1 + (1 + 32) + 32 + 1 + 32 + (1 + 8) + 8 + 8 + 32 + (4 + FeeTier::LEN * MAX_FEE_TIERS);
+
+    // The platform fee in force at `now`, counting a pending increase once it is due.
+    // Payment instructions only read the config, so they use this rather than writing
+    // the pending fee back; the next config update folds it in with `apply_pending_fee`.
+    pub fn fee_bps_at(&self, now: i64) -> u64 {
+        match self.pending_fee_percentage {
+            Some(pending) if now >= self.fee_effective_at => pending,
+            _ => self.fee_percentage,
+        }
+    }
+
+    // The platform fee on a content sale by a creator with `sales_count` lifetime sales.
+    // An active fee override wins over the volume tiers; either only ever lowers the fee
+    // below the global one.
+    pub fn creator_fee_bps(&self, now: i64, sales_count: u64, fee_override: Option<&FeeOverride>) -> u64 {
+        let global_fee_bps = self.fee_bps_at(now);
+        let creator_fee_bps = match fee_override.filter(|fee_override| fee_override.is_active(now)) {
+            Some(fee_override) => Some(fee_override.fee_bps),
+            None => self.tier_fee_bps(sales_count),
+        };
+        creator_fee_bps.map_or(global_fee_bps, |fee_bps| fee_bps.min(global_fee_bps))
+    }
+
+    // The fee of the highest tier `sales_count` has reached, if any.
+    pub fn tier_fee_bps(&self, sales_count: u64) -> Option<u64> {
+        self.fee_tiers
+            .iter()
+            .rev()
+            .find(|tier| sales_count >= tier.min_sales)
+            .map(|tier| tier.fee_bps)
+    }
+
+    pub fn apply_pending_fee(&mut self, now: i64) {
+        if self.pending_fee_percentage.is_some() && now >= self.fee_effective_at {
+            self.fee_percentage = self.fee_bps_at(now);
+            self.pending_fee_percentage = None;
+            self.fee_effective_at = 0;
+        }
+    }
+
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.paused & flag != 0
+    }
+
+    pub fn require_not_paused(&self, flag: u8) -> Result<()> {
+        require!(!self.is_paused(flag), CustomError::ProtocolPaused);
+        Ok(())
+    }
+}
+
+// A volume-based fee tier: creators with at least `min_sales` lifetime sales pay `fee_bps`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeTier {
+    pub min_sales: u64,
+    pub fee_bps: u64,
+}
+
+impl FeeTier {
+    // min_sales + fee_bps
+    pub const LEN: usize = 8 + 8;
+}
+
+// An admin-set platform fee for one creator, at `[b"fee_override", creator]`.
+#[account]
+pub struct FeeOverride {
+    pub creator: Pubkey, // The creator's wallet address
+    pub fee_bps: u64, // Fee charged on the creator's content sales, in basis points
+    pub expires_at: Option<i64>, // The override stops applying at this Unix timestamp (None = never)
+}
+
+impl FeeOverride {
+    // discriminator + creator + fee_bps + expires_at
+    pub const LEN: usize = 8 + 32 + 8 + (1 + 8);
+
+    pub fn is_active(&self, now: i64) -> bool {
+        self.expires_at.map_or(true, |expires_at| now < expires_at)
+    }
+}
+
+// The program-owned account platform fees are paid into, at `[b"treasury"]`.
+// Token fees go to its associated token accounts.
+#[account]
+pub struct Treasury {
+    pub bump: u8,
+}
+
+impl Treasury {
+    // discriminator + bump
+    pub const LEN: usize = 8 + 1;
+}
+
+// NEW: Username registry entry - maps username to wallet address
+#[account]
+pub struct UsernameAccount {
+    pub authority: Pubkey,  // The creator's wallet address
+    pub username: String,   // The username itself
+}
+
+#[account]
+pub struct CreatorAccount {
+    pub creator_wallet: Pubkey,
+    pub last_content_id: u64, // Counter for generating unique content IDs
+    pub profile_cid: String, // IPFS CID for profile metadata (bio, avatar, etc.)
+    pub sales_count: u64, // Lifetime content sales, used for volume fee tiers
+    pub affiliate_bps: u64, // Share of each sale paid to its referrer, unless the content sets its own
+}
+
+impl CreatorAccount {
+    // Exact serialized size: discriminator + wallet + counter + profile_cid + sales_count
+    // + affiliate_bps
+    pub fn space(profile_cid_len: usize) -> usize {
+        8 + 32 + 8 + (4 + profile_cid_len) + 8 + 8
+    }
+}
+
+// A single piece of content, stored in its own PDA at `[b"content", creator, id]`.
+#[account]
+pub struct ContentItem {
+    pub creator: Pubkey, // The creator's wallet address
+    pub id: u64, // Unique ID for the content, per creator
+    pub title: String,
+    pub price: u64, // Price in lamports, or in base units of `payment_mint` when set
     pub encrypted_cid: Vec<u8>, // Encrypted IPFS CID (ciphertext + nonce + auth tag)
+    pub payment_mint: Option<Pubkey>, // SPL mint the content is priced in (None = SOL)
+    pub transfer_fee_payer: TransferFeePayer, // Who absorbs the mint's transfer fee, if any
+    pub listed: bool, // Whether the content can currently be bought
+    pub affiliate_bps: Option<u64>, // Referrer share for this item (None = the creator's share)
+    pub pay_what_you_want: bool, // Whether `price` is a minimum buyers may pay more than
+    pub rental: Option<RentalTerms>, // Terms for renting the item instead of buying it (None = not for rent)
+    pub max_supply: Option<u64>, // Most copies that can ever be bought (None = unlimited)
+    pub sold: u64, // Copies bought through `process_payment`, which numbers each receipt's edition
+}
+
+// What renting a content item costs and how long each rental lasts.
+// The price is in the content item's currency.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RentalTerms {
+    pub price: u64,
+    pub duration: i64, // Seconds of access per rental
+}
+
+// Who absorbs a Token-2022 mint's transfer fee when content priced in it is bought.
+// Has no effect for SOL or for mints without the transfer-fee extension.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferFeePayer {
+    Buyer,   // The buyer pays the fee on top of the price; creator and treasury receive their full shares
+    Creator, // The buyer pays exactly the price; the fee is taken out of the creator's share
+}
+
+impl ContentItem {
+    // The terms a rental or renewal is charged on, if the item can be rented right now.
+    pub fn rental_terms(&self) -> Result<RentalTerms> {
+        require!(self.listed, CustomError::ContentUnlisted);
+        Ok(self.rental.ok_or(CustomError::RentalUnavailable)?)
+    }
+
+    // Whether bundles and subscriptions can unlock the item. Limited editions are only sold as
+    // numbered copies through `process_payment`, and unlisted items aren't on sale at all.
+    pub fn is_bundleable(&self) -> bool {
+        self.listed && self.max_supply.is_none()
+    }
+
+    // Exact serialized size: discriminator + creator + id + title + price + encrypted_cid
+    // + payment_mint + fee payer + listed + affiliate_bps + pay_what_you_want + rental + max_supply + sold
+    pub fn space(title_len: usize, encrypted_cid_len: usize) -> usize {
+        8 + 32 + 8 + (4 + title_len) + 8 + (4 + encrypted_cid_len) + (1 + 32) + 1 + 1 + (1 + 8) + 1 + (1 + 8 + 8)
+            + (1 + 8) + 8
+    }
+}
+
+// How a content item's creator share is divided, at `[b"split", creator, content_id]`.
+// Stored under `CREATOR_DEFAULT_SPLIT` it applies to all of the creator's content
+// that has no split of its own.
+#[account]
+pub struct RevenueSplit {
+    pub creator: Pubkey, // The creator's wallet address
+    pub content_id: u64, // The content item, or `CREATOR_DEFAULT_SPLIT`
+    pub recipients: Vec<SplitRecipient>, // Paid in this order; the first also gets rounding remainders
+}
+
+// One recipient of a revenue split.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SplitRecipient {
+    pub wallet: Pubkey,
+    pub share_bps: u64, // Share of the creator's proceeds, in basis points
+}
+
+impl RevenueSplit {
+    // discriminator + creator + content_id + recipients (wallet + share_bps each)
+    pub const LEN: usize = 8 + 32 + 8 + (4 + (32 + 8) * MAX_SPLIT_RECIPIENTS);
+
+    pub fn validate(recipients: &[SplitRecipient]) -> Result<()> {
+        require!(recipients.len() <= MAX_SPLIT_RECIPIENTS, CustomError::TooManySplitRecipients);
+        require!(
+            recipients.iter().all(|recipient| recipient.share_bps > 0),
+            CustomError::InvalidRevenueSplit
+        );
+        let total_bps = recipients
+            .iter()
+            .try_fold(0u64, |total, recipient| total.checked_add(recipient.share_bps))
+            .ok_or(CustomError::InvalidRevenueSplit)?;
+        require!(total_bps == fee::BPS_DENOMINATOR, CustomError::InvalidRevenueSplit);
+        Ok(())
+    }
+
+    pub fn shares_bps(&self) -> Vec<u64> {
+        self.recipients.iter().map(|recipient| recipient.share_bps).collect()
+    }
+}
+
+#[account]
+pub struct PaidAccessAccount {
+    pub buyer: Pubkey,
+    pub content_id: u64, // ID of the content this receipt grants access to
+    pub creator: Pubkey, // Store which creator this receipt is for
+    pub created_at: i64, // Unix timestamp for when the receipt was created
+    pub price: u64, // Price paid after any coupon, in lamports or base units of `payment_mint`
+    pub fee_amount: u64, // Platform fee taken from the price
+    pub creator_amount: u64, // Share of the price paid to the creator
+    pub payment_mint: Option<Pubkey>, // SPL mint the price was paid in (None = SOL)
+    pub referrer: Option<Pubkey>, // Who referred the buyer, if anyone
+    pub referral_amount: u64, // Part of the creator's share paid to the referrer
+    pub coupon: Option<Pubkey>, // The coupon redeemed, if any
+    pub discount_amount: u64, // Taken off the listed price by the coupon
+    pub expires_at: Option<i64>, // End of a rental's access (None = bought outright)
+    pub edition: Option<u64>, // This copy's number in purchase order, from 1 (None for rentals and migrated receipts)
+}
+
+impl PaidAccessAccount {
+    // discriminator + buyer pubkey + content_id + creator pubkey + timestamp
+    // + price + fee_amount + creator_amount + payment_mint + referrer + referral_amount
+    // + coupon + discount_amount + expires_at + edition
+    pub const LEN: usize =
+        8 + 32 + 8 + 32 + 8 + 8 + 8 + 8 + (1 + 32) + (1 + 32) + 8 + (1 + 32) + 8 + (1 + 8) + (1 + 8);
+
+    // Whether the receipt currently grants access. Purchases always do; rentals until they expire.
+    pub fn is_active(&self, now: i64) -> bool {
+        self.expires_at.map_or(true, |expires_at| now < expires_at)
+    }
+}
+
+// A referrer's running totals in one currency, at `[b"referrer", referrer, mint]`
+// (the all-zero key stands in for the mint of SOL earnings).
+#[account]
+pub struct ReferrerStats {
+    pub referrer: Pubkey, // The wallet referral payouts go to
+    pub payment_mint: Option<Pubkey>, // Currency of these totals (None = SOL)
+    pub referral_count: u64, // Sales made through this referrer
+    pub total_earned: u64, // Lamports or base units of `payment_mint` paid to the referrer
+}
+
+impl ReferrerStats {
+    // discriminator + referrer + payment_mint + referral_count + total_earned
+    pub const LEN: usize = 8 + 32 + (1 + 32) + 8 + 8;
+}
+
+// A creator's tips and pay-what-you-want overpayments in one currency,
+// at `[b"supporters", creator, mint]` (the all-zero key stands in for the mint of SOL).
+#[account]
+pub struct SupporterStats {
+    pub creator: Pubkey, // The creator's wallet address
+    pub payment_mint: Option<Pubkey>, // Currency of these totals (None = SOL)
+    pub tip_count: u64,
+    pub total_tipped: u64, // Tipped before the platform fee
+    pub pay_what_you_want_count: u64, // Pay-what-you-want purchases
+    pub total_overpaid: u64, // Paid above the minimum on pay-what-you-want purchases
+}
+
+impl SupporterStats {
+    // discriminator + creator + payment_mint + tip_count + total_tipped
+    // + pay_what_you_want_count + total_overpaid
+    pub const LEN: usize = 8 + 32 + (1 + 32) + 8 + 8 + 8 + 8;
+}
+
+// Layout of receipts created before they were scoped per creator and recorded amounts.
+// Only used to read those receipts in `migrate_receipt` (and by clients checking access).
+#[derive(AnchorDeserialize)]
+pub struct LegacyPaidAccessAccount {
+    pub buyer: Pubkey,
+    pub content_id: u64,
+    pub creator: Pubkey,
+    pub created_at: i64,
+}
+
+impl LegacyPaidAccessAccount {
+    // Legacy receipts share the `PaidAccessAccount` discriminator but are too short to
+    // deserialize as one, so the owner and discriminator are checked by hand.
+    pub fn try_from_account(info: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*info.owner, crate::ID, CustomError::InvalidLegacyReceipt);
+        Self::try_from_data(&info.try_borrow_data()?)
+    }
+
+    pub fn try_from_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.starts_with(PaidAccessAccount::DISCRIMINATOR),
+            CustomError::InvalidLegacyReceipt
+        );
+        Self::deserialize(&mut &data[PaidAccessAccount::DISCRIMINATOR.len()..])
+            .map_err(|_| error!(CustomError::InvalidLegacyReceipt))
+    }
+}
+
+// Layout of the config before it gained anything beyond the admin wallet and fee.
+// Only used to read it in `migrate_config`.
+#[derive(AnchorDeserialize)]
+pub struct LegacyProtocolConfig {
+    pub admin_wallet: Pubkey,
+    pub fee_percentage: u64,
+}
+
+impl LegacyProtocolConfig {
+    // discriminator + admin_wallet pubkey + fee_percentage u64
+    pub const LEN: usize = 8 + 32 + 8;
+
+    // Checks the owner, discriminator and legacy size by hand, so a config that has
+    // already been migrated is rejected.
+    pub fn try_from_account(info: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*info.owner, crate::ID, CustomError::InvalidLegacyConfig);
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() == Self::LEN && data.starts_with(ProtocolConfig::DISCRIMINATOR),
+            CustomError::InvalidLegacyConfig
+        );
+        Self::deserialize(&mut &data[ProtocolConfig::DISCRIMINATOR.len()..])
+            .map_err(|_| error!(CustomError::InvalidLegacyConfig))
+    }
+}
+
+// Layout of a creator account from before content items had their own accounts, when they
+// were kept in a list on the creator account. Only used by `migrate_creator_content`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyCreatorAccount {
+    pub creator_wallet: Pubkey,
+    pub last_content_id: u64,
+    pub content: Vec<LegacyContentItem>,
+    pub profile_cid: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyContentItem {
+    pub id: u64,
+    pub title: String,
+    pub price: u64, // Price in lamports
+    pub encrypted_cid: Vec<u8>,
+}
+
+impl LegacyCreatorAccount {
+    // Checks the owner and discriminator by hand. Both layouts share the discriminator, but
+    // current creator accounts are always sized exactly for their profile CID, which legacy
+    // ones (sized for their content list) are not.
+    pub fn try_from_account(info: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*info.owner, crate::ID, CustomError::InvalidLegacyCreator);
+        Self::try_from_data(&info.try_borrow_data()?)
+    }
+
+    pub fn try_from_data(data: &[u8]) -> Result<Self> {
+        require!(data.starts_with(CreatorAccount::DISCRIMINATOR), CustomError::InvalidLegacyCreator);
+        let body = &data[CreatorAccount::DISCRIMINATOR.len()..];
+        let is_current = CreatorAccount::deserialize(&mut &body[..])
+            .map_or(false, |current| data.len() == CreatorAccount::space(current.profile_cid.len()));
+        require!(!is_current, CustomError::InvalidLegacyCreator);
+        Self::deserialize(&mut &body[..]).map_err(|_| error!(CustomError::InvalidLegacyCreator))
+    }
+}
+
+// A set of a creator's content items sold together, at `[b"bundle", creator, bundle_id]`.
+#[account]
+pub struct Bundle {
+    pub creator: Pubkey, // The creator's wallet address
+    pub bundle_id: u8, // Creator-chosen ID, unique per creator
+    pub content_ids: Vec<u64>, // The content items the bundle unlocks
+    pub price: u64, // Price before any discount, in lamports or base units of `payment_mint`
+    pub discount_bps: Option<u64>, // Discount off `price`, in basis points
+    pub payment_mint: Option<Pubkey>, // SPL mint the bundle is priced in (None = SOL)
+    pub transfer_fee_payer: TransferFeePayer, // Who absorbs the mint's transfer fee, if any
+}
+
+impl Bundle {
+    // discriminator + creator + bundle_id + content_ids + price + discount + payment_mint + fee payer
+    pub const LEN: usize = 8 + 32 + 1 + (4 + 8 * MAX_BUNDLE_CONTENT_IDS) + 8 + (1 + 8) + (1 + 32) + 1;
+
+    // A bundle lists between 1 and `MAX_BUNDLE_CONTENT_IDS` distinct, existing content IDs.
+    pub fn validate(content_ids: &[u64], discount_bps: Option<u64>, last_content_id: u64) -> Result<()> {
+        require!(
+            !content_ids.is_empty() && content_ids.len() <= MAX_BUNDLE_CONTENT_IDS,
+            CustomError::InvalidBundle
+        );
+        require!(
+            content_ids
+                .iter()
+                .enumerate()
+                .all(|(index, id)| (1..=last_content_id).contains(id) && !content_ids[..index].contains(id)),
+            CustomError::InvalidBundle
+        );
+        if let Some(discount_bps) = discount_bps {
+            require!(discount_bps <= fee::BPS_DENOMINATOR, CustomError::InvalidDiscount);
+        }
+        Ok(())
+    }
+
+    // Checks that `content_items` start with the creator's content item accounts for
+    // `content_ids`, in the same order, and that each of them can be sold in a bundle.
+    pub fn check_content_items(creator: &Pubkey, content_ids: &[u64], content_items: &[AccountInfo]) -> Result<()> {
+        require!(content_items.len() >= content_ids.len(), CustomError::InvalidBundle);
+        for (content_id, info) in content_ids.iter().zip(content_items) {
+            let content_item = load_optional::<ContentItem>(info)?.ok_or(CustomError::InvalidBundle)?;
+            require!(
+                content_item.creator == *creator && content_item.id == *content_id,
+                CustomError::InvalidBundle
+            );
+            require!(content_item.is_bundleable(), CustomError::ContentNotBundleable);
+        }
+        Ok(())
+    }
+
+    // What a buyer pays: the price less the discount, which is rounded down.
+    pub fn sale_price(&self) -> Result<u64> {
+        let Some(discount_bps) = self.discount_bps else {
+            return Ok(self.price);
+        };
+        let discount = self.price as u128 * discount_bps as u128 / fee::BPS_DENOMINATOR as u128;
+        // discount_bps <= 10000, so the discount never exceeds the price.
+        Ok(self.price - discount as u64)
+    }
+}
+
+// A buyer's receipt for a bundle, at `[b"bundle_access", buyer, creator, bundle_id]`.
+// It grants access to the content the bundle held when it was bought.
+#[account]
+pub struct BundleReceipt {
+    pub buyer: Pubkey,
+    pub creator: Pubkey,
+    pub bundle_id: u8,
+    pub content_ids: Vec<u64>, // Copied from the bundle at purchase time
+    pub created_at: i64,
+    pub price: u64, // Price paid after any discount
+    pub fee_amount: u64,
+    pub creator_amount: u64,
+    pub payment_mint: Option<Pubkey>, // None = SOL
+}
+
+impl BundleReceipt {
+    // Exact serialized size: discriminator + buyer + creator + bundle_id + content_ids
+    // + created_at + price + fee_amount + creator_amount + payment_mint
+    pub fn space(content_ids_len: usize) -> usize {
+        8 + 32 + 32 + 1 + (4 + 8 * content_ids_len) + 8 + 8 + 8 + 8 + (1 + 32)
+    }
+
+    // The bundle counterpart to a `PaidAccessAccount` receipt.
+    pub fn grants_access(&self, creator: &Pubkey, content_id: u64) -> bool {
+        self.creator == *creator && self.content_ids.contains(&content_id)
+    }
+}
+
+// What a coupon takes off a content item's price.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CouponDiscount {
+    PercentOff { bps: u64 },   // Basis points off the price
+    AmountOff { amount: u64 }, // Fixed amount off, in the coupon's currency; never below zero
+}
+
+impl CouponDiscount {
+    // A discount must take something off, and at most the whole price.
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            CouponDiscount::PercentOff { bps } => bps > 0 && bps <= fee::BPS_DENOMINATOR,
+            CouponDiscount::AmountOff { amount } => amount > 0,
+        }
+    }
+}
+
+// A creator's promo code, at `[b"coupon", creator, code]`.
+#[account]
+pub struct Coupon {
+    pub creator: Pubkey, // The creator's wallet address
+    pub code: String, // Up to MAX_COUPON_CODE_LEN bytes
+    pub discount: CouponDiscount,
+    pub payment_mint: Option<Pubkey>, // Currency of an amount-off discount (None = SOL)
+    pub max_redemptions: Option<u64>, // Total uses allowed (None = unlimited)
+    pub redemptions: u64, // Times the coupon has been redeemed
+    pub max_per_buyer: Option<u64>, // Uses allowed per buyer (None = unlimited)
+    pub expires_at: Option<i64>, // Unix timestamp from which the coupon can't be used (None = never)
+}
+
+impl Coupon {
+    // discriminator + creator + code + discount (variant + value) + payment_mint
+    // + max_redemptions + redemptions + max_per_buyer + expires_at
+    pub const LEN: usize =
+        8 + 32 + (4 + MAX_COUPON_CODE_LEN) + (1 + 8) + (1 + 32) + (1 + 8) + 8 + (1 + 8) + (1 + 8);
+
+    // What the coupon takes off `price` for content priced in `payment_mint`, rounded down.
+    // Amount-off coupons only apply to content in their own currency.
+    pub fn discount_on(&self, price: u64, payment_mint: Option<Pubkey>) -> Result<u64> {
+        match self.discount {
+            CouponDiscount::PercentOff { bps } => {
+                Ok(fee::split_price(price, bps, FeeRounding::Down)?.fee_amount)
+            }
+            CouponDiscount::AmountOff { amount } => {
+                require!(self.payment_mint == payment_mint, CustomError::CouponNotApplicable);
+                Ok(amount.min(price))
+            }
+        }
+    }
+}
+
+// A buyer's uses of a coupon, at `[b"coupon_redemption", coupon, buyer]`.
+// Only needed for coupons with a per-buyer limit.
+#[account]
+pub struct CouponRedemption {
+    pub coupon: Pubkey,
+    pub buyer: Pubkey,
+    pub redemptions: u64, // Times the buyer has redeemed the coupon
+}
+
+impl CouponRedemption {
+    // discriminator + coupon + buyer + redemptions
+    pub const LEN: usize = 8 + 32 + 32 + 8;
+}
+
+// A creator-defined subscription plan: a recurring price for access to some or all content.
+#[account]
+pub struct SubscriptionTier {
+    pub creator: Pubkey, // The creator's wallet address
+    pub tier_id: u8, // Creator-chosen ID, unique per creator
+    pub price: u64, // Price per period, in lamports or base units of `payment_mint`
+    pub period_seconds: i64, // Length of one period
+    pub payment_mint: Option<Pubkey>, // SPL mint the tier is priced in (None = SOL)
+    pub transfer_fee_payer: TransferFeePayer, // Who absorbs the mint's transfer fee, if any
+    pub content_ids: Vec<u64>, // Content IDs covered by the tier (empty = all content)
+}
+
+impl SubscriptionTier {
+    // discriminator + creator + tier_id + price + period + payment_mint + fee payer + content_ids
+    pub const LEN: usize = 8 + 32 + 1 + 8 + 8 + (1 + 32) + 1 + (4 + 8 * MAX_TIER_CONTENT_IDS);
+
+    // Total price and duration of `periods` consecutive periods.
+    pub fn terms_for(&self, periods: u32) -> Result<(u64, i64)> {
+        require!(periods > 0, CustomError::InvalidSubscriptionPeriod);
+        let price = self
+            .price
+            .checked_mul(periods as u64)
+            .ok_or(CustomError::MathOverflow)?;
+        let duration = self
+            .period_seconds
+            .checked_mul(periods as i64)
+            .ok_or(CustomError::MathOverflow)?;
+        Ok((price, duration))
+    }
+
+    // Whether the tier unlocks the given content ID.
+    pub fn covers(&self, content_id: u64) -> bool {
+        self.content_ids.is_empty() || self.content_ids.contains(&content_id)
+    }
+}
+
+// A subscriber's subscription to a creator. There is one per subscriber and creator.
+#[account]
+pub struct Subscription {
+    pub subscriber: Pubkey,
+    pub creator: Pubkey, // The creator's wallet address
+    pub tier_id: u8, // The tier currently paid for
+    pub started_at: i64, // Start of the current unbroken run of periods
+    pub expires_at: i64, // Access ends at this Unix timestamp
+}
+
+impl Subscription {
+    // discriminator + subscriber + creator + tier_id + started_at + expires_at
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8;
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+
+    // The subscription counterpart to a `PaidAccessAccount` receipt: whether it currently
+    // grants access to `content_item`, given the tier it points at. Like bundles, tiers don't
+    // unlock limited editions or unlisted items.
+    pub fn grants_access(&self, tier: &SubscriptionTier, content_item: &ContentItem, now: i64) -> bool {
+        self.is_active(now)
+            && tier.creator == self.creator
+            && tier.tier_id == self.tier_id
+            && content_item.creator == self.creator
+            && tier.covers(content_item.id)
+            && content_item.is_bundleable()
+    }
+}
+
+
+// 2. INSTRUCTION CONTEXTS
+// These structs define the accounts required by each instruction.
+// Anchor uses this to validate that the correct accounts are passed in.
+
+// Context for initializing the protocol config
+#[derive(Accounts)]
+#[instruction(initial_fee_percentage: u64)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolConfig::LEN,
+        seeds = [b"config"],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = Treasury::LEN,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    // The config in its legacy layout, which `Account` can't deserialize.
+    /// CHECK: `migrate_config` checks the owner, discriminator and size and decodes it
+    /// with `LegacyProtocolConfig`.
+    #[account(mut, seeds = [b"config"], bump)]
+    pub protocol_config: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = admin,
+        space = Treasury::LEN,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    // Must be the admin recorded in the legacy config; pays for the extra rent.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Context for updating the protocol config, and for proposing or cancelling an admin handover
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>, // Only current admin can sign
+}
+
+// Context for accepting a proposed admin handover
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub new_admin: Signer<'info>, // Must be the pending admin
+}
+
+// Context for handing over the fee recipient role
+#[derive(Accounts)]
+pub struct SetFeeRecipient<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub fee_recipient: Signer<'info>, // Must be the current fee recipient
+}
+
+// Context for creating a creator's fee override
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct CreateFeeOverride<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = FeeOverride::LEN,
+        seeds = [b"fee_override", creator.as_ref()],
+        bump
+    )]
+    pub fee_override: Account<'info, FeeOverride>,
+    #[account(seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>, // Only the current admin can manage overrides
+    pub system_program: Program<'info, System>,
+}
+
+// Context for updating a creator's fee override
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct UpdateFeeOverride<'info> {
+    #[account(mut, seeds = [b"fee_override", creator.as_ref()], bump)]
+    pub fee_override: Account<'info, FeeOverride>,
+    #[account(seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>, // Only the current admin can manage overrides
+}
+
+// Context for removing a creator's fee override. The rent goes back to the admin.
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct CloseFeeOverride<'info> {
+    #[account(mut, seeds = [b"fee_override", creator.as_ref()], bump, close = admin)]
+    pub fee_override: Account<'info, FeeOverride>,
+    #[account(seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>, // Only the current admin can manage overrides
+}
+
+// Context for changing the fee cap
+#[derive(Accounts)]
+pub struct SetMaxFeePercentage<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub authority: Signer<'info>, // Governance, or the admin when lowering the cap
+}
+
+// Context for handing over the governance role
+#[derive(Accounts)]
+pub struct SetGovernance<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub governance: Signer<'info>, // Must be the current governance key
+}
+
+// Context for pausing and unpausing instructions
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub authority: Signer<'info>, // The admin or the guardian
+}
+
+// Context for withdrawing collected fees from the treasury
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    // Withdrawals can only go to the configured fee recipient.
+    /// CHECK: This is the fee recipient's wallet, validated by the address constraint.
+    #[account(mut, address = protocol_config.fee_recipient)]
+    pub fee_recipient: AccountInfo<'info>,
+
+    pub admin: Signer<'info>, // Only the current admin can withdraw
+
+    // The accounts below are only required to withdraw tokens.
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+    // The treasury's associated token account for the mint.
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    // The fee recipient's associated token account for the mint.
+    #[account(mut)]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl<'info> WithdrawTreasury<'info> {
+    fn withdraw_lamports(&self, amount: u64) -> Result<()> {
+        let treasury = self.treasury.to_account_info();
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(Treasury::LEN);
+        let available = treasury.lamports().saturating_sub(rent_exempt_minimum);
+        require!(amount <= available, CustomError::InsufficientTreasuryBalance);
+
+        treasury.sub_lamports(amount)?;
+        self.fee_recipient.add_lamports(amount)?;
+        Ok(())
+    }
+
+    fn withdraw_tokens(&self, amount: u64) -> Result<()> {
+        let (
+            Some(mint),
+            Some(treasury_token_account),
+            Some(recipient_token_account),
+            Some(token_program),
+        ) = (
+            self.payment_mint.as_ref(),
+            self.treasury_token_account.as_ref(),
+            self.recipient_token_account.as_ref(),
+            self.token_program.as_ref(),
+        ) else {
+            return err!(CustomError::MissingTokenAccounts);
+        };
+
+        require_keys_eq!(*mint.to_account_info().owner, token_program.key(), CustomError::InvalidTokenAccount);
+        require_keys_eq!(
+            treasury_token_account.key(),
+            get_associated_token_address_with_program_id(&self.treasury.key(), &mint.key(), &token_program.key()),
+            CustomError::InvalidTokenAccount
+        );
+        require_keys_eq!(
+            recipient_token_account.key(),
+            get_associated_token_address_with_program_id(self.fee_recipient.key, &mint.key(), &token_program.key()),
+            CustomError::InvalidTokenAccount
+        );
+        require!(amount <= treasury_token_account.amount, CustomError::InsufficientTreasuryBalance);
+
+        let recipient_info = recipient_token_account.to_account_info();
+        if requires_incoming_memo(&recipient_info)? {
+            let memo_program = self.memo_program.as_ref().ok_or(CustomError::MemoProgramRequired)?;
+            memo::build_memo(CpiContext::new(memo_program.to_account_info(), BuildMemo {}), TREASURY_MEMO)?;
+        }
+
+        let treasury_seeds: &[&[u8]] = &[b"treasury", &[self.treasury.bump]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: treasury_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: recipient_info,
+                    authority: self.treasury.to_account_info(),
+                },
+                &[treasury_seeds],
+            ),
+            amount,
+            mint.decimals,
+        )
+    }
+}
+
+// NEW: Context for registering a username
+#[derive(Accounts)]
+#[instruction(username: String)]
+pub struct RegisterUsername<'info> {
+    // The PDA account for the username registry entry.
+    // Seeds include the username, ensuring each username can only be claimed once.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 32 + 4 + username.len(), // discriminator + pubkey + string length + username
+        seeds = [b"username", username.as_bytes()],
+        bump
+    )]
+    pub username_account: Account<'info, UsernameAccount>,
+
+    // Read to check whether registrations are paused.
+    #[account(seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    // The creator claiming the username
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    // The account paying for the rent. Can be the creator or a relayer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateCreatorContent<'info> {
+    // The creator account in its legacy layout, which `Account` can't deserialize.
+    // The content item accounts to create are passed as remaining accounts.
+    /// CHECK: `migrate_creator_content` checks the owner and discriminator and decodes it
+    /// with `LegacyCreatorAccount`.
+    #[account(mut, seeds = [b"creator", creator.key().as_ref()], bump)]
+    pub creator_account: UncheckedAccount<'info>,
+    // The creator, who pays for the content item accounts.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeCreator<'info> {
+    // The PDA account for the creator's profile and content counter.
+    // `init` means this instruction will create the account.
+    // `payer = payer` means the payer account will pay for the account's rent.
+    // `space` is the initial space allocation. 8 for the discriminator, 32 for the pubkey, 8 for the counter.
+    // Content items live in their own accounts, so this only grows with the profile CID.
+    #[account(
+        init,
+        payer = payer,
+        space = CreatorAccount::space(0), // Starts with an empty profile_cid
+        seeds = [b"creator", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_account: Account<'info, CreatorAccount>,
+
+    // Read to check whether creator sign-ups are paused.
+    #[account(seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // The creator, who must sign the transaction to authorize account creation.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    // The account paying for the rent. Can be the creator or a relayer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    // The system program, required by Solana to create accounts.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(title: String, price: u64, encrypted_cid: Vec<u8>)]
+pub struct AddContent<'info> {
+    // The creator's account. It must be mutable to bump the content counter.
+    #[account(
+        mut,
+        seeds = [b"creator", creator.key().as_ref()],
+        bump,
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
+    )]
+    pub creator_account: Account<'info, CreatorAccount>,
+
+    // The new content item's account, at the next content ID.
+    // It is sized exactly for the title and encrypted CID being stored.
+    #[account(
+        init,
+        payer = payer,
+        space = ContentItem::space(title.len(), encrypted_cid.len()),
+        seeds = [
+            b"content",
+            creator.key().as_ref(),
+            &(creator_account.last_content_id + 1).to_le_bytes()
+        ],
+        bump
+    )]
+    pub content_item: Account<'info, ContentItem>,
+
+    // Read to check whether adding content is paused.
+    #[account(seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    // The creator, who must sign.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    // The account paying for the extra rent. Can be the creator or a relayer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Optional SPL or Token-2022 mint to price the content in. Omit it to price the content in lamports.
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    content_id: u64,
+    title: Option<String>,
+    price: Option<u64>,
+    encrypted_cid: Option<Vec<u8>>
+)]
+pub struct UpdateContent<'info> {
+    // The seeds and has_one tie the content item to the signing creator.
+    // Resized to the new title and encrypted CID lengths; the creator pays for growth
+    // and is refunded when it shrinks.
+    #[account(
+        mut,
+        seeds = [b"content", creator.key().as_ref(), &content_id.to_le_bytes()],
+        bump,
+        has_one = creator,
+        realloc = ContentItem::space(
+            title.as_ref().map_or(content_item.title.len(), |title| title.len()),
+            encrypted_cid.as_ref().map_or(content_item.encrypted_cid.len(), |cid| cid.len()),
+        ),
+        realloc::payer = creator,
+        realloc::zero = false
+    )]
+    pub content_item: Account<'info, ContentItem>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Shared by `unlist_content`, `relist_content`, `set_max_supply` and `set_rental_terms`.
+#[derive(Accounts)]
+#[instruction(content_id: u64)]
+pub struct SetContentListing<'info> {
+    #[account(
+        mut,
+        seeds = [b"content", creator.key().as_ref(), &content_id.to_le_bytes()],
+        bump,
+        has_one = creator
+    )]
+    pub content_item: Account<'info, ContentItem>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(content_id: u64)]
+pub struct SetPayWhatYouWant<'info> {
+    #[account(
+        mut,
+        seeds = [b"content", creator.key().as_ref(), &content_id.to_le_bytes()],
+        bump,
+        has_one = creator
+    )]
+    pub content_item: Account<'info, ContentItem>,
+
+    // Must exist to turn pay-what-you-want pricing on.
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(
+        seeds = [b"supporters", creator.key().as_ref(), content_item.payment_mint.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub supporter_stats: UncheckedAccount<'info>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"creator", creator.key().as_ref()],
+        bump,
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
+    )]
+    pub creator_account: Account<'info, CreatorAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(content_id: u64)]
+pub struct ProcessPayment<'info> {
+    // The PDA "receipt" account.
+    // The seeds ensure that a user can only have one receipt per content item.
+    // Content IDs are only unique per creator, so the creator wallet is part of the seeds.
+    // Created by the handler, or overwritten when it holds a rental of the item.
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(
+        mut,
+        seeds = [
+            b"access",
+            buyer.key().as_ref(),
+            creator_account.creator_wallet.as_ref(),
+            &content_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub paid_access_account: UncheckedAccount<'info>,
+
+    // The protocol's global configuration account
+    #[account(seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    // The creator's account, used to verify the payment destination.
+    #[account(
+        mut,
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
+    )]
+    pub creator_account: Account<'info, CreatorAccount>,
+
+    // The content item being bought, used to verify the price and payment mint.
+    // Mutable so the sale can be counted against its supply.
+    #[account(
+        mut,
+        seeds = [b"content", creator_account.creator_wallet.as_ref(), &content_id.to_le_bytes()],
+        bump
+    )]
+    pub content_item: Account<'info, ContentItem>,
+
+    // The creator's wallet, derived from the creator_account.
+    // The `address` constraint is a key security feature: it ensures the client
+    // passes the correct wallet address that is stored in the creator_account.
+    /// CHECK: This is the creator's wallet address, validated by the address constraint.
+    #[account(mut, address = creator_account.creator_wallet)]
+    pub creator_wallet: AccountInfo<'info>,
+
+    // The protocol treasury that collects the platform fee.
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    // The creator's fee override PDA. Always passed; it is only read if the admin has created it.
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(
+        seeds = [b"fee_override", creator_account.creator_wallet.as_ref()],
+        bump
+    )]
+    pub fee_override: UncheckedAccount<'info>,
+
+    // The content item's revenue split and the creator's default split. Always passed;
+    // the content split is used if it exists, then the default, else the creator wallet is paid.
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(
+        seeds = [b"split", creator_account.creator_wallet.as_ref(), &content_id.to_le_bytes()],
+        bump
+    )]
+    pub content_split: UncheckedAccount<'info>,
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(
+        seeds = [b"split", creator_account.creator_wallet.as_ref(), &CREATOR_DEFAULT_SPLIT.to_le_bytes()],
+        bump
+    )]
+    pub creator_split: UncheckedAccount<'info>,
+
+    // The creator's supporter stats for the content's currency. Always passed; it is only
+    // updated for pay-what-you-want content, which can't be enabled without it.
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(
+        mut,
+        seeds = [
+            b"supporters",
+            creator_account.creator_wallet.as_ref(),
+            content_item.payment_mint.unwrap_or_default().as_ref()
+        ],
+        bump
+    )]
+    pub supporter_stats: UncheckedAccount<'info>,
+
+    // The user who is paying.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // The accounts below are only required when the content is priced in a token.
+    // They are validated against the content's listed mint in `PaymentAccounts::route`.
+
+    // The mint the content is priced in (SPL Token or Token-2022).
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // The buyer's token account the payment is drawn from.
+    #[account(mut)]
+    pub buyer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // The creator wallet's associated token account for the mint.
+    #[account(mut)]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // The treasury's associated token account for the mint, which receives the fee.
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // The token program that owns the mint.
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // Only needed when a receiving Token-2022 account has the memo-required extension enabled.
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    // The accounts below are only passed when the buyer was referred. They are validated
+    // against the stats account in `ProcessPayment::referral`.
+
+    // The referrer's stats for the content's currency, credited with the sale.
+    #[account(mut)]
+    pub referrer_stats: Option<Account<'info, ReferrerStats>>,
+
+    // The referrer's wallet, which receives SOL referral payouts.
+    /// CHECK: Checked against `referrer_stats.referrer` in `ProcessPayment::referral`.
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    // The referrer wallet's associated token account for the mint, for token payouts.
+    #[account(mut)]
+    pub referrer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // The accounts below are only passed when the buyer redeems a coupon. They are
+    // validated in `ProcessPayment::coupon_discount`.
+
+    // The creator's coupon, whose redemption count is incremented.
+    #[account(mut)]
+    pub coupon: Option<Account<'info, Coupon>>,
+
+    // The buyer's redemption counter, required when the coupon limits uses per buyer.
+    #[account(mut)]
+    pub coupon_redemption: Option<Account<'info, CouponRedemption>>,
+}
+
+impl<'info> ProcessPayment<'info> {
+    // The split that applies to this sale and its address, if any.
+    fn revenue_split(&self) -> Result<Option<(Pubkey, RevenueSplit)>> {
+        load_revenue_split(&self.content_split, &self.creator_split)
+    }
+
+    // The amount a coupon takes off the content's price (0 without one), once it is checked to be redeemable.
+    fn coupon_discount(&self, now: i64) -> Result<u64> {
+        let Some(coupon) = &self.coupon else {
+            return Ok(0);
+        };
+        require_keys_eq!(coupon.creator, self.creator_account.creator_wallet, CustomError::CouponNotApplicable);
+        if let Some(expires_at) = coupon.expires_at {
+            require!(now < expires_at, CustomError::CouponExpired);
+        }
+        if let Some(max_redemptions) = coupon.max_redemptions {
+            require!(coupon.redemptions < max_redemptions, CustomError::CouponLimitReached);
+        }
+        if let Some(max_per_buyer) = coupon.max_per_buyer {
+            let redemption = self.coupon_redemption.as_ref().ok_or(CustomError::CouponNotApplicable)?;
+            require_keys_eq!(redemption.coupon, coupon.key(), CustomError::CouponNotApplicable);
+            require_keys_eq!(redemption.buyer, self.buyer.key(), CustomError::CouponNotApplicable);
+            require!(redemption.redemptions < max_per_buyer, CustomError::CouponLimitReached);
+        }
+        coupon.discount_on(self.content_item.price, self.content_item.payment_mint)
+    }
+
+    // The referrer's cut of the creator's share, if the buyer was referred: the content
+    // item's affiliate share, else the creator's. Paid to the referrer's wallet for SOL,
+    // or to its associated token account for tokens.
+    fn referral(&self) -> Result<Option<Payee<'info>>> {
+        let Some(referrer_stats) = &self.referrer_stats else {
+            return Ok(None);
+        };
+        require_keys_neq!(referrer_stats.referrer, self.buyer.key(), CustomError::SelfReferral);
+        require!(
+            referrer_stats.payment_mint == self.content_item.payment_mint,
+            CustomError::InvalidReferrer
+        );
+
+        let destination = match self.content_item.payment_mint {
+            None => {
+                let referrer = self.referrer.as_ref().ok_or(CustomError::InvalidReferrer)?;
+                require_keys_eq!(referrer.key(), referrer_stats.referrer, CustomError::InvalidReferrer);
+                referrer.to_account_info()
+            }
+            Some(mint) => {
+                let (Some(referrer_token_account), Some(token_program)) =
+                    (&self.referrer_token_account, &self.token_program)
+                else {
+                    return err!(CustomError::InvalidReferrer);
+                };
+                require_keys_eq!(
+                    referrer_token_account.key(),
+                    get_associated_token_address_with_program_id(
+                        &referrer_stats.referrer,
+                        &mint,
+                        &token_program.key(),
+                    ),
+                    CustomError::InvalidReferrer
+                );
+                referrer_token_account.to_account_info()
+            }
+        };
+
+        Ok(Some(Payee {
+            destination,
+            share_bps: self
+                .content_item
+                .affiliate_bps
+                .unwrap_or(self.creator_account.affiliate_bps),
+        }))
+    }
+
+    fn payment_accounts(&self) -> PaymentAccounts<'_, 'info> {
+        PaymentAccounts {
+            payer: &self.buyer,
+            creator_wallet: &self.creator_wallet,
+            treasury: &self.treasury,
+            system_program: &self.system_program,
+            payment_mint: self.payment_mint.as_ref(),
+            payer_token_account: self.buyer_token_account.as_ref(),
+            creator_token_account: self.creator_token_account.as_ref(),
+            treasury_token_account: self.treasury_token_account.as_ref(),
+            token_program: self.token_program.as_ref(),
+            memo_program: self.memo_program.as_ref(),
+        }
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(content_id: u64)]
+pub struct RentContent<'info> {
+    // The rental receipt, at the same address a purchase receipt would have: a buyer can't
+    // rent content they own or are already renting (see `RenewRental`), and buying it later
+    // turns the rental into a purchase.
+    #[account(
+        init,
+        payer = buyer,
+        space = PaidAccessAccount::LEN,
+        seeds = [
+            b"access",
+            buyer.key().as_ref(),
+            content_item.creator.as_ref(),
+            &content_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub paid_access_account: Account<'info, PaidAccessAccount>,
+
+    // The creator's account, which counts the rental towards the creator's volume tier.
+    #[account(
+        mut,
+        seeds = [b"creator", content_item.creator.as_ref()],
+        bump,
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
+    )]
+    pub creator_account: Account<'info, CreatorAccount>,
+
+    #[account(
+        seeds = [b"content", content_item.creator.as_ref(), &content_id.to_le_bytes()],
+        bump
+    )]
+    pub content_item: Account<'info, ContentItem>,
+
+    #[account(seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: This is the creator's wallet address, validated by the address constraint.
+    #[account(mut, address = content_item.creator)]
+    pub creator_wallet: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    // The creator's fee override and the content and default revenue splits. See `ProcessPayment`.
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(seeds = [b"fee_override", content_item.creator.as_ref()], bump)]
+    pub fee_override: UncheckedAccount<'info>,
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(
+        seeds = [b"split", content_item.creator.as_ref(), &content_id.to_le_bytes()],
+        bump
+    )]
+    pub content_split: UncheckedAccount<'info>,
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(
+        seeds = [b"split", content_item.creator.as_ref(), &CREATOR_DEFAULT_SPLIT.to_le_bytes()],
+        bump
+    )]
+    pub creator_split: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // Only required when the content is priced in a token. See `ProcessPayment`.
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub buyer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl<'info> RentContent<'info> {
+    fn payment_accounts(&self) -> PaymentAccounts<'_, 'info> {
+        PaymentAccounts {
+            payer: &self.buyer,
+            creator_wallet: &self.creator_wallet,
+            treasury: &self.treasury,
+            system_program: &self.system_program,
+            payment_mint: self.payment_mint.as_ref(),
+            payer_token_account: self.buyer_token_account.as_ref(),
+            creator_token_account: self.creator_token_account.as_ref(),
+            treasury_token_account: self.treasury_token_account.as_ref(),
+            token_program: self.token_program.as_ref(),
+            memo_program: self.memo_program.as_ref(),
+        }
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(content_id: u64)]
+pub struct RenewRental<'info> {
+    // The buyer's existing rental receipt. Purchase receipts are rejected in `renew_rental`.
+    #[account(
+        mut,
+        seeds = [
+            b"access",
+            buyer.key().as_ref(),
+            content_item.creator.as_ref(),
+            &content_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub paid_access_account: Account<'info, PaidAccessAccount>,
+
+    // The creator's account, which counts the rental towards the creator's volume tier.
+    #[account(
+        mut,
+        seeds = [b"creator", content_item.creator.as_ref()],
+        bump,
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
+    )]
+    pub creator_account: Account<'info, CreatorAccount>,
+
+    #[account(
+        seeds = [b"content", content_item.creator.as_ref(), &content_id.to_le_bytes()],
+        bump
+    )]
+    pub content_item: Account<'info, ContentItem>,
+
+    #[account(seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: This is the creator's wallet address, validated by the address constraint.
+    #[account(mut, address = content_item.creator)]
+    pub creator_wallet: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    // The creator's fee override and the content and default revenue splits. See `ProcessPayment`.
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(seeds = [b"fee_override", content_item.creator.as_ref()], bump)]
+    pub fee_override: UncheckedAccount<'info>,
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(
+        seeds = [b"split", content_item.creator.as_ref(), &content_id.to_le_bytes()],
+        bump
+    )]
+    pub content_split: UncheckedAccount<'info>,
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(
+        seeds = [b"split", content_item.creator.as_ref(), &CREATOR_DEFAULT_SPLIT.to_le_bytes()],
+        bump
+    )]
+    pub creator_split: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // Only required when the content is priced in a token. See `ProcessPayment`.
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub buyer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl<'info> RenewRental<'info> {
+    fn payment_accounts(&self) -> PaymentAccounts<'_, 'info> {
+        PaymentAccounts {
+            payer: &self.buyer,
+            creator_wallet: &self.creator_wallet,
+            treasury: &self.treasury,
+            system_program: &self.system_program,
+            payment_mint: self.payment_mint.as_ref(),
+            payer_token_account: self.buyer_token_account.as_ref(),
+            creator_token_account: self.creator_token_account.as_ref(),
+            treasury_token_account: self.treasury_token_account.as_ref(),
+            token_program: self.token_program.as_ref(),
+            memo_program: self.memo_program.as_ref(),
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct SetAffiliateShare<'info> {
+    #[account(
+        mut,
+        seeds = [b"creator", creator.key().as_ref()],
+        bump,
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
+    )]
+    pub creator_account: Account<'info, CreatorAccount>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(content_id: u64)]
+pub struct SetContentAffiliateShare<'info> {
+    #[account(
+        mut,
+        seeds = [b"content", creator.key().as_ref(), &content_id.to_le_bytes()],
+        bump,
+        has_one = creator
+    )]
+    pub content_item: Account<'info, ContentItem>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(payment_mint: Option<Pubkey>)]
+pub struct RegisterReferrer<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = ReferrerStats::LEN,
+        seeds = [b"referrer", referrer.key().as_ref(), payment_mint.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub referrer_stats: Account<'info, ReferrerStats>,
+
+    pub referrer: Signer<'info>,
+
+    // The account paying for the rent. Can be the referrer or a relayer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(payment_mint: Option<Pubkey>)]
+pub struct CreateSupporterStats<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = SupporterStats::LEN,
+        seeds = [b"supporters", creator.key().as_ref(), payment_mint.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub supporter_stats: Account<'info, SupporterStats>,
+
+    pub creator: Signer<'info>,
+
+    // The account paying for the rent. Can be the creator or a relayer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
-#[account]
-pub struct PaidAccessAccount {
-    pub buyer: Pubkey,
-    pub content_id: u64, // ID of the content this receipt grants access to
-    pub creator: Pubkey, // Store which creator this receipt is for
-    pub created_at: i64, // Unix timestamp for when the receipt was created
+#[derive(Accounts)]
+pub struct TipCreator<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    // The creator being tipped, read for their fee tier.
+    #[account(constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator)]
+    pub creator_account: Account<'info, CreatorAccount>,
+
+    /// CHECK: This is the creator's wallet address, validated by the address constraint.
+    #[account(mut, address = creator_account.creator_wallet)]
+    pub creator_wallet: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(
+        seeds = [b"fee_override", creator_account.creator_wallet.as_ref()],
+        bump
+    )]
+    pub fee_override: UncheckedAccount<'info>,
+
+    // The creator's stats for the tip's currency, which must exist for the creator to be tipped.
+    // Its currency is checked against `payment_mint` in `tip_creator`.
+    #[account(
+        mut,
+        constraint = supporter_stats.creator == creator_account.creator_wallet @ CustomError::InvalidSupporterStats
+    )]
+    pub supporter_stats: Account<'info, SupporterStats>,
+
+    #[account(mut)]
+    pub tipper: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // The accounts below are only required for tips in a token.
+    // They are validated against the mint in `PaymentAccounts::route`.
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub tipper_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub memo_program: Option<Program<'info, Memo>>,
 }
 
+impl<'info> TipCreator<'info> {
+    fn payment_accounts(&self) -> PaymentAccounts<'_, 'info> {
+        PaymentAccounts {
+            payer: &self.tipper,
+            creator_wallet: &self.creator_wallet,
+            treasury: &self.treasury,
+            system_program: &self.system_program,
+            payment_mint: self.payment_mint.as_ref(),
+            payer_token_account: self.tipper_token_account.as_ref(),
+            creator_token_account: self.creator_token_account.as_ref(),
+            treasury_token_account: self.treasury_token_account.as_ref(),
+            token_program: self.token_program.as_ref(),
+            memo_program: self.memo_program.as_ref(),
+        }
+    }
+}
 
-// 2. INSTRUCTION CONTEXTS
-// These structs define the accounts required by each instruction.
-// Anchor uses this to validate that the correct accounts are passed in.
+#[derive(Accounts)]
+#[instruction(content_id: u64)]
+pub struct CreateRevenueSplit<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = RevenueSplit::LEN,
+        seeds = [b"split", creator.key().as_ref(), &content_id.to_le_bytes()],
+        bump
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+
+    // Used to check the content ID exists.
+    #[account(
+        seeds = [b"creator", creator.key().as_ref()],
+        bump,
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
+    )]
+    pub creator_account: Account<'info, CreatorAccount>,
+
+    pub creator: Signer<'info>,
+
+    // The account paying for the rent. Can be the creator or a relayer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
 
-// Context for initializing the protocol config
 #[derive(Accounts)]
-#[instruction(initial_fee_percentage: u64)]
-pub struct InitializeConfig<'info> {
+#[instruction(content_id: u64)]
+pub struct UpdateRevenueSplit<'info> {
+    // The seeds tie the split to the signing creator.
+    #[account(
+        mut,
+        seeds = [b"split", creator.key().as_ref(), &content_id.to_le_bytes()],
+        bump
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(content_id: u64)]
+pub struct CloseRevenueSplit<'info> {
+    #[account(
+        mut,
+        seeds = [b"split", creator.key().as_ref(), &content_id.to_le_bytes()],
+        bump,
+        close = creator
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(content_id: u64, creator: Pubkey)]
+pub struct MigrateReceipt<'info> {
+    // The receipt derived from the legacy seeds, which did not include the creator.
+    // It is closed once its data has been copied, returning the rent to the buyer.
+    /// CHECK: Legacy receipts use an older layout, so `migrate_receipt` checks the owner
+    /// and discriminator and decodes them with `LegacyPaidAccessAccount`.
+    #[account(
+        mut,
+        seeds = [b"access", buyer.key().as_ref(), &content_id.to_le_bytes()],
+        bump
+    )]
+    pub legacy_access_account: UncheckedAccount<'info>,
+
+    // The creator-scoped receipt, using the creator recorded on the legacy receipt.
     #[account(
         init,
-        payer = admin,
-        space = 8 + 32 + 8, // discriminator + admin_wallet pubkey + fee_percentage u64
-        seeds = [b"config"],
+        payer = payer,
+        space = PaidAccessAccount::LEN,
+        seeds = [
+            b"access",
+            buyer.key().as_ref(),
+            creator.as_ref(),
+            &content_id.to_le_bytes()
+        ],
         bump
     )]
-    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub paid_access_account: Account<'info, PaidAccessAccount>,
+
+    // The buyer who owns the receipt. Does not need to sign since nothing is taken from them.
+    /// CHECK: Validated against the legacy receipt in `migrate_receipt`.
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub buyer: UncheckedAccount<'info>,
+
+    // The account paying for the new receipt's rent. Can be the buyer or a relayer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
-// Context for updating the protocol config
 #[derive(Accounts)]
-pub struct UpdateConfig<'info> {
-    #[account(mut, seeds = [b"config"], bump)]
+#[instruction(bundle_id: u8)]
+pub struct CreateBundle<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = Bundle::LEN,
+        seeds = [b"bundle", creator.key().as_ref(), &[bundle_id]],
+        bump
+    )]
+    pub bundle: Account<'info, Bundle>,
+
+    // Read to check the bundled content IDs exist.
+    #[account(
+        seeds = [b"creator", creator.key().as_ref()],
+        bump,
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
+    )]
+    pub creator_account: Account<'info, CreatorAccount>,
+
+    pub creator: Signer<'info>,
+
+    // The account paying for the rent. Can be the creator or a relayer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Optional SPL or Token-2022 mint to price the bundle in. Omit it to price the bundle in lamports.
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(bundle_id: u8)]
+pub struct UpdateBundle<'info> {
+    // The seeds tie the bundle to the signing creator.
+    #[account(
+        mut,
+        seeds = [b"bundle", creator.key().as_ref(), &[bundle_id]],
+        bump
+    )]
+    pub bundle: Account<'info, Bundle>,
+
+    #[account(
+        seeds = [b"creator", creator.key().as_ref()],
+        bump,
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
+    )]
+    pub creator_account: Account<'info, CreatorAccount>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(bundle_id: u8)]
+pub struct CloseBundle<'info> {
+    #[account(
+        mut,
+        seeds = [b"bundle", creator.key().as_ref(), &[bundle_id]],
+        bump,
+        close = creator
+    )]
+    pub bundle: Account<'info, Bundle>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(bundle_id: u8)]
+pub struct PurchaseBundle<'info> {
+    // One bundle receipt per buyer and bundle, sized to the bundle's content list.
+    #[account(
+        init,
+        payer = buyer,
+        space = BundleReceipt::space(bundle.content_ids.len()),
+        seeds = [b"bundle_access", buyer.key().as_ref(), bundle.creator.as_ref(), &[bundle_id]],
+        bump
+    )]
+    pub bundle_receipt: Account<'info, BundleReceipt>,
+
+    #[account(
+        seeds = [b"bundle", bundle.creator.as_ref(), &[bundle_id]],
+        bump
+    )]
+    pub bundle: Account<'info, Bundle>,
+
+    #[account(seeds = [b"config"], bump)]
     pub protocol_config: Account<'info, ProtocolConfig>,
+
+    // Counts the sale towards the creator's volume tier.
+    #[account(
+        mut,
+        seeds = [b"creator", bundle.creator.as_ref()],
+        bump,
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
+    )]
+    pub creator_account: Account<'info, CreatorAccount>,
+
+    /// CHECK: This is the creator's wallet address, validated by the address constraint.
+    #[account(mut, address = bundle.creator)]
+    pub creator_wallet: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    // The creator's fee override and default revenue split. See `ProcessPayment`.
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(seeds = [b"fee_override", bundle.creator.as_ref()], bump)]
+    pub fee_override: UncheckedAccount<'info>,
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(
+        seeds = [b"split", bundle.creator.as_ref(), &CREATOR_DEFAULT_SPLIT.to_le_bytes()],
+        bump
+    )]
+    pub creator_split: UncheckedAccount<'info>,
+
     #[account(mut)]
-    pub admin: Signer<'info>, // Only current admin can sign
+    pub buyer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // Only required when the bundle is priced in a token. See `ProcessPayment`.
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub buyer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl<'info> PurchaseBundle<'info> {
+    fn payment_accounts(&self) -> PaymentAccounts<'_, 'info> {
+        PaymentAccounts {
+            payer: &self.buyer,
+            creator_wallet: &self.creator_wallet,
+            treasury: &self.treasury,
+            system_program: &self.system_program,
+            payment_mint: self.payment_mint.as_ref(),
+            payer_token_account: self.buyer_token_account.as_ref(),
+            creator_token_account: self.creator_token_account.as_ref(),
+            treasury_token_account: self.treasury_token_account.as_ref(),
+            token_program: self.token_program.as_ref(),
+            memo_program: self.memo_program.as_ref(),
+        }
+    }
 }
 
-// NEW: Context for registering a username
 #[derive(Accounts)]
-#[instruction(username: String)]
-pub struct RegisterUsername<'info> {
-    // The PDA account for the username registry entry.
-    // Seeds include the username, ensuring each username can only be claimed once.
+#[instruction(code: String)]
+pub struct CreateCoupon<'info> {
     #[account(
         init,
         payer = payer,
-        space = 8 + 32 + 4 + username.len(), // discriminator + pubkey + string length + username
-        seeds = [b"username", username.as_bytes()],
+        space = Coupon::LEN,
+        seeds = [b"coupon", creator.key().as_ref(), code.as_bytes()],
         bump
     )]
-    pub username_account: Account<'info, UsernameAccount>,
+    pub coupon: Account<'info, Coupon>,
 
-    // The creator claiming the username
-    #[account(mut)]
     pub creator: Signer<'info>,
 
     // The account paying for the rent. Can be the creator or a relayer.
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    // Optional mint an amount-off discount is in. Omit it for a discount in lamports.
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeCreator<'info> {
-    // The PDA account for the creator's content list.
-    // `init` means this instruction will create the account.
-    // `payer = payer` means the payer account will pay for the account's rent.
-    // `space` is the initial space allocation. 8 for the discriminator, 32 for the pubkey, 4 for the vector prefix.
-    // We will need to reallocate more space later when content is added.
+#[instruction(code: String)]
+pub struct CloseCoupon<'info> {
+    // The seeds tie the coupon to the signing creator.
+    #[account(
+        mut,
+        seeds = [b"coupon", creator.key().as_ref(), code.as_bytes()],
+        bump,
+        close = creator
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateCouponRedemption<'info> {
     #[account(
         init,
         payer = payer,
-        space = 8 + 32 + 8 + 4 + 4, // discriminator + wallet + counter + vec prefix + string prefix (profile_cid)
-        seeds = [b"creator", creator.key().as_ref()],
+        space = CouponRedemption::LEN,
+        seeds = [b"coupon_redemption", coupon.key().as_ref(), buyer.key().as_ref()],
         bump
     )]
-    pub creator_account: Account<'info, CreatorAccount>,
-    
-    // The creator, who must sign the transaction to authorize account creation.
+    pub coupon_redemption: Account<'info, CouponRedemption>,
+
+    pub coupon: Account<'info, Coupon>,
+
+    pub buyer: Signer<'info>,
+
+    // The account paying for the rent. Can be the buyer or a relayer.
     #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier_id: u8)]
+pub struct CreateSubscriptionTier<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = SubscriptionTier::LEN,
+        seeds = [b"tier", creator.key().as_ref(), &[tier_id]],
+        bump
+    )]
+    pub subscription_tier: Account<'info, SubscriptionTier>,
+
+    // Tiers can only be created by wallets that have a creator account.
+    #[account(
+        seeds = [b"creator", creator.key().as_ref()],
+        bump,
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
+    )]
+    pub creator_account: Account<'info, CreatorAccount>,
+
     pub creator: Signer<'info>,
 
     // The account paying for the rent. Can be the creator or a relayer.
     #[account(mut)]
     pub payer: Signer<'info>,
-    
-    // The system program, required by Solana to create accounts.
+
+    // Optional SPL or Token-2022 mint to price the tier in. Omit it to price the tier in lamports.
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct AddContent<'info> {
-    // The creator's content list account. It must be mutable to add content.
-    // `realloc` will increase the account's size to fit the new content.
-    // `realloc::payer` specifies who pays for the extra rent.
-    // `realloc::zero` ensures the new memory is zeroed out.
+#[instruction(tier_id: u8)]
+pub struct UpdateSubscriptionTier<'info> {
+    // The seeds tie the tier to the signing creator.
     #[account(
         mut,
-        seeds = [b"creator", creator.key().as_ref()],
-        bump,
-        // Approximate: id(8) + title(128) + price(8) + encrypted_cid(100)
-        // PLUS profile_cid current length
-        realloc = 8 + 32 + 8 + 4 + (creator_account.content.len() + 1) * (8 + 4 + 128 + 8 + 4 + 100) + (4 + creator_account.profile_cid.len()), 
-        realloc::payer = payer,
-        realloc::zero = true
+        seeds = [b"tier", creator.key().as_ref(), &[tier_id]],
+        bump
     )]
-    pub creator_account: Account<'info, CreatorAccount>,
+    pub subscription_tier: Account<'info, SubscriptionTier>,
 
-    // The creator, who must sign.
-    #[account(mut)]
     pub creator: Signer<'info>,
-
-    // The account paying for the extra rent. Can be the creator or a relayer.
-    #[account(mut)]
-    pub payer: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(profile_cid: String)]
-pub struct UpdateProfile<'info> {
+#[instruction(tier_id: u8)]
+pub struct Subscribe<'info> {
+    // One subscription per subscriber and creator.
+    #[account(
+        init,
+        payer = subscriber,
+        space = Subscription::LEN,
+        seeds = [b"subscription", subscriber.key().as_ref(), subscription_tier.creator.as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    #[account(
+        seeds = [b"tier", subscription_tier.creator.as_ref(), &[tier_id]],
+        bump
+    )]
+    pub subscription_tier: Account<'info, SubscriptionTier>,
+
+    #[account(seeds = [b"config"], bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    // Counts the sale towards the creator's volume tier.
     #[account(
         mut,
-        seeds = [b"creator", creator.key().as_ref()],
+        seeds = [b"creator", subscription_tier.creator.as_ref()],
         bump,
-        // Reallocate to fit new profile CID length + existing content
-        // Note: Using approximate size for content items again. 
-        // In production, you might want a cleaner way to track size or separate accounts.
-        realloc = 8 + 32 + 8 + 4 + (creator_account.content.len() * (8 + 4 + 128 + 8 + 4 + 100)) + (4 + profile_cid.len()),
-        realloc::payer = creator,
-        realloc::zero = false
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
     )]
     pub creator_account: Account<'info, CreatorAccount>,
 
+    /// CHECK: This is the creator's wallet address, validated by the address constraint.
+    #[account(mut, address = subscription_tier.creator)]
+    pub creator_wallet: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    // The creator's fee override. See `ProcessPayment`.
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(seeds = [b"fee_override", subscription_tier.creator.as_ref()], bump)]
+    pub fee_override: UncheckedAccount<'info>,
+
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub subscriber: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    // Only required when the tier is priced in a token. See `ProcessPayment`.
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub subscriber_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl<'info> Subscribe<'info> {
+    fn payment_accounts(&self) -> PaymentAccounts<'_, 'info> {
+        PaymentAccounts {
+            payer: &self.subscriber,
+            creator_wallet: &self.creator_wallet,
+            treasury: &self.treasury,
+            system_program: &self.system_program,
+            payment_mint: self.payment_mint.as_ref(),
+            payer_token_account: self.subscriber_token_account.as_ref(),
+            creator_token_account: self.creator_token_account.as_ref(),
+            treasury_token_account: self.treasury_token_account.as_ref(),
+            token_program: self.token_program.as_ref(),
+            memo_program: self.memo_program.as_ref(),
+        }
+    }
 }
 
 #[derive(Accounts)]
-#[instruction(content_id: u64)]
-pub struct ProcessPayment<'info> {
-    // The PDA "receipt" account.
-    // The seeds ensure that a user can only have one receipt per content item.
+#[instruction(tier_id: u8)]
+pub struct RenewSubscription<'info> {
     #[account(
-        init,
-        payer = buyer,
-        space = 8 + 32 + 8 + 32 + 8, // discriminator + buyer pubkey + content_id + creator pubkey + timestamp
-        seeds = [b"access", buyer.key().as_ref(), &content_id.to_le_bytes()],
+        mut,
+        seeds = [b"subscription", subscriber.key().as_ref(), subscription_tier.creator.as_ref()],
         bump
     )]
-    pub paid_access_account: Account<'info, PaidAccessAccount>,
+    pub subscription: Account<'info, Subscription>,
+
+    // The tier being paid for. The subscription's seeds already tie it to this tier's creator.
+    #[account(
+        seeds = [b"tier", subscription_tier.creator.as_ref(), &[tier_id]],
+        bump
+    )]
+    pub subscription_tier: Account<'info, SubscriptionTier>,
 
-    // The protocol's global configuration account
     #[account(seeds = [b"config"], bump)]
     pub protocol_config: Account<'info, ProtocolConfig>,
 
-    // The creator's account, used to verify the payment destination and price.
-    #[account(mut)]
+    // Counts the sale towards the creator's volume tier.
+    #[account(
+        mut,
+        seeds = [b"creator", subscription_tier.creator.as_ref()],
+        bump,
+        constraint = is_current_creator_layout(&creator_account) @ CustomError::InvalidLegacyCreator
+    )]
     pub creator_account: Account<'info, CreatorAccount>,
 
-    // The creator's wallet, derived from the creator_account.
-    // The `address` constraint is a key security feature: it ensures the client
-    // passes the correct wallet address that is stored in the creator_account.
     /// CHECK: This is the creator's wallet address, validated by the address constraint.
-    #[account(mut, address = creator_account.creator_wallet)]
+    #[account(mut, address = subscription_tier.creator)]
     pub creator_wallet: AccountInfo<'info>,
 
-    // The admin wallet that receives the platform fee.
-    // Its address is validated against the protocol_config.
-    /// CHECK: This is the admin wallet address, validated by the address constraint.
-    #[account(mut, address = protocol_config.admin_wallet)]
-    pub admin_wallet: AccountInfo<'info>,
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    // The creator's fee override. See `ProcessPayment`.
+    /// CHECK: Address checked by the seeds; decoded with `load_optional` when it is program-owned.
+    #[account(seeds = [b"fee_override", subscription_tier.creator.as_ref()], bump)]
+    pub fee_override: UncheckedAccount<'info>,
 
-    // The user who is paying.
     #[account(mut)]
-    pub buyer: Signer<'info>,
+    pub subscriber: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    // Only required when the tier is priced in a token. See `ProcessPayment`.
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub subscriber_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl<'info> RenewSubscription<'info> {
+    fn payment_accounts(&self) -> PaymentAccounts<'_, 'info> {
+        PaymentAccounts {
+            payer: &self.subscriber,
+            creator_wallet: &self.creator_wallet,
+            treasury: &self.treasury,
+            system_program: &self.system_program,
+            payment_mint: self.payment_mint.as_ref(),
+            payer_token_account: self.subscriber_token_account.as_ref(),
+            creator_token_account: self.creator_token_account.as_ref(),
+            treasury_token_account: self.treasury_token_account.as_ref(),
+            token_program: self.token_program.as_ref(),
+            memo_program: self.memo_program.as_ref(),
+        }
+    }
 }
 
 
@@ -410,4 +3905,924 @@ pub enum CustomError {
     InvalidUsername,
     #[msg("Invalid fee percentage. Must be <= 10000 (100%).")]
     InvalidFeePercentage,
-}
\ No newline at end of file
+    #[msg("The payment mint does not match the mint the content is priced in.")]
+    PaymentMintMismatch,
+    #[msg("Token-priced content requires the mint, token accounts and token program.")]
+    MissingTokenAccounts,
+    #[msg("A token account does not belong to the expected wallet.")]
+    InvalidTokenAccount,
+    #[msg("Arithmetic overflow while computing payment amounts.")]
+    MathOverflow,
+    #[msg("A receiving token account requires a memo; pass the memo program.")]
+    MemoProgramRequired,
+    #[msg("Subscription periods and period lengths must be greater than zero.")]
+    InvalidSubscriptionPeriod,
+    #[msg("A subscription tier can list at most 32 content IDs.")]
+    TooManyTierContentIds,
+    #[msg("An active subscription can only be renewed on its current tier.")]
+    SubscriptionTierMismatch,
+    #[msg("The content price is higher than the maximum price the buyer accepted.")]
+    PriceAboveMaximum,
+    #[msg("The platform fee is higher than the maximum fee the buyer accepted.")]
+    FeeAboveMaximum,
+    #[msg("The creator account is not in the legacy layout, or the content accounts don't match its content list.")]
+    InvalidLegacyCreator,
+    #[msg("Title is too long. Must be at most 128 bytes.")]
+    TitleTooLong,
+    #[msg("Encrypted CID is too long. Must be at most 128 bytes.")]
+    EncryptedCidTooLong,
+    #[msg("Profile CID is too long. Must be at most 100 bytes.")]
+    ProfileCidTooLong,
+    #[msg("This content has been unlisted by its creator and cannot be bought.")]
+    ContentUnlisted,
+    #[msg("The legacy receipt is missing or does not match the given buyer and creator.")]
+    InvalidLegacyReceipt,
+    #[msg("The config account is missing or not in the legacy layout.")]
+    InvalidLegacyConfig,
+    #[msg("There is no pending admin proposal.")]
+    NoPendingAdmin,
+    #[msg("The treasury does not hold enough to cover this withdrawal.")]
+    InsufficientTreasuryBalance,
+    #[msg("This instruction is paused by the protocol admin or guardian.")]
+    ProtocolPaused,
+    #[msg("Unknown pause flags. Only the PAUSE_* bits can be set.")]
+    InvalidPauseFlags,
+    #[msg("The fee is above the protocol's fee cap.")]
+    FeeAboveCap,
+    #[msg("The protocol config can hold at most 4 fee tiers.")]
+    TooManyFeeTiers,
+    #[msg("Fee tiers must be listed by strictly increasing minimum sales.")]
+    InvalidFeeTiers,
+    #[msg("Revenue split shares must be non-zero and sum to 10000 basis points.")]
+    InvalidRevenueSplit,
+    #[msg("A revenue split can have at most 8 recipients.")]
+    TooManySplitRecipients,
+    #[msg("The split recipient accounts are missing or do not match the stored revenue split.")]
+    SplitRecipientMismatch,
+    #[msg("Invalid affiliate share. Must be <= 10000 (100%).")]
+    InvalidAffiliateShare,
+    #[msg("The referrer's accounts are missing or not registered for the content's currency.")]
+    InvalidReferrer,
+    #[msg("A buyer cannot refer their own purchase.")]
+    SelfReferral,
+    #[msg("A bundle must list between 1 and 32 distinct content IDs of its creator.")]
+    InvalidBundle,
+    #[msg("Invalid discount. Must be <= 10000 (100%).")]
+    InvalidDiscount,
+    #[msg("Invalid coupon. Codes are 1 to 32 bytes, discounts and limits must be non-zero and expiry in the future.")]
+    InvalidCoupon,
+    #[msg("This coupon can't be used on this purchase.")]
+    CouponNotApplicable,
+    #[msg("This coupon has expired.")]
+    CouponExpired,
+    #[msg("This coupon has no redemptions left.")]
+    CouponLimitReached,
+    #[msg("The creator has no supporter stats for this currency.")]
+    SupporterStatsMissing,
+    #[msg("Supporter stats don't belong to this creator or currency.")]
+    InvalidSupporterStats,
+    #[msg("Tips must be greater than zero.")]
+    InvalidTipAmount,
+    #[msg("Tip message exceeds maximum length of 140 bytes.")]
+    TipMessageTooLong,
+    #[msg("This content isn't offered for rent.")]
+    RentalUnavailable,
+    #[msg("Invalid rental terms. The duration must be greater than zero.")]
+    InvalidRentalTerms,
+    #[msg("This receipt is for a purchase, not a rental.")]
+    NotARental,
+    #[msg("This content has already been bought.")]
+    AlreadyPurchased,
+    #[msg("This content is sold out.")]
+    SoldOut,
+    #[msg("Invalid max supply. It can only be lowered, and not below the copies already sold.")]
+    InvalidMaxSupply,
+    #[msg("Limited editions and unlisted content can't be sold in a bundle.")]
+    ContentNotBundleable,
+    #[msg("Limited editions can't be offered for rent.")]
+    LimitedEditionRental,
+}
+
+
+// 4. PAYMENT HELPERS
+// Shared plumbing for moving funds from a buyer, in lamports or SPL / Token-2022 tokens.
+
+// Memos attached to transfers into accounts that require incoming memos.
+const PAYMENT_MEMO: &[u8] = b"Auton payment";
+const TREASURY_MEMO: &[u8] = b"Auton treasury withdrawal";
+
+// How the buyer's funds are moved.
+pub enum PaymentRail<'info> {
+    // Native SOL, moved with the system program.
+    Sol {
+        payer: AccountInfo<'info>,
+        system_program: AccountInfo<'info>,
+    },
+    // A token, moved from the buyer's token account with `transfer_checked` through
+    // whichever token program owns the mint.
+    Token {
+        source: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        mint: AccountInfo<'info>,
+        decimals: u8,
+        transfer_fee_config: Option<TransferFeeConfig>,
+        token_program: AccountInfo<'info>,
+        memo_program: Option<AccountInfo<'info>>,
+    },
+}
+
+impl<'info> PaymentRail<'info> {
+    // Returns the amount to send so the destination is credited `net_amount` after the
+    // mint's transfer fee. Identity for SOL and for mints without a transfer fee.
+    pub fn gross_for_net(&self, net_amount: u64) -> Result<u64> {
+        let PaymentRail::Token { transfer_fee_config: Some(fee_config), .. } = self else {
+            return Ok(net_amount);
+        };
+        let epoch = Clock::get()?.epoch;
+        let transfer_fee = fee_config
+            .calculate_inverse_epoch_fee(epoch, net_amount)
+            .ok_or(CustomError::MathOverflow)?;
+        net_amount
+            .checked_add(transfer_fee)
+            .ok_or_else(|| error!(CustomError::MathOverflow))
+    }
+
+    // Sends `amount` (lamports or token base units) to `destination`. Zero amounts are skipped.
+    pub fn pay(&self, destination: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        match self {
+            PaymentRail::Sol { payer, system_program } => {
+                let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
+                    payer.key,
+                    destination.key,
+                    amount,
+                );
+                anchor_lang::solana_program::program::invoke(
+                    &transfer_ix,
+                    &[payer.clone(), destination.clone(), system_program.clone()],
+                )?;
+            }
+            PaymentRail::Token { source, authority, mint, decimals, token_program, memo_program, .. } => {
+                if requires_incoming_memo(destination)? {
+                    let memo_program = memo_program
+                        .as_ref()
+                        .ok_or(CustomError::MemoProgramRequired)?;
+                    memo::build_memo(CpiContext::new(memo_program.clone(), BuildMemo {}), PAYMENT_MEMO)?;
+                }
+
+                token_interface::transfer_checked(
+                    CpiContext::new(
+                        token_program.clone(),
+                        TransferChecked {
+                            from: source.clone(),
+                            mint: mint.clone(),
+                            to: destination.clone(),
+                            authority: authority.clone(),
+                        },
+                    ),
+                    amount,
+                    *decimals,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// A resolved payment: the rail the buyer pays with and the account receiving each share.
+pub struct PaymentRoute<'info> {
+    pub rail: PaymentRail<'info>,
+    pub fee_destination: AccountInfo<'info>,
+    pub payees: Vec<Payee<'info>>, // Where the creator's share goes: the creator alone, or a revenue split
+    pub referral: Option<Payee<'info>>, // A referrer's cut, taken from the creator's share before the payees
+}
+
+// An account receiving part of the creator's share.
+pub struct Payee<'info> {
+    pub destination: AccountInfo<'info>,
+    pub share_bps: u64, // Portion of the creator's share, in basis points
+}
+
+// What a settled payment moved, in lamports or token base units.
+pub struct Settlement {
+    pub price: u64,          // The price that was split
+    pub fee_amount: u64,     // Platform fee credited to the treasury
+    pub creator_amount: u64, // Share of the price owed to the creator, after any referral
+    pub referral_amount: u64, // Share of the price paid to the referrer
+    pub buyer_total: u64,    // What actually left the buyer, including mint transfer fees
+}
+
+impl<'info> PaymentRoute<'info> {
+    // Splits `price` into the platform fee and the creator's share and transfers both,
+    // paying the referral out of the creator's share and dividing the rest between the payees.
+    pub fn settle(
+        &self,
+        price: u64,
+        fee_bps: u64,
+        fee_rounding: FeeRounding,
+        transfer_fee_payer: TransferFeePayer,
+    ) -> Result<Settlement> {
+        let fee::FeeSplit { fee_amount, creator_amount } = fee::split_price(price, fee_bps, fee_rounding)?;
+        // The referrer's cut is rounded down, leaving any remainder with the creator.
+        let (referral_amount, creator_amount) = match &self.referral {
+            Some(referral) => {
+                let cut = fee::split_price(creator_amount, referral.share_bps, FeeRounding::Down)?;
+                (cut.fee_amount, cut.creator_amount)
+            }
+            None => (0, creator_amount),
+        };
+
+        // Token-2022 mints can withhold a transfer fee from every transfer. The treasury always
+        // receives the full platform fee; the policy decides whether the buyer tops up the
+        // creator's share or the creator's share absorbs the withheld amount.
+        let fee_transfer = self.rail.gross_for_net(fee_amount)?;
+        let referral_transfer = self.rail.gross_for_net(referral_amount)?;
+        let shares_bps: Vec<u64> = self.payees.iter().map(|payee| payee.share_bps).collect();
+        let creator_transfers = match transfer_fee_payer {
+            TransferFeePayer::Buyer => fee::split_shares(creator_amount, &shares_bps)?
+                .into_iter()
+                .map(|share| self.rail.gross_for_net(share))
+                .collect::<Result<Vec<_>>>()?,
+            TransferFeePayer::Creator => fee::split_shares(
+                price
+                    .checked_sub(fee_transfer)
+                    .and_then(|rest| rest.checked_sub(referral_transfer))
+                    .ok_or(CustomError::MathOverflow)?,
+                &shares_bps,
+            )?,
+        };
+
+        // 1. Transfer Platform Fee to the Treasury
+        self.rail.pay(&self.fee_destination, fee_transfer)?;
+
+        msg!("Collected {} in platform fees", fee_amount);
+
+        // 2. Pay the Referrer's Cut
+        if let Some(referral) = &self.referral {
+            self.rail.pay(&referral.destination, referral_transfer)?;
+            msg!("Paid {} to the referrer", referral_amount);
+        }
+
+        // 3. Transfer Remaining Amount to the Creator's Wallet, or to each split recipient
+        for (payee, amount) in self.payees.iter().zip(&creator_transfers) {
+            self.rail.pay(&payee.destination, *amount)?;
+        }
+
+        let buyer_total = std::iter::once(&referral_transfer)
+            .chain(&creator_transfers)
+            .try_fold(fee_transfer, |total, amount| total.checked_add(*amount))
+            .ok_or(CustomError::MathOverflow)?;
+        if buyer_total != price {
+            msg!("Mint transfer fees: buyer sent {} for a price of {}", buyer_total, price);
+        }
+
+        Ok(Settlement { price, fee_amount, creator_amount, referral_amount, buyer_total })
+    }
+}
+
+// The accounts any paid instruction needs to move funds, borrowed from its context.
+// The token accounts are only required when the price is in a token.
+pub struct PaymentAccounts<'a, 'info> {
+    pub payer: &'a Signer<'info>,
+    pub creator_wallet: &'a AccountInfo<'info>,
+    pub treasury: &'a Account<'info, Treasury>,
+    pub system_program: &'a Program<'info, System>,
+    pub payment_mint: Option<&'a InterfaceAccount<'info, Mint>>,
+    pub payer_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub creator_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub treasury_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<&'a Interface<'info, TokenInterface>>,
+    pub memo_program: Option<&'a Program<'info, Memo>>,
+}
+
+impl<'a, 'info> PaymentAccounts<'a, 'info> {
+    // Resolves how the payer pays and where the fee and creator share are sent.
+    // Prices without a payment mint are paid in lamports straight to the creator wallet and the treasury.
+    // Token prices must come with the listed mint and the associated token
+    // accounts of the creator wallet and the treasury under the mint's token program.
+    // With a revenue split, the creator's share goes to the split's recipients instead, whose
+    // wallets (or associated token accounts) must be given in the split's order.
+    pub fn route(
+        &self,
+        payment_mint: Option<Pubkey>,
+        revenue_split: Option<(&RevenueSplit, &[AccountInfo<'info>])>,
+    ) -> Result<PaymentRoute<'info>> {
+        let Some(listed_mint) = payment_mint else {
+            return Ok(PaymentRoute {
+                rail: PaymentRail::Sol {
+                    payer: self.payer.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                },
+                fee_destination: self.treasury.to_account_info(),
+                payees: payees(self.creator_wallet.clone(), revenue_split, |wallet| *wallet)?,
+                referral: None,
+            });
+        };
+
+        let (
+            Some(mint),
+            Some(payer_token_account),
+            Some(creator_token_account),
+            Some(treasury_token_account),
+            Some(token_program),
+        ) = (
+            self.payment_mint,
+            self.payer_token_account,
+            self.creator_token_account,
+            self.treasury_token_account,
+            self.token_program,
+        ) else {
+            return err!(CustomError::MissingTokenAccounts);
+        };
+
+        require_keys_eq!(mint.key(), listed_mint, CustomError::PaymentMintMismatch);
+        require_keys_eq!(*mint.to_account_info().owner, token_program.key(), CustomError::InvalidTokenAccount);
+        require_keys_eq!(payer_token_account.mint, listed_mint, CustomError::PaymentMintMismatch);
+        require_keys_eq!(payer_token_account.owner, self.payer.key(), CustomError::InvalidTokenAccount);
+        require_keys_eq!(
+            creator_token_account.key(),
+            get_associated_token_address_with_program_id(
+                self.creator_wallet.key,
+                &listed_mint,
+                &token_program.key(),
+            ),
+            CustomError::InvalidTokenAccount
+        );
+        require_keys_eq!(
+            treasury_token_account.key(),
+            get_associated_token_address_with_program_id(
+                &self.treasury.key(),
+                &listed_mint,
+                &token_program.key(),
+            ),
+            CustomError::InvalidTokenAccount
+        );
+
+        Ok(PaymentRoute {
+            rail: PaymentRail::Token {
+                source: payer_token_account.to_account_info(),
+                authority: self.payer.to_account_info(),
+                mint: mint.to_account_info(),
+                decimals: mint.decimals,
+                transfer_fee_config: read_transfer_fee_config(&mint.to_account_info())?,
+                token_program: token_program.to_account_info(),
+                memo_program: self.memo_program.map(|program| program.to_account_info()),
+            },
+            fee_destination: treasury_token_account.to_account_info(),
+            payees: payees(creator_token_account.to_account_info(), revenue_split, |wallet| {
+                get_associated_token_address_with_program_id(wallet, &listed_mint, &token_program.key())
+            })?,
+            referral: None,
+        })
+    }
+}
+
+// The creator's destination alone, or each split recipient's account checked against the
+// address `destination_for` derives from their wallet.
+fn payees<'info>(
+    creator_destination: AccountInfo<'info>,
+    revenue_split: Option<(&RevenueSplit, &[AccountInfo<'info>])>,
+    destination_for: impl Fn(&Pubkey) -> Pubkey,
+) -> Result<Vec<Payee<'info>>> {
+    let Some((split, recipient_accounts)) = revenue_split else {
+        return Ok(vec![Payee { destination: creator_destination, share_bps: fee::BPS_DENOMINATOR }]);
+    };
+    require!(
+        recipient_accounts.len() >= split.recipients.len(),
+        CustomError::SplitRecipientMismatch
+    );
+
+    split
+        .recipients
+        .iter()
+        .zip(recipient_accounts)
+        .map(|(recipient, account)| {
+            require_keys_eq!(*account.key, destination_for(&recipient.wallet), CustomError::SplitRecipientMismatch);
+            Ok(Payee { destination: account.clone(), share_bps: recipient.share_bps })
+        })
+        .collect()
+}
+
+// Reads a program account that may not have been created yet, at an address already
+// checked by the caller's seeds constraint. An empty, system-owned account reads as None.
+fn load_optional<T: AccountDeserialize>(info: &AccountInfo) -> Result<Option<T>> {
+    if *info.owner != crate::ID {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    T::try_deserialize(&mut &data[..]).map(Some)
+}
+
+// Creates a program-owned PDA of `space` bytes, paid for by `payer`. Lamports already sent to
+// the address are kept and only topped up to the rent-exempt minimum.
+fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent_exempt = Rent::get()?.minimum_balance(space);
+    if account.lamports() == 0 {
+        return anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::CreateAccount { from: payer.clone(), to: account.clone() },
+                &[signer_seeds],
+            ),
+            rent_exempt,
+            space as u64,
+            &crate::ID,
+        );
+    }
+    if account.lamports() < rent_exempt {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer { from: payer.clone(), to: account.clone() },
+            ),
+            rent_exempt - account.lamports(),
+        )?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate { account_to_allocate: account.clone() },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign { account_to_assign: account.clone() },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )
+}
+
+// The revenue split that applies to a content item and its address: the item's own split
+// if it has one, else the creator's default, else None.
+fn load_revenue_split(
+    content_split: &AccountInfo,
+    creator_split: &AccountInfo,
+) -> Result<Option<(Pubkey, RevenueSplit)>> {
+    if let Some(split) = load_optional::<RevenueSplit>(content_split)? {
+        return Ok(Some((content_split.key(), split)));
+    }
+    Ok(load_optional::<RevenueSplit>(creator_split)?.map(|split| (creator_split.key(), split)))
+}
+
+// Reads the transfer-fee extension from a mint. Classic SPL mints and Token-2022 mints
+// without the extension return None.
+// Resizes a program-owned account to `space` bytes, charging `payer` when it grows and
+// refunding the excess rent to `payer` when it shrinks.
+fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    let rent_exempt = Rent::get()?.minimum_balance(space);
+    if account.lamports() > rent_exempt {
+        let surplus = account.lamports() - rent_exempt;
+        **account.try_borrow_mut_lamports()? -= surplus;
+        **payer.try_borrow_mut_lamports()? += surplus;
+    } else if account.lamports() < rent_exempt {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer { from: payer.clone(), to: account.clone() },
+            ),
+            rent_exempt - account.lamports(),
+        )?;
+    }
+    account.resize(space)?;
+    Ok(())
+}
+
+// Whether a creator account is in the current layout. A legacy account, still holding its
+// content list, can decode as a `CreatorAccount` but is never sized exactly for one.
+fn is_current_creator_layout(creator_account: &Account<CreatorAccount>) -> bool {
+    creator_account.to_account_info().data_len() == CreatorAccount::space(creator_account.profile_cid.len())
+}
+
+fn read_transfer_fee_config(mint: &AccountInfo) -> Result<Option<TransferFeeConfig>> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<SplMint>::unpack(&mint_data)?;
+    Ok(mint_state.get_extension::<TransferFeeConfig>().ok().copied())
+}
+
+// Whether a Token-2022 account has the memo-required extension turned on.
+fn requires_incoming_memo(token_account: &AccountInfo) -> Result<bool> {
+    let account_data = token_account.try_borrow_data()?;
+    let account_state = StateWithExtensions::<SplTokenAccount>::unpack(&account_data)?;
+    Ok(account_state
+        .get_extension::<MemoTransfer>()
+        .map(|extension| bool::from(extension.require_incoming_transfer_memos))
+        .unwrap_or(false))
+}
+
+
+// 5. EVENTS
+// Emitted on every state change so indexers can rebuild history from the
+// transaction logs instead of scanning accounts. Timestamps are Unix seconds.
+
+#[event]
+pub struct ConfigInitialized {
+    pub admin_wallet: Pubkey,
+    pub fee_percentage: u64, // Basis points
+    pub fee_rounding: FeeRounding,
+    pub fee_recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+// Emitted when a legacy config is upgraded to the current layout.
+#[event]
+pub struct ConfigMigrated {
+    pub admin_wallet: Pubkey,
+    pub fee_percentage: u64, // Basis points, carried over from the legacy config
+    pub timestamp: i64,
+}
+
+// `fee_percentage` is the fee in force now; an increase shows up as `pending_fee_percentage`
+// until `fee_effective_at`.
+#[event]
+pub struct ConfigUpdated {
+    pub admin_wallet: Pubkey,
+    pub previous_fee_percentage: u64,
+    pub fee_percentage: u64,
+    pub fee_rounding: FeeRounding,
+    pub pending_fee_percentage: Option<u64>,
+    pub fee_effective_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaxFeePercentageUpdated {
+    pub authority: Pubkey, // Governance or the admin
+    pub previous_max_fee_percentage: u64,
+    pub max_fee_percentage: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceUpdated {
+    pub previous_governance: Pubkey,
+    pub governance: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin_wallet: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin_wallet: Pubkey,
+    pub admin_wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalCancelled {
+    pub admin_wallet: Pubkey,
+    pub cancelled_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeRecipientUpdated {
+    pub previous_fee_recipient: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub fee_recipient: Pubkey,
+    pub payment_mint: Option<Pubkey>, // None = SOL
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+// `paused` is the full mask after the change; compare with `previous_paused` for what flipped.
+#[event]
+pub struct PauseUpdated {
+    pub authority: Pubkey, // The admin or guardian that signed
+    pub previous_paused: u8,
+    pub paused: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianUpdated {
+    pub previous_guardian: Pubkey,
+    pub guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeTiersUpdated {
+    pub fee_tiers: Vec<FeeTier>,
+    pub timestamp: i64,
+}
+
+// Emitted when a fee override is created or updated.
+#[event]
+pub struct FeeOverrideSet {
+    pub creator: Pubkey,
+    pub fee_bps: u64,
+    pub expires_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeOverrideRemoved {
+    pub creator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UsernameRegistered {
+    pub authority: Pubkey,
+    pub username: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorInitialized {
+    pub creator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProfileUpdated {
+    pub creator: Pubkey,
+    pub profile_cid: String,
+    pub timestamp: i64,
+}
+
+// Emitted for each batch of a legacy creator's content moved into content item accounts.
+// The creator account is in the current layout once `remaining` reaches zero.
+#[event]
+pub struct CreatorContentMigrated {
+    pub creator: Pubkey,
+    pub migrated: u64, // Items moved in this batch
+    pub remaining: u64, // Items still in the legacy list
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentAdded {
+    pub creator: Pubkey,
+    pub content_id: u64,
+    pub title: String,
+    pub price: u64,
+    pub payment_mint: Option<Pubkey>,
+    pub transfer_fee_payer: TransferFeePayer,
+    pub timestamp: i64,
+}
+
+// Carries the item's title and price after the edit, whether or not they changed.
+#[event]
+pub struct ContentUpdated {
+    pub creator: Pubkey,
+    pub content_id: u64,
+    pub title: String,
+    pub price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentListingChanged {
+    pub creator: Pubkey,
+    pub content_id: u64,
+    pub listed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PayWhatYouWantChanged {
+    pub creator: Pubkey,
+    pub content_id: u64,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaxSupplySet {
+    pub creator: Pubkey,
+    pub content_id: u64,
+    pub max_supply: u64,
+    pub sold: u64, // Copies already bought when the cap was set
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RentalTermsChanged {
+    pub creator: Pubkey,
+    pub content_id: u64,
+    pub rental: Option<RentalTerms>, // None = no longer for rent
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentPurchased {
+    pub buyer: Pubkey,
+    pub creator: Pubkey,
+    pub content_id: u64,
+    pub payment_mint: Option<Pubkey>, // None = SOL
+    pub price: u64,
+    pub fee_bps: u64, // Platform fee rate applied to this purchase
+    pub fee_amount: u64,
+    pub creator_amount: u64,
+    pub buyer_total: u64, // What left the buyer, including mint transfer fees
+    pub revenue_split: Option<Pubkey>, // The split the creator's share was divided by, if any
+    pub referrer: Option<Pubkey>,
+    pub referral_amount: u64, // Paid to the referrer out of the creator's share
+    pub coupon: Option<Pubkey>,
+    pub discount_amount: u64, // Taken off the listed price by the coupon
+    pub edition: u64, // The copy's number in purchase order
+    pub timestamp: i64,
+}
+
+// Emitted for a new rental and for each renewal.
+#[event]
+pub struct ContentRented {
+    pub buyer: Pubkey,
+    pub creator: Pubkey,
+    pub content_id: u64,
+    pub payment_mint: Option<Pubkey>, // None = SOL
+    pub price: u64,
+    pub fee_bps: u64, // Platform fee rate applied to this rental
+    pub fee_amount: u64,
+    pub creator_amount: u64,
+    pub buyer_total: u64, // What left the buyer, including mint transfer fees
+    pub revenue_split: Option<Pubkey>, // The split the creator's share was divided by, if any
+    pub expires_at: i64, // When the rental now ends
+    pub renewal: bool,
+    pub timestamp: i64,
+}
+
+// Emitted when a revenue split is created or updated.
+#[event]
+pub struct RevenueSplitSet {
+    pub creator: Pubkey,
+    pub content_id: u64, // `CREATOR_DEFAULT_SPLIT` for the creator's default
+    pub recipients: Vec<SplitRecipient>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RevenueSplitRemoved {
+    pub creator: Pubkey,
+    pub content_id: u64,
+    pub timestamp: i64,
+}
+
+// Emitted when a creator changes their affiliate share or a content item's own share.
+#[event]
+pub struct AffiliateShareUpdated {
+    pub creator: Pubkey,
+    pub content_id: Option<u64>, // None for the creator-wide share
+    pub affiliate_bps: Option<u64>, // None when a content item's own share is cleared
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferrerRegistered {
+    pub referrer: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReceiptMigrated {
+    pub buyer: Pubkey,
+    pub creator: Pubkey,
+    pub content_id: u64,
+    pub created_at: i64, // Original purchase time carried over from the legacy receipt
+    pub timestamp: i64,
+}
+
+// Emitted when a bundle is created or updated.
+#[event]
+pub struct BundleSet {
+    pub creator: Pubkey,
+    pub bundle_id: u8,
+    pub content_ids: Vec<u64>,
+    pub price: u64,
+    pub discount_bps: Option<u64>,
+    pub payment_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BundleRemoved {
+    pub creator: Pubkey,
+    pub bundle_id: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BundlePurchased {
+    pub buyer: Pubkey,
+    pub creator: Pubkey,
+    pub bundle_id: u8,
+    pub content_ids: Vec<u64>,
+    pub payment_mint: Option<Pubkey>,
+    pub price: u64, // Price paid after any discount
+    pub fee_bps: u64,
+    pub fee_amount: u64,
+    pub creator_amount: u64,
+    pub buyer_total: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SupporterStatsCreated {
+    pub creator: Pubkey,
+    pub payment_mint: Option<Pubkey>, // None = SOL
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorTipped {
+    pub tipper: Pubkey,
+    pub creator: Pubkey,
+    pub payment_mint: Option<Pubkey>, // None = SOL
+    pub amount: u64,
+    pub fee_bps: u64,
+    pub fee_amount: u64,
+    pub creator_amount: u64,
+    pub message: Option<String>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouponCreated {
+    pub creator: Pubkey,
+    pub coupon: Pubkey,
+    pub code: String,
+    pub discount: CouponDiscount,
+    pub payment_mint: Option<Pubkey>,
+    pub max_redemptions: Option<u64>,
+    pub max_per_buyer: Option<u64>,
+    pub expires_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouponRemoved {
+    pub creator: Pubkey,
+    pub code: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionTierCreated {
+    pub creator: Pubkey,
+    pub tier_id: u8,
+    pub price: u64,
+    pub period_seconds: i64,
+    pub payment_mint: Option<Pubkey>,
+    pub content_ids: Vec<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionTierUpdated {
+    pub creator: Pubkey,
+    pub tier_id: u8,
+    pub price: u64,
+    pub period_seconds: i64,
+    pub content_ids: Vec<u64>,
+    pub timestamp: i64,
+}
+
+// Emitted by both `subscribe` and `renew_subscription`.
+#[event]
+pub struct SubscriptionPaid {
+    pub subscriber: Pubkey,
+    pub creator: Pubkey,
+    pub tier_id: u8,
+    pub periods: u32,
+    pub payment_mint: Option<Pubkey>,
+    pub price: u64, // Total for all periods paid
+    pub fee_bps: u64,
+    pub fee_amount: u64,
+    pub creator_amount: u64,
+    pub buyer_total: u64,
+    pub started_at: i64,
+    pub expires_at: i64,
+    pub renewal: bool,
+    pub timestamp: i64,
+}
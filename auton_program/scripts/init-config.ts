@@ -17,6 +17,12 @@ async function main() {
 
   console.log("Protocol Config PDA:", configPDA.toBase58());
 
+  // The treasury PDA collects platform fees and is created alongside the config
+  const [treasuryPDA] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    program.programId
+  );
+
   // 2. Define Initial Settings
   const INITIAL_FEE_BPS = new anchor.BN(500); // 5%
   // Admin will be the wallet currently configured in Anchor.toml / env
@@ -36,11 +42,30 @@ async function main() {
       return;
     }
 
+    // A config left by an older program version only holds the admin and fee (48 bytes),
+    // which the current layout can't decode; upgrade it in place instead
+    const rawConfig = await provider.connection.getAccountInfo(configPDA);
+    if (rawConfig && rawConfig.data.length === 48) {
+      console.log("Found a legacy config, migrating it...");
+      const tx = await program.methods
+        .migrateConfig()
+        .accounts({
+          protocolConfig: configPDA,
+          treasury: treasuryPDA,
+          admin: admin.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .rpc();
+      console.log("Success! Transaction signature:", tx);
+      return;
+    }
+
     // 4. Call initialize_config
     const tx = await program.methods
       .initializeConfig(INITIAL_FEE_BPS)
       .accounts({
         protocolConfig: configPDA,
+        treasury: treasuryPDA,
         admin: admin.publicKey,
         systemProgram: web3.SystemProgram.programId,
       })
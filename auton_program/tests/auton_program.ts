@@ -16,7 +16,7 @@ describe("auton_program", () => {
   const creator1 = web3.Keypair.generate();
   const creator2 = web3.Keypair.generate();
   const creator3 = web3.Keypair.generate();
-  const admin = web3.Keypair.generate(); // Admin wallet for fees
+  const admin = web3.Keypair.generate(); // Admin wallet, also the initial fee recipient
   const allCreators = [creator1, creator2, creator3];
 
   // Protocol Config PDA
@@ -25,6 +25,12 @@ describe("auton_program", () => {
     program.programId
   );
 
+  // Protocol Treasury PDA, which collects the platform fee
+  const [treasuryPDA] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    program.programId
+  );
+
   // Fee settings (5%)
   const FEE_BPS = new anchor.BN(500);
 
@@ -47,6 +53,145 @@ describe("auton_program", () => {
     return pda;
   };
 
+  // Helper to get a creator's fee override PDA (only read by the program if the admin created it)
+  const getFeeOverridePDA = (creatorWallet: web3.PublicKey) => {
+    const [pda, _] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_override"), creatorWallet.toBuffer()],
+      program.programId
+    );
+    return pda;
+  };
+
+  // Helper to get a revenue split PDA; content ID 0 is the creator's default split
+  const CREATOR_DEFAULT_SPLIT = new anchor.BN(0);
+  const getSplitPDA = (creatorWallet: web3.PublicKey, contentId: anchor.BN) => {
+    const [pda, _] = web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("split"),
+        creatorWallet.toBuffer(),
+        contentId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    return pda;
+  };
+
+  // Helper to get a creator's supporter stats PDA for SOL, or for a token mint (only read by
+  // purchases of pay-what-you-want content)
+  const getSupporterStatsPDA = (creatorWallet: web3.PublicKey, mint = web3.PublicKey.default) => {
+    const [pda, _] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("supporters"), creatorWallet.toBuffer(), mint.toBuffer()],
+      program.programId
+    );
+    return pda;
+  };
+
+  // Helper to get the PDA of a creator's content item
+  const getContentPDA = (creatorWallet: web3.PublicKey, contentId: anchor.BN) => {
+    const [pda, _] = web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("content"),
+        creatorWallet.toBuffer(),
+        contentId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    return pda;
+  };
+
+  // Helper to get a buyer's receipt PDA for a creator's content item
+  const getReceiptPDA = (
+    buyerWallet: web3.PublicKey,
+    creatorWallet: web3.PublicKey,
+    contentId: anchor.BN
+  ) => {
+    const [pda, _] = web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("access"),
+        buyerWallet.toBuffer(),
+        creatorWallet.toBuffer(),
+        contentId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    return pda;
+  };
+
+  // Minimal SPL Token helpers, built by hand so the tests only depend on web3.js
+  const TOKEN_PROGRAM_ID = new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+  const ASSOCIATED_TOKEN_PROGRAM_ID = new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+  const MINT_SIZE = 82;
+
+  // Associated token account address; the owner may be a PDA such as the treasury
+  const getAta = (owner: web3.PublicKey, mint: web3.PublicKey) =>
+    web3.PublicKey.findProgramAddressSync(
+      [owner.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    )[0];
+
+  // Creates a mint with `authority` as mint authority and no freeze authority
+  const createMint = async (authority: web3.Keypair, decimals: number) => {
+    const mint = web3.Keypair.generate();
+    const initializeMint2 = new web3.TransactionInstruction({
+      programId: TOKEN_PROGRAM_ID,
+      keys: [{ pubkey: mint.publicKey, isWritable: true, isSigner: false }],
+      data: Buffer.concat([Buffer.from([20, decimals]), authority.publicKey.toBuffer(), Buffer.from([0])]),
+    });
+    const tx = new web3.Transaction().add(
+      web3.SystemProgram.createAccount({
+        fromPubkey: authority.publicKey,
+        newAccountPubkey: mint.publicKey,
+        space: MINT_SIZE,
+        lamports: await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE),
+        programId: TOKEN_PROGRAM_ID,
+      }),
+      initializeMint2
+    );
+    await web3.sendAndConfirmTransaction(provider.connection, tx, [authority, mint]);
+    return mint.publicKey;
+  };
+
+  // Creates `owner`'s associated token account for `mint` (if missing) and mints `amount` into it
+  const createAtaAndMint = async (
+    payer: web3.Keypair,
+    owner: web3.PublicKey,
+    mint: web3.PublicKey,
+    amount: anchor.BN
+  ) => {
+    const ata = getAta(owner, mint);
+    const createIdempotent = new web3.TransactionInstruction({
+      programId: ASSOCIATED_TOKEN_PROGRAM_ID,
+      keys: [
+        { pubkey: payer.publicKey, isWritable: true, isSigner: true },
+        { pubkey: ata, isWritable: true, isSigner: false },
+        { pubkey: owner, isWritable: false, isSigner: false },
+        { pubkey: mint, isWritable: false, isSigner: false },
+        { pubkey: web3.SystemProgram.programId, isWritable: false, isSigner: false },
+        { pubkey: TOKEN_PROGRAM_ID, isWritable: false, isSigner: false },
+      ],
+      data: Buffer.from([1]),
+    });
+    const tx = new web3.Transaction().add(createIdempotent);
+    if (!amount.isZero()) {
+      tx.add(
+        new web3.TransactionInstruction({
+          programId: TOKEN_PROGRAM_ID,
+          keys: [
+            { pubkey: mint, isWritable: true, isSigner: false },
+            { pubkey: ata, isWritable: true, isSigner: false },
+            { pubkey: payer.publicKey, isWritable: false, isSigner: true },
+          ],
+          data: Buffer.concat([Buffer.from([7]), amount.toArrayLike(Buffer, "le", 8)]),
+        })
+      );
+    }
+    await web3.sendAndConfirmTransaction(provider.connection, tx, [payer]);
+    return ata;
+  };
+
+  const tokenBalance = async (tokenAccount: web3.PublicKey) =>
+    new anchor.BN((await provider.connection.getTokenAccountBalance(tokenAccount)).value.amount);
+
   before("Fund all test wallets and Initialize Config", async () => {
     // Airdrop SOL to the buyer, admin, and all creators
     const walletsToFund = [buyer, admin, ...allCreators];
@@ -63,6 +208,7 @@ describe("auton_program", () => {
         .initializeConfig(FEE_BPS)
         .accounts({
             protocolConfig: configPDA,
+            treasury: treasuryPDA,
             admin: admin.publicKey,
             systemProgram: web3.SystemProgram.programId,
         })
@@ -188,7 +334,6 @@ describe("auton_program", () => {
         const accountData = await program.account.creatorAccount.fetch(creatorPDA);
         assert.ok(accountData.creatorWallet.equals(creator.publicKey));
         assert.equal(accountData.lastContentId.toNumber(), 0);
-        assert.isEmpty(accountData.content);
       }
     });
 
@@ -196,42 +341,100 @@ describe("auton_program", () => {
       // Creator 1 adds 2 items
       const creator1PDA = getCreatorPDA(creator1.publicKey);
       await program.methods
-        .addContent("Creator 1, Content 1", new anchor.BN(1 * web3.LAMPORTS_PER_SOL), encryptCID("cid1_1"))
-        .accounts({ creatorAccount: creator1PDA, creator: creator1.publicKey })
+        .addContent("Creator 1, Content 1", new anchor.BN(1 * web3.LAMPORTS_PER_SOL), encryptCID("cid1_1"), { buyer: {} })
+        .accounts({
+          creatorAccount: creator1PDA,
+          contentItem: getContentPDA(creator1.publicKey, new anchor.BN(1)),
+          creator: creator1.publicKey,
+        })
         .signers([creator1])
         .rpc();
       await program.methods
-        .addContent("Creator 1, Content 2", new anchor.BN(2 * web3.LAMPORTS_PER_SOL), encryptCID("cid1_2"))
-        .accounts({ creatorAccount: creator1PDA, creator: creator1.publicKey })
+        .addContent("Creator 1, Content 2", new anchor.BN(2 * web3.LAMPORTS_PER_SOL), encryptCID("cid1_2"), { buyer: {} })
+        .accounts({
+          creatorAccount: creator1PDA,
+          contentItem: getContentPDA(creator1.publicKey, new anchor.BN(2)),
+          creator: creator1.publicKey,
+        })
         .signers([creator1])
         .rpc();
       
       const creator1Data = await program.account.creatorAccount.fetch(creator1PDA);
-      assert.equal(creator1Data.content.length, 2);
       assert.equal(creator1Data.lastContentId.toNumber(), 2);
-      assert.equal(creator1Data.content[1].id.toNumber(), 2);
+      const creator1Content2 = await program.account.contentItem.fetch(
+        getContentPDA(creator1.publicKey, new anchor.BN(2))
+      );
+      assert.equal(creator1Content2.id.toNumber(), 2);
+      assert.ok(creator1Content2.creator.equals(creator1.publicKey));
 
       // Creator 2 adds 1 item
       const creator2PDA = getCreatorPDA(creator2.publicKey);
       await program.methods
-        .addContent("Creator 2, Content 1", new anchor.BN(0.5 * web3.LAMPORTS_PER_SOL), encryptCID("cid2_1"))
-        .accounts({ creatorAccount: creator2PDA, creator: creator2.publicKey })
+        .addContent("Creator 2, Content 1", new anchor.BN(0.5 * web3.LAMPORTS_PER_SOL), encryptCID("cid2_1"), { buyer: {} })
+        .accounts({
+          creatorAccount: creator2PDA,
+          contentItem: getContentPDA(creator2.publicKey, new anchor.BN(1)),
+          creator: creator2.publicKey,
+        })
         .signers([creator2])
         .rpc();
       
       const creator2Data = await program.account.creatorAccount.fetch(creator2PDA);
-      assert.equal(creator2Data.content.length, 1);
       assert.equal(creator2Data.lastContentId.toNumber(), 1);
     });
 
     it("Can look up a specific creator's content list", async () => {
-      const creator1PDA = getCreatorPDA(creator1.publicKey);
-      const accountData = await program.account.creatorAccount.fetch(creator1PDA);
+      // Content items are separate accounts; filter them by the creator field (after the discriminator)
+      const content = await program.account.contentItem.all([
+        { memcmp: { offset: 8, bytes: creator1.publicKey.toBase58() } },
+      ]);
+      content.sort((a, b) => a.account.id.cmp(b.account.id));
+
+      assert.equal(content.length, 2, "Expected 2 content items for creator 1");
+      assert.equal(content[0].account.title, "Creator 1, Content 1");
+      assert.equal(content[1].account.id.toNumber(), 2);
+      assert.equal(content[1].account.price.toNumber(), 2 * web3.LAMPORTS_PER_SOL);
+    });
+
+    it("Fails when a content title is too long", async () => {
+      const creator3PDA = getCreatorPDA(creator3.publicKey);
+      try {
+        await program.methods
+          .addContent("t".repeat(129), new anchor.BN(web3.LAMPORTS_PER_SOL), encryptCID("cid3_1"), { buyer: {} })
+          .accounts({
+            creatorAccount: creator3PDA,
+            contentItem: getContentPDA(creator3.publicKey, new anchor.BN(1)),
+            creator: creator3.publicKey,
+          })
+          .signers([creator3])
+          .rpc();
+        assert.fail("Should have failed with TitleTooLong");
+      } catch (err) {
+        assert.isTrue(err instanceof anchor.AnchorError);
+        const anchorError = err as anchor.AnchorError;
+        assert.equal(anchorError.error.errorCode.code, "TitleTooLong");
+      }
+    });
+
+    it("Resizes the creator account exactly when the profile CID changes", async () => {
+      const creator3PDA = getCreatorPDA(creator3.publicKey);
+      const longCid = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+      const shortCid = "QmShort";
+
+      for (const profileCid of [longCid, shortCid]) {
+        await program.methods
+          .updateProfile(profileCid)
+          .accounts({ creatorAccount: creator3PDA, creator: creator3.publicKey })
+          .signers([creator3])
+          .rpc();
 
-      assert.equal(accountData.content.length, 2, "Expected 2 content items for creator 1");
-      assert.equal(accountData.content[0].title, "Creator 1, Content 1");
-      assert.equal(accountData.content[1].id.toNumber(), 2);
-      assert.equal(accountData.content[1].price.toNumber(), 2 * web3.LAMPORTS_PER_SOL);
+        const accountInfo = await provider.connection.getAccountInfo(creator3PDA);
+        // discriminator + wallet + counter + string prefix + profile CID
+        assert.equal(accountInfo.data.length, 8 + 32 + 8 + 4 + profileCid.length);
+        // Shrinking refunds the excess, so the account holds exactly the rent-exempt minimum
+        const rentExempt = await provider.connection.getMinimumBalanceForRentExemption(accountInfo.data.length);
+        assert.equal(accountInfo.lamports, rentExempt);
+      }
     });
 
     it("Can look up all available creators from the chain", async () => {
@@ -254,33 +457,37 @@ describe("auton_program", () => {
     it("Lets a buyer purchase content from a creator, splits fee correctly", async () => {
       const creatorPDA = getCreatorPDA(creator1.publicKey);
       
-      const [receiptPDA, _] = web3.PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("access"),
-          buyer.publicKey.toBuffer(),
-          contentIdToBuy.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
+      const receiptPDA = getReceiptPDA(buyer.publicKey, creator1.publicKey, contentIdToBuy);
 
       const creatorBalanceBefore = await provider.connection.getBalance(creator1.publicKey);
-      const adminBalanceBefore = await provider.connection.getBalance(admin.publicKey);
+      const treasuryBalanceBefore = await provider.connection.getBalance(treasuryPDA);
 
-      const creatorAccountData = await program.account.creatorAccount.fetch(creatorPDA);
-      const contentPrice = creatorAccountData.content.find(c => c.id.eq(contentIdToBuy)).price;
+      const contentPDA = getContentPDA(creator1.publicKey, contentIdToBuy);
+      const contentPrice = (await program.account.contentItem.fetch(contentPDA)).price;
 
       // Calculate expected amounts
       const feeAmount = contentPrice.mul(FEE_BPS).div(new anchor.BN(10000));
       const creatorAmount = contentPrice.sub(feeAmount);
 
+      // Capture the purchase event that indexers follow
+      let purchaseEvent = null;
+      const listener = program.addEventListener("contentPurchased", (event) => {
+        purchaseEvent = event;
+      });
+
       await program.methods
-        .processPayment(contentIdToBuy)
+        .processPayment(contentIdToBuy, contentPrice, FEE_BPS)
         .accounts({
           paidAccessAccount: receiptPDA,
           protocolConfig: configPDA,
           creatorAccount: creatorPDA,
+          contentItem: contentPDA,
           creatorWallet: creator1.publicKey,
-          adminWallet: admin.publicKey,
+          treasury: treasuryPDA,
+          feeOverride: getFeeOverridePDA(creator1.publicKey),
+          contentSplit: getSplitPDA(creator1.publicKey, contentIdToBuy),
+          creatorSplit: getSplitPDA(creator1.publicKey, CREATOR_DEFAULT_SPLIT),
+          supporterStats: getSupporterStatsPDA(creator1.publicKey),
           buyer: buyer.publicKey,
           systemProgram: web3.SystemProgram.programId,
         })
@@ -291,8 +498,24 @@ describe("auton_program", () => {
       const receiptData = await program.account.paidAccessAccount.fetch(receiptPDA);
       assert.ok(receiptData.buyer.equals(buyer.publicKey));
       assert.ok(receiptData.contentId.eq(contentIdToBuy));
+      assert.ok(receiptData.price.eq(contentPrice));
+      assert.ok(receiptData.feeAmount.eq(feeAmount));
+      assert.ok(receiptData.creatorAmount.eq(creatorAmount));
+      assert.isNull(receiptData.paymentMint);
+
+      // 2. Verify the emitted event carries the same breakdown
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+      assert.isNotNull(purchaseEvent, "ContentPurchased event was not emitted");
+      assert.ok(purchaseEvent.buyer.equals(buyer.publicKey));
+      assert.ok(purchaseEvent.creator.equals(creator1.publicKey));
+      assert.ok(purchaseEvent.contentId.eq(contentIdToBuy));
+      assert.ok(purchaseEvent.feeBps.eq(FEE_BPS));
+      assert.ok(purchaseEvent.feeAmount.eq(feeAmount));
+      assert.ok(purchaseEvent.creatorAmount.eq(creatorAmount));
+      assert.ok(purchaseEvent.timestamp.eq(receiptData.createdAt));
 
-      // 2. Verify Creator Payment
+      // 3. Verify Creator Payment
       const creatorBalanceAfter = await provider.connection.getBalance(creator1.publicKey);
       assert.equal(
         creatorBalanceAfter,
@@ -300,12 +523,12 @@ describe("auton_program", () => {
         "Creator did not receive the correct payment (should be price - fee)"
       );
 
-      // 3. Verify Admin Fee
-      const adminBalanceAfter = await provider.connection.getBalance(admin.publicKey);
+      // 4. Verify Treasury Fee
+      const treasuryBalanceAfter = await provider.connection.getBalance(treasuryPDA);
       assert.equal(
-        adminBalanceAfter,
-        adminBalanceBefore + feeAmount.toNumber(),
-        "Admin did not receive the correct platform fee"
+        treasuryBalanceAfter,
+        treasuryBalanceBefore + feeAmount.toNumber(),
+        "Treasury did not receive the correct platform fee"
       );
     });
 
@@ -314,14 +537,7 @@ describe("auton_program", () => {
 
       // 1. Frontend derives the receipt PDA address
       const contentId = new anchor.BN(2);
-      const [receiptPDA, _] = web3.PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("access"),
-          buyer.publicKey.toBuffer(),
-          contentId.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
+      const receiptPDA = getReceiptPDA(buyer.publicKey, creator1.publicKey, contentId);
       console.log(`   - Looking for receipt at address: ${receiptPDA.toBase58()}`);
 
       // 2. Frontend fetches the receipt account
@@ -331,11 +547,10 @@ describe("auton_program", () => {
       console.log("   - ✓ Receipt found! Access granted.");
 
       // 3. Now that access is verified, frontend retrieves the encrypted CID
-      const creatorPDA = getCreatorPDA(creator1.publicKey);
-      const creatorAccountData = await program.account.creatorAccount.fetch(creatorPDA);
-      
-      const contentItem = creatorAccountData.content.find(c => c.id.eq(contentId));
-      assert.isDefined(contentItem, "Content item not found in creator's account");
+      const contentItem = await program.account.contentItem.fetch(
+        getContentPDA(creator1.publicKey, contentId)
+      );
+      assert.isDefined(contentItem, "Content item not found for creator");
       console.log("   - Retrieved encrypted CID. Ready to decrypt and fetch from IPFS.");
 
       // For the test, we'll verify it's the correct one we added earlier
@@ -346,46 +561,348 @@ describe("auton_program", () => {
       assert.isNotEmpty(contentItem.encryptedCid);
     });
 
+    it("Lets a buyer purchase the same content ID from different creators", async () => {
+      // Creator 1 and creator 2 both have a content item #1
+      const sameContentId = new anchor.BN(1);
+
+      for (const creator of [creator1, creator2]) {
+        const receiptPDA = getReceiptPDA(buyer.publicKey, creator.publicKey, sameContentId);
+        await program.methods
+          .processPayment(sameContentId, new anchor.BN(web3.LAMPORTS_PER_SOL), null)
+          .accounts({
+            paidAccessAccount: receiptPDA,
+            protocolConfig: configPDA,
+            creatorAccount: getCreatorPDA(creator.publicKey),
+            contentItem: getContentPDA(creator.publicKey, sameContentId),
+            creatorWallet: creator.publicKey,
+            treasury: treasuryPDA,
+            feeOverride: getFeeOverridePDA(creator.publicKey),
+            contentSplit: getSplitPDA(creator.publicKey, sameContentId),
+            creatorSplit: getSplitPDA(creator.publicKey, CREATOR_DEFAULT_SPLIT),
+            supporterStats: getSupporterStatsPDA(creator.publicKey),
+            buyer: buyer.publicKey,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+
+        const receiptData = await program.account.paidAccessAccount.fetch(receiptPDA);
+        assert.ok(receiptData.creator.equals(creator.publicKey));
+        assert.ok(receiptData.contentId.eq(sameContentId));
+      }
+    });
+
+    it("Fails when the price is above the buyer's maximum", async () => {
+      // Creator 2's item #1 costs 0.5 SOL
+      const contentId = new anchor.BN(1);
+      // The admin wallet hasn't bought this item yet, so it can act as the buyer here
+      const receiptPDA = getReceiptPDA(admin.publicKey, creator2.publicKey, contentId);
+
+      try {
+        await program.methods
+          .processPayment(contentId, new anchor.BN(0.4 * web3.LAMPORTS_PER_SOL), null)
+          .accounts({
+            paidAccessAccount: receiptPDA,
+            protocolConfig: configPDA,
+            creatorAccount: getCreatorPDA(creator2.publicKey),
+            contentItem: getContentPDA(creator2.publicKey, contentId),
+            creatorWallet: creator2.publicKey,
+            treasury: treasuryPDA,
+            feeOverride: getFeeOverridePDA(creator2.publicKey),
+            contentSplit: getSplitPDA(creator2.publicKey, contentId),
+            creatorSplit: getSplitPDA(creator2.publicKey, CREATOR_DEFAULT_SPLIT),
+            supporterStats: getSupporterStatsPDA(creator2.publicKey),
+            buyer: admin.publicKey,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+        assert.fail("Should have failed with PriceAboveMaximum");
+      } catch (err) {
+        assert.isTrue(err instanceof anchor.AnchorError);
+        const anchorError = err as anchor.AnchorError;
+        assert.equal(anchorError.error.errorCode.code, "PriceAboveMaximum");
+      }
+    });
+
+    it("Fails when the platform fee is above the buyer's maximum", async () => {
+      const contentId = new anchor.BN(1);
+
+      try {
+        await program.methods
+          .processPayment(contentId, new anchor.BN(web3.LAMPORTS_PER_SOL), new anchor.BN(100))
+          .accounts({
+            paidAccessAccount: getReceiptPDA(admin.publicKey, creator2.publicKey, contentId),
+            protocolConfig: configPDA,
+            creatorAccount: getCreatorPDA(creator2.publicKey),
+            contentItem: getContentPDA(creator2.publicKey, contentId),
+            creatorWallet: creator2.publicKey,
+            treasury: treasuryPDA,
+            feeOverride: getFeeOverridePDA(creator2.publicKey),
+            contentSplit: getSplitPDA(creator2.publicKey, contentId),
+            creatorSplit: getSplitPDA(creator2.publicKey, CREATOR_DEFAULT_SPLIT),
+            supporterStats: getSupporterStatsPDA(creator2.publicKey),
+            buyer: admin.publicKey,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+        assert.fail("Should have failed with FeeAboveMaximum");
+      } catch (err) {
+        assert.isTrue(err instanceof anchor.AnchorError);
+        const anchorError = err as anchor.AnchorError;
+        assert.equal(anchorError.error.errorCode.code, "FeeAboveMaximum");
+      }
+    });
+
     it("Fails when trying to purchase non-existent content", async () => {
       const nonExistentContentId = new anchor.BN(99);
       const creatorPDA = getCreatorPDA(creator1.publicKey);
 
       // We must derive the correct PDA, even for a failing transaction,
       // so that the instruction's account validation passes.
-      const [receiptPDA, _] = web3.PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("access"),
-          buyer.publicKey.toBuffer(),
-          nonExistentContentId.toArrayLike(Buffer, "le", 8),
-        ],
-        program.programId
-      );
+      const receiptPDA = getReceiptPDA(buyer.publicKey, creator1.publicKey, nonExistentContentId);
 
       try {
         await program.methods
-          .processPayment(nonExistentContentId)
+          .processPayment(nonExistentContentId, new anchor.BN(web3.LAMPORTS_PER_SOL), null)
           .accounts({
             paidAccessAccount: receiptPDA,
             protocolConfig: configPDA, // Added
             creatorAccount: creatorPDA,
+            contentItem: getContentPDA(creator1.publicKey, nonExistentContentId),
             creatorWallet: creator1.publicKey,
-            adminWallet: admin.publicKey, // Added
+            treasury: treasuryPDA,
+            feeOverride: getFeeOverridePDA(creator1.publicKey),
+            contentSplit: getSplitPDA(creator1.publicKey, nonExistentContentId),
+            creatorSplit: getSplitPDA(creator1.publicKey, CREATOR_DEFAULT_SPLIT),
+            supporterStats: getSupporterStatsPDA(creator1.publicKey),
             buyer: buyer.publicKey,
             systemProgram: web3.SystemProgram.programId,
           })
           .signers([buyer])
           .rpc();
-        assert.fail("Transaction should have failed with ContentNotFound");
+        assert.fail("Transaction should have failed: the content item account does not exist");
       } catch (err) {
-        // Check that the error is the specific custom error we expect.
+        // The content item PDA was never created, so Anchor rejects it before the handler runs.
         assert.isTrue(err instanceof anchor.AnchorError);
         const anchorError = err as anchor.AnchorError;
-        assert.equal(anchorError.error.errorCode.code, "ContentNotFound");
-        assert.include(anchorError.error.errorMessage, "The specified content was not found in the creator's account.");
+        assert.equal(anchorError.error.errorCode.code, "AccountNotInitialized");
       }
     });
+
+    it("Splits the creator's share between collaborators", async () => {
+      const contentId = new anchor.BN(1);
+      const splitPDA = getSplitPDA(creator1.publicKey, contentId);
+      const recipients = [
+        { wallet: creator1.publicKey, shareBps: new anchor.BN(5000) },
+        { wallet: creator2.publicKey, shareBps: new anchor.BN(5000) },
+      ];
+
+      await program.methods
+        .createRevenueSplit(contentId, recipients)
+        .accounts({
+          revenueSplit: splitPDA,
+          creatorAccount: getCreatorPDA(creator1.publicKey),
+          creator: creator1.publicKey,
+          payer: creator1.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([creator1])
+        .rpc();
+
+      const contentPDA = getContentPDA(creator1.publicKey, contentId);
+      const contentPrice = (await program.account.contentItem.fetch(contentPDA)).price;
+      const creatorAmount = contentPrice.sub(contentPrice.mul(FEE_BPS).div(new anchor.BN(10000)));
+      const collaboratorBalanceBefore = await provider.connection.getBalance(creator2.publicKey);
+
+      // Each recipient's wallet follows the named accounts, in split order
+      await program.methods
+        .processPayment(contentId, contentPrice, null)
+        .accounts({
+          paidAccessAccount: getReceiptPDA(admin.publicKey, creator1.publicKey, contentId),
+          protocolConfig: configPDA,
+          creatorAccount: getCreatorPDA(creator1.publicKey),
+          contentItem: contentPDA,
+          creatorWallet: creator1.publicKey,
+          treasury: treasuryPDA,
+          feeOverride: getFeeOverridePDA(creator1.publicKey),
+          contentSplit: splitPDA,
+          creatorSplit: getSplitPDA(creator1.publicKey, CREATOR_DEFAULT_SPLIT),
+          supporterStats: getSupporterStatsPDA(creator1.publicKey),
+          buyer: admin.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .remainingAccounts(
+          recipients.map((recipient) => ({ pubkey: recipient.wallet, isWritable: true, isSigner: false }))
+        )
+        .signers([admin])
+        .rpc();
+
+      const collaboratorBalanceAfter = await provider.connection.getBalance(creator2.publicKey);
+      assert.equal(collaboratorBalanceAfter, collaboratorBalanceBefore + creatorAmount.divn(2).toNumber());
+
+      // Remove it again so later purchases pay creator 1 directly
+      await program.methods
+        .closeRevenueSplit(contentId)
+        .accounts({ revenueSplit: splitPDA, creator: creator1.publicKey })
+        .signers([creator1])
+        .rpc();
+      assert.isNull(await provider.connection.getAccountInfo(splitPDA));
+    });
+
+    it("Lets a buyer pay for token-priced content in the listed mint", async () => {
+      // Creator 3 lists its first item at 25 tokens of a 6-decimal mint
+      const mint = await createMint(creator3, 6);
+      const price = new anchor.BN(25_000_000);
+      const contentId = new anchor.BN(1);
+      const contentPDA = getContentPDA(creator3.publicKey, contentId);
+      await program.methods
+        .addContent("Creator 3, Token Content", price, encryptCID("cid3_1"), { buyer: {} })
+        .accounts({
+          creatorAccount: getCreatorPDA(creator3.publicKey),
+          contentItem: contentPDA,
+          creator: creator3.publicKey,
+          payer: creator3.publicKey,
+          paymentMint: mint,
+        })
+        .signers([creator3])
+        .rpc();
+      assert.ok((await program.account.contentItem.fetch(contentPDA)).paymentMint.equals(mint));
+
+      const buyerTokenAccount = await createAtaAndMint(creator3, buyer.publicKey, mint, price);
+      const creatorTokenAccount = await createAtaAndMint(creator3, creator3.publicKey, mint, new anchor.BN(0));
+      const treasuryTokenAccount = await createAtaAndMint(creator3, treasuryPDA, mint, new anchor.BN(0));
+      const creatorLamportsBefore = await provider.connection.getBalance(creator3.publicKey);
+
+      const receiptPDA = getReceiptPDA(buyer.publicKey, creator3.publicKey, contentId);
+      await program.methods
+        .processPayment(contentId, price, null)
+        .accounts({
+          paidAccessAccount: receiptPDA,
+          protocolConfig: configPDA,
+          creatorAccount: getCreatorPDA(creator3.publicKey),
+          contentItem: contentPDA,
+          creatorWallet: creator3.publicKey,
+          treasury: treasuryPDA,
+          feeOverride: getFeeOverridePDA(creator3.publicKey),
+          contentSplit: getSplitPDA(creator3.publicKey, contentId),
+          creatorSplit: getSplitPDA(creator3.publicKey, CREATOR_DEFAULT_SPLIT),
+          supporterStats: getSupporterStatsPDA(creator3.publicKey, mint),
+          buyer: buyer.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+          paymentMint: mint,
+          buyerTokenAccount,
+          creatorTokenAccount,
+          treasuryTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([buyer])
+        .rpc();
+
+      // The fee and the creator's share move in tokens; no lamports change hands
+      const feeAmount = price.mul(FEE_BPS).div(new anchor.BN(10000));
+      assert.ok((await tokenBalance(buyerTokenAccount)).isZero());
+      assert.ok((await tokenBalance(creatorTokenAccount)).eq(price.sub(feeAmount)));
+      assert.ok((await tokenBalance(treasuryTokenAccount)).eq(feeAmount));
+      assert.equal(await provider.connection.getBalance(creator3.publicKey), creatorLamportsBefore);
+
+      const receipt = await program.account.paidAccessAccount.fetch(receiptPDA);
+      assert.ok(receipt.paymentMint.equals(mint));
+      assert.ok(receipt.price.eq(price));
+      assert.ok(receipt.feeAmount.eq(feeAmount));
+    });
   });
   
+  describe("Content Editing", () => {
+    const contentId = new anchor.BN(2); // Creator 2's second item, added below
+
+    before("Add a second item for creator 2", async () => {
+      await program.methods
+        .addContent("Creator 2, Content 2", new anchor.BN(web3.LAMPORTS_PER_SOL), encryptCID("cid2_2"), { buyer: {} })
+        .accounts({
+          creatorAccount: getCreatorPDA(creator2.publicKey),
+          contentItem: getContentPDA(creator2.publicKey, contentId),
+          creator: creator2.publicKey,
+        })
+        .signers([creator2])
+        .rpc();
+    });
+
+    it("Lets a creator reprice and retitle a content item", async () => {
+      const contentPDA = getContentPDA(creator2.publicKey, contentId);
+      const newPrice = new anchor.BN(0.25 * web3.LAMPORTS_PER_SOL);
+
+      await program.methods
+        .updateContent(contentId, "Creator 2, Content 2 (fixed typo)", newPrice, null)
+        .accounts({ contentItem: contentPDA, creator: creator2.publicKey })
+        .signers([creator2])
+        .rpc();
+
+      const contentItem = await program.account.contentItem.fetch(contentPDA);
+      assert.equal(contentItem.title, "Creator 2, Content 2 (fixed typo)");
+      assert.ok(contentItem.price.eq(newPrice));
+      assert.isTrue(contentItem.listed);
+    });
+
+    it("Prevents another creator from editing the item", async () => {
+      try {
+        await program.methods
+          .updateContent(contentId, null, new anchor.BN(1), null)
+          .accounts({ contentItem: getContentPDA(creator2.publicKey, contentId), creator: creator1.publicKey })
+          .signers([creator1])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        // The content PDA does not match creator 1's seeds
+        assert.ok(err);
+      }
+    });
+
+    it("Rejects purchases of unlisted content", async () => {
+      const contentPDA = getContentPDA(creator2.publicKey, contentId);
+      await program.methods
+        .unlistContent(contentId)
+        .accounts({ contentItem: contentPDA, creator: creator2.publicKey })
+        .signers([creator2])
+        .rpc();
+
+      try {
+        await program.methods
+          .processPayment(contentId, new anchor.BN(web3.LAMPORTS_PER_SOL), null)
+          .accounts({
+            paidAccessAccount: getReceiptPDA(buyer.publicKey, creator2.publicKey, contentId),
+            protocolConfig: configPDA,
+            creatorAccount: getCreatorPDA(creator2.publicKey),
+            contentItem: contentPDA,
+            creatorWallet: creator2.publicKey,
+            treasury: treasuryPDA,
+            feeOverride: getFeeOverridePDA(creator2.publicKey),
+            contentSplit: getSplitPDA(creator2.publicKey, contentId),
+            creatorSplit: getSplitPDA(creator2.publicKey, CREATOR_DEFAULT_SPLIT),
+            supporterStats: getSupporterStatsPDA(creator2.publicKey),
+            buyer: buyer.publicKey,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+        assert.fail("Should have failed with ContentUnlisted");
+      } catch (err) {
+        assert.isTrue(err instanceof anchor.AnchorError);
+        const anchorError = err as anchor.AnchorError;
+        assert.equal(anchorError.error.errorCode.code, "ContentUnlisted");
+      }
+
+      await program.methods
+        .relistContent(contentId)
+        .accounts({ contentItem: contentPDA, creator: creator2.publicKey })
+        .signers([creator2])
+        .rpc();
+      const contentItem = await program.account.contentItem.fetch(contentPDA);
+      assert.isTrue(contentItem.listed);
+    });
+  });
+
   describe("Relayed Transactions", () => {
     const relayer = web3.Keypair.generate();
     const relayedBuyer = web3.Keypair.generate(); // New buyer for this test
@@ -409,24 +926,22 @@ describe("auton_program", () => {
 
     it("Allows a relayer to pay gas for a buyer's purchase", async () => {
         const creatorPDA = getCreatorPDA(creator1.publicKey);
-        const [receiptPDA] = web3.PublicKey.findProgramAddressSync(
-            [
-                Buffer.from("access"),
-                relayedBuyer.publicKey.toBuffer(),
-                contentId.toArrayLike(Buffer, "le", 8),
-            ],
-            program.programId
-        );
+        const receiptPDA = getReceiptPDA(relayedBuyer.publicKey, creator1.publicKey, contentId);
 
         // 1. Build the instruction (User's intent)
         const ix = await program.methods
-            .processPayment(contentId)
+            .processPayment(contentId, new anchor.BN(2 * web3.LAMPORTS_PER_SOL), null)
             .accounts({
                 paidAccessAccount: receiptPDA,
                 protocolConfig: configPDA,
                 creatorAccount: creatorPDA,
+                contentItem: getContentPDA(creator1.publicKey, contentId),
                 creatorWallet: creator1.publicKey,
-                adminWallet: admin.publicKey,
+                treasury: treasuryPDA,
+                feeOverride: getFeeOverridePDA(creator1.publicKey),
+                contentSplit: getSplitPDA(creator1.publicKey, contentId),
+                creatorSplit: getSplitPDA(creator1.publicKey, CREATOR_DEFAULT_SPLIT),
+                supporterStats: getSupporterStatsPDA(creator1.publicKey),
                 buyer: relayedBuyer.publicKey,
                 systemProgram: web3.SystemProgram.programId,
             })
@@ -459,29 +974,562 @@ describe("auton_program", () => {
     });
   });
 
-  describe("Protocol Management", () => {
-    it("Allows admin to update fee percentage", async () => {
-      const NEW_FEE_BPS = new anchor.BN(800); // Change to 8%
+  describe("Referrals", () => {
+    const referrer = web3.Keypair.generate();
+    const referredBuyer = web3.Keypair.generate();
+    const contentId = new anchor.BN(1);
+    const [referrerStatsPDA] = web3.PublicKey.findProgramAddressSync(
+      // SOL earnings are keyed by the all-zero mint
+      [Buffer.from("referrer"), referrer.publicKey.toBuffer(), web3.PublicKey.default.toBuffer()],
+      program.programId
+    );
+
+    before("Fund the referrer and buyer", async () => {
+      for (const wallet of [referrer, referredBuyer]) {
+        const sig = await provider.connection.requestAirdrop(wallet.publicKey, 5 * web3.LAMPORTS_PER_SOL);
+        await provider.connection.confirmTransaction(sig, "confirmed");
+      }
+    });
+
+    it("Pays a registered referrer the creator's affiliate share", async () => {
+      await program.methods
+        .setAffiliateShare(new anchor.BN(1000)) // 10% of the creator's share
+        .accounts({ creatorAccount: getCreatorPDA(creator1.publicKey), creator: creator1.publicKey })
+        .signers([creator1])
+        .rpc();
+      await program.methods
+        .registerReferrer(null)
+        .accounts({
+          referrerStats: referrerStatsPDA,
+          referrer: referrer.publicKey,
+          payer: referrer.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([referrer])
+        .rpc();
+
+      const contentPDA = getContentPDA(creator1.publicKey, contentId);
+      const contentPrice = (await program.account.contentItem.fetch(contentPDA)).price;
+      const creatorShare = contentPrice.sub(contentPrice.mul(FEE_BPS).div(new anchor.BN(10000)));
+      const referralAmount = creatorShare.divn(10);
+      const referrerBalanceBefore = await provider.connection.getBalance(referrer.publicKey);
+      const receiptPDA = getReceiptPDA(referredBuyer.publicKey, creator1.publicKey, contentId);
 
       await program.methods
-        .updateConfig(null, NEW_FEE_BPS)
+        .processPayment(contentId, contentPrice, null)
         .accounts({
+          paidAccessAccount: receiptPDA,
           protocolConfig: configPDA,
-          admin: admin.publicKey,
+          creatorAccount: getCreatorPDA(creator1.publicKey),
+          contentItem: contentPDA,
+          creatorWallet: creator1.publicKey,
+          treasury: treasuryPDA,
+          feeOverride: getFeeOverridePDA(creator1.publicKey),
+          contentSplit: getSplitPDA(creator1.publicKey, contentId),
+          creatorSplit: getSplitPDA(creator1.publicKey, CREATOR_DEFAULT_SPLIT),
+          supporterStats: getSupporterStatsPDA(creator1.publicKey),
+          buyer: referredBuyer.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+          referrerStats: referrerStatsPDA,
+          referrer: referrer.publicKey,
         })
-        .signers([admin])
+        .signers([referredBuyer])
         .rpc();
 
-      const config = await program.account.protocolConfig.fetch(configPDA);
-      assert.ok(config.feePercentage.eq(NEW_FEE_BPS));
+      const referrerBalanceAfter = await provider.connection.getBalance(referrer.publicKey);
+      assert.equal(referrerBalanceAfter, referrerBalanceBefore + referralAmount.toNumber());
+
+      const receipt = await program.account.paidAccessAccount.fetch(receiptPDA);
+      assert.ok(receipt.referrer.equals(referrer.publicKey));
+      assert.ok(receipt.referralAmount.eq(referralAmount));
+      assert.ok(receipt.creatorAmount.eq(creatorShare.sub(referralAmount)));
+
+      const stats = await program.account.referrerStats.fetch(referrerStatsPDA);
+      assert.equal(stats.referralCount.toNumber(), 1);
+      assert.ok(stats.totalEarned.eq(referralAmount));
+
+      // Turn referral payouts off again for the rest of the suite
+      await program.methods
+        .setAffiliateShare(new anchor.BN(0))
+        .accounts({ creatorAccount: getCreatorPDA(creator1.publicKey), creator: creator1.publicKey })
+        .signers([creator1])
+        .rpc();
     });
+  });
 
-    it("Prevents unauthorized users from updating config", async () => {
-      const MALICIOUS_FEE = new anchor.BN(0); // Try to set fee to 0%
+  describe("Bundles", () => {
+    const bundleBuyer = web3.Keypair.generate();
+    const bundleId = 1;
+    const contentIds = [new anchor.BN(1), new anchor.BN(2)];
+    const bundlePrice = new anchor.BN(3 * web3.LAMPORTS_PER_SOL);
+    const discountBps = new anchor.BN(1000); // 10% off
+    // The bundle's content items are checked on creation and purchase, passed in order
+    const contentItemAccounts = () =>
+      contentIds.map((id) => ({ pubkey: getContentPDA(creator1.publicKey, id), isWritable: false, isSigner: false }));
 
-      try {
-        await program.methods
-          .updateConfig(null, MALICIOUS_FEE)
+    const [bundlePDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("bundle"), creator1.publicKey.toBuffer(), Buffer.from([bundleId])],
+      program.programId
+    );
+    const [bundleReceiptPDA] = web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("bundle_access"),
+        bundleBuyer.publicKey.toBuffer(),
+        creator1.publicKey.toBuffer(),
+        Buffer.from([bundleId]),
+      ],
+      program.programId
+    );
+
+    before("Fund the bundle buyer", async () => {
+      const sig = await provider.connection.requestAirdrop(bundleBuyer.publicKey, 5 * web3.LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig, "confirmed");
+    });
+
+    it("Sells every item in a bundle for one discounted payment", async () => {
+      await program.methods
+        .createBundle(bundleId, contentIds, bundlePrice, discountBps, { buyer: {} })
+        .accounts({
+          bundle: bundlePDA,
+          creatorAccount: getCreatorPDA(creator1.publicKey),
+          creator: creator1.publicKey,
+          payer: creator1.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .remainingAccounts(contentItemAccounts())
+        .signers([creator1])
+        .rpc();
+
+      const salePrice = bundlePrice.sub(bundlePrice.mul(discountBps).div(new anchor.BN(10000)));
+      const feeAmount = salePrice.mul(FEE_BPS).div(new anchor.BN(10000));
+      const creatorBalanceBefore = await provider.connection.getBalance(creator1.publicKey);
+
+      await program.methods
+        .purchaseBundle(bundleId, salePrice, null)
+        .accounts({
+          bundleReceipt: bundleReceiptPDA,
+          bundle: bundlePDA,
+          protocolConfig: configPDA,
+          creatorAccount: getCreatorPDA(creator1.publicKey),
+          creatorWallet: creator1.publicKey,
+          treasury: treasuryPDA,
+          feeOverride: getFeeOverridePDA(creator1.publicKey),
+          creatorSplit: getSplitPDA(creator1.publicKey, CREATOR_DEFAULT_SPLIT),
+          buyer: bundleBuyer.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .remainingAccounts(contentItemAccounts())
+        .signers([bundleBuyer])
+        .rpc();
+
+      const creatorBalanceAfter = await provider.connection.getBalance(creator1.publicKey);
+      assert.equal(creatorBalanceAfter, creatorBalanceBefore + salePrice.sub(feeAmount).toNumber());
+
+      const receipt = await program.account.bundleReceipt.fetch(bundleReceiptPDA);
+      assert.ok(receipt.buyer.equals(bundleBuyer.publicKey));
+      assert.deepEqual(receipt.contentIds.map((id) => id.toNumber()), [1, 2]);
+      assert.ok(receipt.price.eq(salePrice));
+    });
+  });
+
+  describe("Coupons", () => {
+    const couponBuyer = web3.Keypair.generate();
+    const code = "LAUNCH";
+    const contentId = new anchor.BN(1);
+    const discountBps = new anchor.BN(2000); // 20% off
+
+    const [couponPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("coupon"), creator2.publicKey.toBuffer(), Buffer.from(code)],
+      program.programId
+    );
+
+    before("Fund the coupon buyer", async () => {
+      const sig = await provider.connection.requestAirdrop(couponBuyer.publicKey, 5 * web3.LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig, "confirmed");
+    });
+
+    it("Takes a coupon's discount off the price before the fee split", async () => {
+      await program.methods
+        .createCoupon(code, { percentOff: { bps: discountBps } }, new anchor.BN(100), null, null)
+        .accounts({
+          coupon: couponPDA,
+          creator: creator2.publicKey,
+          payer: creator2.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([creator2])
+        .rpc();
+
+      const contentPDA = getContentPDA(creator2.publicKey, contentId);
+      const listedPrice = (await program.account.contentItem.fetch(contentPDA)).price;
+      const discountAmount = listedPrice.mul(discountBps).div(new anchor.BN(10000));
+      const price = listedPrice.sub(discountAmount);
+      const feeAmount = price.mul(FEE_BPS).div(new anchor.BN(10000));
+      const creatorBalanceBefore = await provider.connection.getBalance(creator2.publicKey);
+      const receiptPDA = getReceiptPDA(couponBuyer.publicKey, creator2.publicKey, contentId);
+
+      // The buyer's maximum is checked against the discounted price
+      await program.methods
+        .processPayment(contentId, price, null)
+        .accounts({
+          paidAccessAccount: receiptPDA,
+          protocolConfig: configPDA,
+          creatorAccount: getCreatorPDA(creator2.publicKey),
+          contentItem: contentPDA,
+          creatorWallet: creator2.publicKey,
+          treasury: treasuryPDA,
+          feeOverride: getFeeOverridePDA(creator2.publicKey),
+          contentSplit: getSplitPDA(creator2.publicKey, contentId),
+          creatorSplit: getSplitPDA(creator2.publicKey, CREATOR_DEFAULT_SPLIT),
+          supporterStats: getSupporterStatsPDA(creator2.publicKey),
+          buyer: couponBuyer.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+          coupon: couponPDA,
+        })
+        .signers([couponBuyer])
+        .rpc();
+
+      const creatorBalanceAfter = await provider.connection.getBalance(creator2.publicKey);
+      assert.equal(creatorBalanceAfter, creatorBalanceBefore + price.sub(feeAmount).toNumber());
+
+      const receipt = await program.account.paidAccessAccount.fetch(receiptPDA);
+      assert.ok(receipt.coupon.equals(couponPDA));
+      assert.ok(receipt.discountAmount.eq(discountAmount));
+      assert.ok(receipt.price.eq(price));
+
+      const coupon = await program.account.coupon.fetch(couponPDA);
+      assert.equal(coupon.redemptions.toNumber(), 1);
+    });
+  });
+
+  describe("Tips", () => {
+    const tipAmount = new anchor.BN(0.2 * web3.LAMPORTS_PER_SOL);
+    const supporterStatsPDA = getSupporterStatsPDA(creator2.publicKey);
+
+    it("Lets a buyer tip a creator with a message", async () => {
+      await program.methods
+        .createSupporterStats(null)
+        .accounts({
+          supporterStats: supporterStatsPDA,
+          creator: creator2.publicKey,
+          payer: creator2.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([creator2])
+        .rpc();
+
+      const feeAmount = tipAmount.mul(FEE_BPS).div(new anchor.BN(10000));
+      const creatorBalanceBefore = await provider.connection.getBalance(creator2.publicKey);
+
+      await program.methods
+        .tipCreator(tipAmount, null, "Loved the first episode")
+        .accounts({
+          protocolConfig: configPDA,
+          creatorAccount: getCreatorPDA(creator2.publicKey),
+          creatorWallet: creator2.publicKey,
+          treasury: treasuryPDA,
+          feeOverride: getFeeOverridePDA(creator2.publicKey),
+          supporterStats: supporterStatsPDA,
+          tipper: buyer.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+      const creatorBalanceAfter = await provider.connection.getBalance(creator2.publicKey);
+      assert.equal(creatorBalanceAfter, creatorBalanceBefore + tipAmount.sub(feeAmount).toNumber());
+
+      const stats = await program.account.supporterStats.fetch(supporterStatsPDA);
+      assert.equal(stats.tipCount.toNumber(), 1);
+      assert.ok(stats.totalTipped.eq(tipAmount));
+    });
+  });
+
+  describe("Rentals", () => {
+    const renter = web3.Keypair.generate();
+    const contentId = new anchor.BN(1);
+    const rentalPrice = new anchor.BN(0.1 * web3.LAMPORTS_PER_SOL);
+    const rentalDuration = new anchor.BN(48 * 60 * 60); // 48 hours
+
+    before("Fund the renter", async () => {
+      const sig = await provider.connection.requestAirdrop(renter.publicKey, 5 * web3.LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig, "confirmed");
+    });
+
+    it("Rents content for a limited time and extends the rental on renewal", async () => {
+      const contentPDA = getContentPDA(creator2.publicKey, contentId);
+      await program.methods
+        .setRentalTerms(contentId, { price: rentalPrice, duration: rentalDuration })
+        .accounts({ contentItem: contentPDA, creator: creator2.publicKey })
+        .signers([creator2])
+        .rpc();
+
+      const receiptPDA = getReceiptPDA(renter.publicKey, creator2.publicKey, contentId);
+      const rentalAccounts = {
+        paidAccessAccount: receiptPDA,
+        creatorAccount: getCreatorPDA(creator2.publicKey),
+        contentItem: contentPDA,
+        protocolConfig: configPDA,
+        creatorWallet: creator2.publicKey,
+        treasury: treasuryPDA,
+        feeOverride: getFeeOverridePDA(creator2.publicKey),
+        contentSplit: getSplitPDA(creator2.publicKey, contentId),
+        creatorSplit: getSplitPDA(creator2.publicKey, CREATOR_DEFAULT_SPLIT),
+        buyer: renter.publicKey,
+        systemProgram: web3.SystemProgram.programId,
+      };
+
+      const feeAmount = rentalPrice.mul(FEE_BPS).div(new anchor.BN(10000));
+      const creatorBalanceBefore = await provider.connection.getBalance(creator2.publicKey);
+
+      await program.methods
+        .rentContent(contentId, rentalPrice, null)
+        .accounts(rentalAccounts)
+        .signers([renter])
+        .rpc();
+
+      const creatorBalanceAfter = await provider.connection.getBalance(creator2.publicKey);
+      assert.equal(creatorBalanceAfter, creatorBalanceBefore + rentalPrice.sub(feeAmount).toNumber());
+
+      const rented = await program.account.paidAccessAccount.fetch(receiptPDA);
+      assert.ok(rented.price.eq(rentalPrice));
+      assert.ok(rented.expiresAt.eq(rented.createdAt.add(rentalDuration)));
+
+      await program.methods
+        .renewRental(contentId, rentalPrice, null)
+        .accounts(rentalAccounts)
+        .signers([renter])
+        .rpc();
+
+      const renewed = await program.account.paidAccessAccount.fetch(receiptPDA);
+      assert.ok(renewed.expiresAt.eq(rented.expiresAt.add(rentalDuration)));
+    });
+
+    it("Refuses to renew a permanent purchase", async () => {
+      // `buyer` bought this item outright earlier in the suite
+      try {
+        await program.methods
+          .renewRental(contentId, rentalPrice, null)
+          .accounts({
+            paidAccessAccount: getReceiptPDA(buyer.publicKey, creator2.publicKey, contentId),
+            creatorAccount: getCreatorPDA(creator2.publicKey),
+            contentItem: getContentPDA(creator2.publicKey, contentId),
+            protocolConfig: configPDA,
+            creatorWallet: creator2.publicKey,
+            treasury: treasuryPDA,
+            feeOverride: getFeeOverridePDA(creator2.publicKey),
+            contentSplit: getSplitPDA(creator2.publicKey, contentId),
+            creatorSplit: getSplitPDA(creator2.publicKey, CREATOR_DEFAULT_SPLIT),
+            buyer: buyer.publicKey,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+        assert.fail("Should have failed with NotARental");
+      } catch (err) {
+        assert.isTrue(err instanceof anchor.AnchorError);
+        const anchorError = err as anchor.AnchorError;
+        assert.equal(anchorError.error.errorCode.code, "NotARental");
+      }
+    });
+  });
+
+  describe("Limited Editions", () => {
+    const collector = web3.Keypair.generate();
+    const contentId = new anchor.BN(3); // Creator 2's third item, added below
+    const price = new anchor.BN(0.1 * web3.LAMPORTS_PER_SOL);
+
+    const purchaseAccounts = (purchaser: web3.PublicKey) => ({
+      paidAccessAccount: getReceiptPDA(purchaser, creator2.publicKey, contentId),
+      protocolConfig: configPDA,
+      creatorAccount: getCreatorPDA(creator2.publicKey),
+      contentItem: getContentPDA(creator2.publicKey, contentId),
+      creatorWallet: creator2.publicKey,
+      treasury: treasuryPDA,
+      feeOverride: getFeeOverridePDA(creator2.publicKey),
+      contentSplit: getSplitPDA(creator2.publicKey, contentId),
+      creatorSplit: getSplitPDA(creator2.publicKey, CREATOR_DEFAULT_SPLIT),
+      supporterStats: getSupporterStatsPDA(creator2.publicKey),
+      buyer: purchaser,
+      systemProgram: web3.SystemProgram.programId,
+    });
+
+    before("Add a single-copy item for creator 2 and fund the collector", async () => {
+      const contentPDA = getContentPDA(creator2.publicKey, contentId);
+      await program.methods
+        .addContent("Creator 2, Content 3", price, encryptCID("cid2_3"), { buyer: {} })
+        .accounts({
+          creatorAccount: getCreatorPDA(creator2.publicKey),
+          contentItem: contentPDA,
+          creator: creator2.publicKey,
+        })
+        .signers([creator2])
+        .rpc();
+
+      await program.methods
+        .setMaxSupply(contentId, new anchor.BN(1))
+        .accounts({ contentItem: contentPDA, creator: creator2.publicKey })
+        .signers([creator2])
+        .rpc();
+
+      const sig = await provider.connection.requestAirdrop(collector.publicKey, 5 * web3.LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig, "confirmed");
+    });
+
+    it("Numbers editions in purchase order and sells out at the max supply", async () => {
+      await program.methods
+        .processPayment(contentId, price, null)
+        .accounts(purchaseAccounts(collector.publicKey))
+        .signers([collector])
+        .rpc();
+
+      const receipt = await program.account.paidAccessAccount.fetch(
+        getReceiptPDA(collector.publicKey, creator2.publicKey, contentId)
+      );
+      assert.equal(receipt.edition.toNumber(), 1);
+
+      const item = await program.account.contentItem.fetch(getContentPDA(creator2.publicKey, contentId));
+      assert.equal(item.sold.toNumber(), 1);
+      assert.equal(item.maxSupply.toNumber(), 1);
+
+      try {
+        await program.methods
+          .processPayment(contentId, price, null)
+          .accounts(purchaseAccounts(buyer.publicKey))
+          .signers([buyer])
+          .rpc();
+        assert.fail("Should have failed with SoldOut");
+      } catch (err) {
+        assert.isTrue(err instanceof anchor.AnchorError);
+        const anchorError = err as anchor.AnchorError;
+        assert.equal(anchorError.error.errorCode.code, "SoldOut");
+      }
+    });
+  });
+
+  describe("Subscriptions", () => {
+    const tierId = 1;
+    const periodSeconds = new anchor.BN(30 * 24 * 60 * 60); // 30 days
+    const tierPrice = new anchor.BN(0.1 * web3.LAMPORTS_PER_SOL);
+
+    const [tierPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("tier"), creator2.publicKey.toBuffer(), Buffer.from([tierId])],
+      program.programId
+    );
+    const [subscriptionPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("subscription"), buyer.publicKey.toBuffer(), creator2.publicKey.toBuffer()],
+      program.programId
+    );
+
+    it("Lets a creator create a subscription tier", async () => {
+      await program.methods
+        .createSubscriptionTier(tierId, tierPrice, periodSeconds, [], { buyer: {} })
+        .accounts({
+          subscriptionTier: tierPDA,
+          creatorAccount: getCreatorPDA(creator2.publicKey),
+          creator: creator2.publicKey,
+          payer: creator2.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([creator2])
+        .rpc();
+
+      const tier = await program.account.subscriptionTier.fetch(tierPDA);
+      assert.ok(tier.creator.equals(creator2.publicKey));
+      assert.ok(tier.price.eq(tierPrice));
+      assert.isEmpty(tier.contentIds);
+    });
+
+    it("Lets a buyer subscribe and renew, extending from the current expiry", async () => {
+      const paymentAccounts = {
+        subscriptionTier: tierPDA,
+        protocolConfig: configPDA,
+        creatorAccount: getCreatorPDA(creator2.publicKey),
+        creatorWallet: creator2.publicKey,
+        treasury: treasuryPDA,
+        feeOverride: getFeeOverridePDA(creator2.publicKey),
+        subscriber: buyer.publicKey,
+        systemProgram: web3.SystemProgram.programId,
+      };
+
+      const creatorBalanceBefore = await provider.connection.getBalance(creator2.publicKey);
+
+      await program.methods
+        .subscribe(tierId, 1, tierPrice, null)
+        .accounts({ subscription: subscriptionPDA, ...paymentAccounts })
+        .signers([buyer])
+        .rpc();
+
+      const subscribed = await program.account.subscription.fetch(subscriptionPDA);
+      assert.ok(subscribed.subscriber.equals(buyer.publicKey));
+      assert.equal(
+        subscribed.expiresAt.sub(subscribed.startedAt).toNumber(),
+        periodSeconds.toNumber()
+      );
+
+      const creatorBalanceAfter = await provider.connection.getBalance(creator2.publicKey);
+      const feeAmount = tierPrice.mul(FEE_BPS).div(new anchor.BN(10000));
+      assert.equal(creatorBalanceAfter, creatorBalanceBefore + tierPrice.sub(feeAmount).toNumber());
+
+      await program.methods
+        .renewSubscription(tierId, 2, tierPrice.muln(2), null)
+        .accounts({ subscription: subscriptionPDA, ...paymentAccounts })
+        .signers([buyer])
+        .rpc();
+
+      const renewed = await program.account.subscription.fetch(subscriptionPDA);
+      assert.ok(renewed.startedAt.eq(subscribed.startedAt));
+      assert.ok(renewed.expiresAt.eq(subscribed.expiresAt.add(periodSeconds.muln(2))));
+    });
+  });
+
+  describe("Protocol Management", () => {
+    it("Schedules fee increases instead of applying them immediately", async () => {
+      const NEW_FEE_BPS = new anchor.BN(800); // Change to 8%
+
+      await program.methods
+        .updateConfig(NEW_FEE_BPS, null)
+        .accounts({
+          protocolConfig: configPDA,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      // The current fee is unchanged until the notice period is over
+      const config = await program.account.protocolConfig.fetch(configPDA);
+      assert.ok(config.feePercentage.eq(FEE_BPS));
+      assert.ok(config.pendingFeePercentage.eq(NEW_FEE_BPS));
+      assert.isAbove(config.feeEffectiveAt.toNumber(), Math.floor(Date.now() / 1000));
+    });
+
+    it("Rejects fees above the fee cap", async () => {
+      const config = await program.account.protocolConfig.fetch(configPDA);
+      const aboveCap = config.maxFeePercentage.addn(1);
+
+      try {
+        await program.methods
+          .updateConfig(aboveCap, null)
+          .accounts({
+            protocolConfig: configPDA,
+            admin: admin.publicKey,
+          })
+          .signers([admin])
+          .rpc();
+        assert.fail("Should have failed with FeeAboveCap");
+      } catch (err) {
+        assert.isTrue(err instanceof anchor.AnchorError);
+        const anchorError = err as anchor.AnchorError;
+        assert.equal(anchorError.error.errorCode.code, "FeeAboveCap");
+      }
+    });
+
+    it("Prevents unauthorized users from updating config", async () => {
+      const MALICIOUS_FEE = new anchor.BN(0); // Try to set fee to 0%
+
+      try {
+        await program.methods
+          .updateConfig(MALICIOUS_FEE, null)
           .accounts({
             protocolConfig: configPDA,
             admin: buyer.publicKey, // Buyer tries to sign as admin
@@ -495,5 +1543,145 @@ describe("auton_program", () => {
         assert.equal(anchorError.error.errorCode.code, "Unauthorized");
       }
     });
+
+    it("Lets the admin withdraw collected fees to the fee recipient", async () => {
+      const config = await program.account.protocolConfig.fetch(configPDA);
+      assert.ok(config.feeRecipient.equals(admin.publicKey));
+
+      const withdrawAmount = new anchor.BN(1000);
+      const recipientBalanceBefore = await provider.connection.getBalance(admin.publicKey);
+
+      await program.methods
+        .withdrawTreasury(withdrawAmount)
+        .accounts({
+          protocolConfig: configPDA,
+          treasury: treasuryPDA,
+          feeRecipient: admin.publicKey,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      // The admin is also the fee payer here, so allow for the transaction fee
+      const recipientBalanceAfter = await provider.connection.getBalance(admin.publicKey);
+      assert.isAbove(recipientBalanceAfter, recipientBalanceBefore - 10000);
+    });
+
+    it("Lets the admin give a creator a custom fee", async () => {
+      const feeOverridePDA = getFeeOverridePDA(creator2.publicKey);
+
+      await program.methods
+        .createFeeOverride(creator2.publicKey, new anchor.BN(100), null)
+        .accounts({
+          feeOverride: feeOverridePDA,
+          protocolConfig: configPDA,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const feeOverride = await program.account.feeOverride.fetch(feeOverridePDA);
+      assert.ok(feeOverride.creator.equals(creator2.publicKey));
+      assert.equal(feeOverride.feeBps.toNumber(), 100);
+      assert.isNull(feeOverride.expiresAt);
+
+      // Remove it again so later purchases pay the global fee
+      await program.methods
+        .closeFeeOverride(creator2.publicKey)
+        .accounts({
+          feeOverride: feeOverridePDA,
+          protocolConfig: configPDA,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      assert.isNull(await provider.connection.getAccountInfo(feeOverridePDA));
+    });
+
+    it("Blocks purchases while payments are paused", async () => {
+      const PAUSE_PAYMENTS = 1;
+      const contentId = new anchor.BN(1);
+      const receiptPDA = getReceiptPDA(admin.publicKey, creator2.publicKey, contentId);
+
+      await program.methods
+        .setPaused(PAUSE_PAYMENTS)
+        .accounts({ protocolConfig: configPDA, authority: admin.publicKey })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await program.methods
+          .processPayment(contentId, new anchor.BN(web3.LAMPORTS_PER_SOL), null)
+          .accounts({
+            paidAccessAccount: receiptPDA,
+            protocolConfig: configPDA,
+            creatorAccount: getCreatorPDA(creator2.publicKey),
+            contentItem: getContentPDA(creator2.publicKey, contentId),
+            creatorWallet: creator2.publicKey,
+            treasury: treasuryPDA,
+            feeOverride: getFeeOverridePDA(creator2.publicKey),
+            contentSplit: getSplitPDA(creator2.publicKey, contentId),
+            creatorSplit: getSplitPDA(creator2.publicKey, CREATOR_DEFAULT_SPLIT),
+            supporterStats: getSupporterStatsPDA(creator2.publicKey),
+            buyer: admin.publicKey,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+        assert.fail("Should have failed with ProtocolPaused");
+      } catch (err) {
+        assert.isTrue(err instanceof anchor.AnchorError);
+        const anchorError = err as anchor.AnchorError;
+        assert.equal(anchorError.error.errorCode.code, "ProtocolPaused");
+      }
+
+      // Resume, so the rest of the suite can keep buying
+      await program.methods
+        .setPaused(0)
+        .accounts({ protocolConfig: configPDA, authority: admin.publicKey })
+        .signers([admin])
+        .rpc();
+
+      const config = await program.account.protocolConfig.fetch(configPDA);
+      assert.equal(config.paused, 0);
+    });
+
+    it("Hands the admin role over only once the new admin accepts", async () => {
+      const newAdmin = web3.Keypair.generate();
+
+      await program.methods
+        .proposeAdmin(newAdmin.publicKey)
+        .accounts({ protocolConfig: configPDA, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+
+      let config = await program.account.protocolConfig.fetch(configPDA);
+      assert.ok(config.adminWallet.equals(admin.publicKey));
+      assert.ok(config.pendingAdmin.equals(newAdmin.publicKey));
+
+      // Cancel, so the rest of the suite keeps the original admin
+      await program.methods
+        .cancelAdminProposal()
+        .accounts({ protocolConfig: configPDA, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await program.methods
+          .acceptAdmin()
+          .accounts({ protocolConfig: configPDA, newAdmin: newAdmin.publicKey })
+          .signers([newAdmin])
+          .rpc();
+        assert.fail("Should have failed with NoPendingAdmin");
+      } catch (err) {
+        assert.isTrue(err instanceof anchor.AnchorError);
+        const anchorError = err as anchor.AnchorError;
+        assert.equal(anchorError.error.errorCode.code, "NoPendingAdmin");
+      }
+
+      config = await program.account.protocolConfig.fetch(configPDA);
+      assert.ok(config.adminWallet.equals(admin.publicKey));
+      assert.isNull(config.pendingAdmin);
+    });
   });
 });
@@ -4,6 +4,7 @@ import * as anchor from '@coral-xyz/anchor';
 import { AutonProgram } from '@/lib/anchor/auton_program'; // Adjust path as needed
 import IDL from '@/lib/anchor/auton_program.json'; // Adjust path as needed
 import { createDecipheriv } from 'crypto';
+import { decodeLegacyReceipt } from '@/lib/legacy-receipt';
 
 const ENCRYPTION_SECRET_KEY = process.env.ENCRYPTION_SECRET_KEY;
 const SOLANA_RPC_URL = process.env.NEXT_PUBLIC_SOLANA_RPC_URL || 'http://127.0.0.1:8899';
@@ -19,6 +20,18 @@ if (!AUTON_PROGRAM_ID) {
 const encryptionKeyBuffer = Buffer.from(ENCRYPTION_SECRET_KEY, 'hex');
 const programId = new PublicKey(AUTON_PROGRAM_ID);
 
+// USDC on mainnet and devnet; content priced in any other mint is reported by its address.
+const USDC_MINTS = [
+  'EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v',
+  '4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU',
+];
+
+// The asset a content item is priced in: SOL when it has no payment mint.
+function assetTypeFor(paymentMint: PublicKey | null): string {
+  if (!paymentMint) return 'SOL';
+  return USDC_MINTS.includes(paymentMint.toBase58()) ? 'USDC' : paymentMint.toBase58();
+}
+
 // Decryption function (matches test implementation)
 function decryptCID(encryptedDataHex: string): string {
   const encryptedData = Buffer.from(encryptedDataHex, 'hex');
@@ -79,50 +92,108 @@ export async function GET(
       }
     }
 
-    // 1. Check for PaidAccessAccount (receipt)
+    // 1. Check for PaidAccessAccount (receipt), scoped to this creator's content ID
+    const contentIdBytes = new anchor.BN(contentIdNum).toArrayLike(Buffer, "le", 8);
     const [paidAccessPDA] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("access"),
         buyerPubkey.toBuffer(),
-        new anchor.BN(contentIdNum).toArrayLike(Buffer, "le", 8),
+        creatorPubkey.toBuffer(),
+        contentIdBytes,
       ],
       programId
     );
 
+    // A purchase grants access for good; a rental only until it expires.
+    const now = Math.floor(Date.now() / 1000);
     let hasAccess = false;
     try {
-      await program.account.paidAccessAccount.fetch(paidAccessPDA);
-      hasAccess = true;
+      const receipt = await program.account.paidAccessAccount.fetch(paidAccessPDA);
+      hasAccess = receipt.expiresAt === null || now < receipt.expiresAt.toNumber();
     } catch (e) {
       // Account not found, means no access
       hasAccess = false;
     }
 
-    // 2. Fetch CreatorAccount to get content details (price, encrypted CID)
-    const [creatorAccountPDA] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("creator"),
-        creatorPubkey.toBuffer()
-      ],
+    // Receipts created before receipts were scoped per creator live at the legacy
+    // [access, buyer, content_id] address until migrated. Those only prove access
+    // when the creator stored on the receipt is the creator being requested.
+    if (!hasAccess) {
+      const [legacyPaidAccessPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("access"), buyerPubkey.toBuffer(), contentIdBytes],
+        programId
+      );
+      const legacyAccount = await connection.getAccountInfo(legacyPaidAccessPDA);
+      const legacyReceipt = legacyAccount?.owner.equals(programId) ? decodeLegacyReceipt(legacyAccount.data) : null;
+      hasAccess = !!legacyReceipt && legacyReceipt.creator.equals(creatorPubkey);
+    }
+
+    // A bundle receipt that lists this content ID also grants access. Bundle IDs are a u8,
+    // so every possible receipt address is checked, 100 accounts per request.
+    if (!hasAccess) {
+      const bundleReceiptPDAs = Array.from({ length: 256 }, (_, bundleId) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("bundle_access"), buyerPubkey.toBuffer(), creatorPubkey.toBuffer(), Buffer.from([bundleId])],
+          programId
+        )[0]
+      );
+      for (let i = 0; i < bundleReceiptPDAs.length && !hasAccess; i += 100) {
+        const accounts = await connection.getMultipleAccountsInfo(bundleReceiptPDAs.slice(i, i + 100));
+        hasAccess = accounts.some((account) => {
+          if (!account?.owner.equals(programId)) return false;
+          const bundleReceipt = program.coder.accounts.decode('bundleReceipt', account.data);
+          return bundleReceipt.creator.equals(creatorPubkey)
+            && bundleReceipt.contentIds.some((id: anchor.BN) => id.eqn(contentIdNum));
+        });
+      }
+    }
+
+    // 2. Fetch the content item PDA to get its details (price, encrypted CID)
+    const [contentItemPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("content"), creatorPubkey.toBuffer(), contentIdBytes],
       programId
     );
-    const creatorAccount = await program.account.creatorAccount.fetch(creatorAccountPDA);
-    const contentItem = creatorAccount.content.find(item => item.id.toNumber() === contentIdNum);
+    const contentItem = await program.account.contentItem.fetchNullable(contentItemPDA);
 
     if (!contentItem) {
       return NextResponse.json({ error: 'Content not found for this creator' }, { status: 404 });
     }
 
+    // An active subscription to the creator grants access when its tier covers this content ID
+    // (a tier with no content IDs covers all of the creator's content). Subscriptions don't
+    // unlock limited editions or unlisted content.
+    if (!hasAccess && contentItem.listed && contentItem.maxSupply === null) {
+      const [subscriptionPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("subscription"), buyerPubkey.toBuffer(), creatorPubkey.toBuffer()],
+        programId
+      );
+      const subscription = await program.account.subscription.fetchNullable(subscriptionPDA);
+      if (subscription && now < subscription.expiresAt.toNumber()) {
+        const [tierPDA] = PublicKey.findProgramAddressSync(
+          [Buffer.from("tier"), creatorPubkey.toBuffer(), Buffer.from([subscription.tierId])],
+          programId
+        );
+        const tier = await program.account.subscriptionTier.fetchNullable(tierPDA);
+        hasAccess = !!tier
+          && (tier.contentIds.length === 0 || tier.contentIds.some((id) => id.eqn(contentIdNum)));
+      }
+    }
+
     if (hasAccess) {
       // User has paid, decrypt and return the IPFS CID
       const decryptedCid = decryptCID(Buffer.from(contentItem.encryptedCid).toString('hex'));
       return NextResponse.json({ ipfsCid: decryptedCid });
     } else {
-      // User has not paid, return 402 Payment Required
+      // User has not paid, return 402 Payment Required. The price is in lamports, or in base
+      // units of the payment mint for token-priced content.
+      const paymentMint = contentItem.paymentMint ? contentItem.paymentMint.toBase58() : null;
+      const assetType = assetTypeFor(contentItem.paymentMint);
       const headers = {
         'X-Payment-Required': 'true',
         'X-Content-Price': contentItem.price.toString(),
-        'X-Creator-Wallet': creatorAccount.creatorWallet.toBase58(),
+        'X-Asset-Type': assetType,
+        ...(paymentMint ? { 'X-Payment-Mint': paymentMint } : {}),
+        'X-Creator-Wallet': contentItem.creator.toBase58(),
         'X-Content-Id': contentItem.id.toString(),
       };
 
@@ -131,8 +202,9 @@ export async function GET(
           error: 'Payment Required',
           paymentDetails: {
             price: contentItem.price.toNumber(),
-            assetType: 'SOL', // Assuming SOL for now
-            creatorWalletAddress: creatorAccount.creatorWallet.toBase58(),
+            assetType,
+            paymentMint,
+            creatorWalletAddress: contentItem.creator.toBase58(),
             contentId: contentItem.id.toNumber(),
           },
         },
@@ -2,6 +2,7 @@ import { NextResponse } from 'next/server';
 import * as anchor from '@coral-xyz/anchor';
 import { Connection, PublicKey } from '@solana/web3.js';
 import IDL from '@/lib/anchor/auton_program.json';
+import { fetchReceipts } from '@/lib/legacy-receipt';
 
 const SOLANA_RPC_URL = process.env.NEXT_PUBLIC_SOLANA_RPC_URL || 'http://127.0.0.1:8899';
 const AUTON_PROGRAM_ID = process.env.NEXT_PUBLIC_AUTON_PROGRAM_ID;
@@ -34,25 +35,20 @@ export async function GET(req: Request) {
       }
     })();
 
-    // Fetch all creator accounts to map content IDs -> creator wallets
-    const creators = await program.account.creatorAccount.all();
-    const contentCreatorMap: Record<number, string> = {};
-    type CreatorAccountRow = { publicKey: PublicKey, account: any };
-    for (const ca of creators as CreatorAccountRow[]) {
-      const items = (ca.account as any).content || [];
-      const wallet = (ca.account as any).creatorWallet || (ca.account as any).creator_wallet || ca.publicKey.toBase58();
-      const walletStr = (wallet && wallet.toBase58) ? wallet.toBase58() : String(wallet);
-      for (const it of items) {
-        const idNum = (it.id && typeof it.id.toNumber === 'function') ? it.id.toNumber() : Number(it.id);
-        if (!isNaN(idNum)) contentCreatorMap[idNum] = walletStr;
-      }
-    }
-
-    // Build price map and content IDs to scan for this creator
+    // Build price map and content IDs to scan for this creator.
+    // Each content item is its own PDA at [b"content", creator, id (u64 LE)], with IDs 1..=lastContentId
     const creatorPubkey = new PublicKey(creator);
     const [creatorAccountPDA] = PublicKey.findProgramAddressSync([Buffer.from('creator'), creatorPubkey.toBuffer()], programId);
     const creatorAccount = await program.account.creatorAccount.fetch(creatorAccountPDA);
-    const contentItems = (creatorAccount.content || []).map((c: any) => ({ id: c.id.toNumber(), price: (c.price && typeof c.price.toNumber === 'function') ? c.price.toNumber() : Number(c.price) }));
+    const contentPDAs = Array.from({ length: creatorAccount.lastContentId.toNumber() }, (_, i) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('content'), creatorPubkey.toBuffer(), new anchor.BN(i + 1).toArrayLike(Buffer, 'le', 8)],
+        programId
+      )[0]
+    );
+    const contentItems = (await program.account.contentItem.fetchMultiple(contentPDAs))
+      .filter((c: any) => !!c)
+      .map((c: any) => ({ id: c.id.toNumber(), price: (c.price && typeof c.price.toNumber === 'function') ? c.price.toNumber() : Number(c.price) }));
     const priceMap: Record<number, number> = {};
     const contentIds = contentItems.map((ci: any) => {
       priceMap[ci.id] = (ci.price || 0) / anchor.web3.LAMPORTS_PER_SOL;
@@ -66,7 +62,7 @@ export async function GET(req: Request) {
     // Creator memcmp first
     try {
       const creatorFilter = [{ memcmp: { offset: 8 + 32 + 8, bytes: creator } }];
-      const receiptsByCreator = await program.account.paidAccessAccount.all(creatorFilter);
+      const receiptsByCreator = await fetchReceipts(program, creatorFilter);
       for (const r of receiptsByCreator) {
         const acc: any = r.account;
         const pub = r.publicKey.toBase58();
@@ -147,21 +143,17 @@ export async function GET(req: Request) {
       const contentFilter = [{ memcmp: { offset: 8 + 32, bytes } }];
       console.debug('creator_receipts: cid', cid, 'bytes', bytes, 'filter', contentFilter);
       try {
-        const res = await program.account.paidAccessAccount.all(contentFilter as any);
+        const res = await fetchReceipts(program, contentFilter);
         console.debug('creator_receipts: res length', res?.length, 'for cid', cid, 'example', res?.[0]?.publicKey?.toBase58?.());
         for (const r of res) {
           const pub = r.publicKey.toBase58();
           if (seen.has(pub)) continue;
           const acc: any = r.account;
-          const mappedCreator = contentCreatorMap[cid];
-          console.debug('creator_receipts: mappedCreator', mappedCreator, 'for cid', cid);
-          // If the account has a creator field, prefer that; otherwise, try simple mapping
+          // Content IDs are only unique per creator, so prefer the receipt's creator field
           const accCreator = (acc.creator && typeof acc.creator.toBase58 === 'function') ? acc.creator.toBase58() : (acc.creator || acc.creator_pubkey || acc.creatorWallet || acc.creator_wallet);
           let shouldCount = false;
           if (accCreator) {
             if (String(accCreator) === creator) shouldCount = true;
-          } else if (mappedCreator === creator) {
-            shouldCount = true;
           } else {
             // As a fallback, inspect the transaction to verify transfer destination
             const verified = await verifyReceiptPaidToCreator(pub, creator);
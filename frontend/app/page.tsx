@@ -31,6 +31,13 @@ if (!AUTON_PROGRAM_ID) {
 
 const relayerPubkey = RELAYER_PUBKEY_STR ? new PublicKey(RELAYER_PUBKEY_STR) : null;
 
+// Each content item is its own PDA at [b"content", creator, id (u64 LE)]
+const getContentPDA = (creator: PublicKey, contentId: number) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("content"), creator.toBuffer(), new anchor.BN(contentId).toArrayLike(Buffer, "le", 8)],
+    new PublicKey(AUTON_PROGRAM_ID)
+  )[0];
+
 // Re-defining types to ensure self-containment if imports fail
 type FormState = {
   title: string;
@@ -203,7 +210,15 @@ export default function CreatorWorkspace() {
     setFetchingContent(true);
     try {
       const account = await program.account.creatorAccount.fetch(creatorAccountPDA);
-      setCreatorAccountData(account);
+      // Content items live in their own PDAs, with IDs running from 1 to lastContentId
+      const contentIds = Array.from({ length: account.lastContentId.toNumber() }, (_, i) => i + 1);
+      const items = await program.account.contentItem.fetchMultiple(
+        contentIds.map((id) => getContentPDA(account.creatorWallet, id))
+      );
+      const content = items
+        .filter((item): item is NonNullable<typeof item> => !!item)
+        .map((item) => ({ ...item, creatorWalletAddress: item.creator }));
+      setCreatorAccountData({ ...account, content } as unknown as CreatorAccountData);
       
       if (account.profileCid) {
         try {
@@ -262,8 +277,10 @@ export default function CreatorWorkspace() {
         const transaction = new Transaction();
         
         let needsInit = false;
+        let nextContentId = 1;
         try {
-            await program.account.creatorAccount.fetch(creatorAccountPDA);
+            const existing = await program.account.creatorAccount.fetch(creatorAccountPDA);
+            nextContentId = existing.lastContentId.toNumber() + 1;
         } catch (e) { needsInit = true; }
 
         if (needsInit) {
@@ -282,11 +299,13 @@ export default function CreatorWorkspace() {
 
         transaction.add(
             await program.methods
-                .addContent(form.title, priceBN, encryptedCidBuffer)
+                .addContent(form.title, priceBN, encryptedCidBuffer, { buyer: {} })
                 .accounts({
                     creatorAccount: creatorAccountPDA,
+                    contentItem: getContentPDA(publicKey, nextContentId),
                     creator: publicKey,
                     payer: relayerPubkey,
+                    paymentMint: null,
                 })
                 .instruction()
         );
@@ -360,10 +379,12 @@ export default function CreatorWorkspace() {
       const { encryptedCid } = await uploadResponse.json();
 
       let currentCreatorAccount = creatorAccountData;
+      let nextContentId = 1;
       if (!currentCreatorAccount) {
         setStatus({ type: 'success', message: 'Initializing account...' });
         try {
-          await program.account.creatorAccount.fetch(creatorAccountPDA);
+          const existing = await program.account.creatorAccount.fetch(creatorAccountPDA);
+          nextContentId = existing.lastContentId.toNumber() + 1;
         } catch (e) {
             const initTx = new Transaction().add(
               await program.methods
@@ -389,6 +410,8 @@ export default function CreatorWorkspace() {
             await connection.confirmTransaction({ signature: initSignature, blockhash, lastValidBlockHeight }, 'confirmed');
             await new Promise(resolve => setTimeout(resolve, 2000)); 
         }
+      } else {
+        nextContentId = currentCreatorAccount.lastContentId.toNumber() + 1;
       }
 
       setStatus({ type: 'success', message: 'Publishing...' });
@@ -397,11 +420,13 @@ export default function CreatorWorkspace() {
 
       const addContentTx = new Transaction().add(
         await program.methods
-          .addContent(form.title, priceBN, encryptedCidBuffer)
+          .addContent(form.title, priceBN, encryptedCidBuffer, { buyer: {} })
           .accounts({
             creatorAccount: creatorAccountPDA,
+            contentItem: getContentPDA(publicKey, nextContentId),
             creator: publicKey,
             payer: publicKey, 
+            paymentMint: null,
           })
           .instruction()
       );
@@ -27,19 +27,37 @@ type ContentItem = {
   id: anchor.BN;
   title: string;
   price: anchor.BN;
-  encryptedCid: number[];
+  encryptedCid: Buffer;
+  paymentMint: PublicKey | null;
+  listed: boolean;
 };
 
 type CreatorAccountData = {
   creatorWallet: PublicKey;
   lastContentId: anchor.BN;
-  content: ContentItem[];
   profileCid?: string;
 };
 
+// Each content item lives in its own PDA at [b"content", creator, id (u64 LE)]
+const getContentPDA = (creator: PublicKey, contentId: number) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("content"), creator.toBuffer(), new anchor.BN(contentId).toArrayLike(Buffer, "le", 8)],
+    programId
+  )[0];
+
+const getSplitPDA = (creator: PublicKey, contentId: anchor.BN) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("split"), creator.toBuffer(), contentId.toArrayLike(Buffer, "le", 8)],
+    programId
+  )[0];
+
+// The split PDA that applies to all of a creator's content without one of its own
+const CREATOR_DEFAULT_SPLIT = new anchor.BN(0);
+
 type PaymentDetails = {
   price: number;
   assetType: string;
+  paymentMint: string | null;
   creatorWalletAddress: string;
   contentId: number;
 };
@@ -63,6 +81,7 @@ export default function CreatorContentPage({ creatorId }: CreatorContentPageProp
   const { connection } = useConnection();
   
   const [creatorAccount, setCreatorAccount] = useState<CreatorAccountData | null>(null);
+  const [contentItems, setContentItems] = useState<ContentItem[]>([]);
   const [creatorProfile, setCreatorProfile] = useState<CreatorProfile | null>(null);
   const [resolvedWalletAddress, setResolvedWalletAddress] = useState<string | null>(null);
   const [isUsername, setIsUsername] = useState(false);
@@ -185,12 +204,12 @@ export default function CreatorContentPage({ creatorId }: CreatorContentPageProp
     // Prevent repeated attempts
     if (autoFocused === id) return;
 
-    const item = creatorAccount.content.find((c) => (c.id as anchor.BN).toNumber() === id);
+    const item = contentItems.find((c) => c.id.toNumber() === id);
     if (item) {
       setTimeout(() => handleUnlockContent(item), 300);
       setAutoFocused(id);
     }
-  }, [searchParams, creatorAccount, autoFocused]);
+  }, [searchParams, creatorAccount, contentItems, autoFocused]);
 
   const fetchCreatorContent = async () => {
     if (!program || !creatorAccountPDA) return;
@@ -200,6 +219,15 @@ export default function CreatorContentPage({ creatorId }: CreatorContentPageProp
       const account = await program.account.creatorAccount.fetch(creatorAccountPDA);
       setCreatorAccount(account);
 
+      // Content IDs run from 1 to lastContentId; only listed items are shown
+      const contentIds = Array.from({ length: account.lastContentId.toNumber() }, (_, i) => i + 1);
+      const items = await program.account.contentItem.fetchMultiple(
+        contentIds.map((id) => getContentPDA(account.creatorWallet, id))
+      );
+      setContentItems(
+        items.filter((item): item is NonNullable<typeof item> => !!item && item.listed) as ContentItem[]
+      );
+
       if (account.profileCid) {
         try {
           const response = await fetch(`${IPFS_GATEWAY_URL}${account.profileCid}`);
@@ -229,6 +257,7 @@ export default function CreatorContentPage({ creatorId }: CreatorContentPageProp
          setError('Failed to load content.');
       }
       setCreatorAccount(null);
+      setContentItems([]);
     } finally {
       setLoading(false);
     }
@@ -271,31 +300,74 @@ export default function CreatorContentPage({ creatorId }: CreatorContentPageProp
             [Buffer.from("config")],
             program.programId
         );
-        const protocolConfig = await program.account.protocolConfig.fetch(configPDA);
-        const adminWallet = protocolConfig.adminWallet;
-
         if (!creatorAccountPDA) throw new Error('Creator account PDA not found');
+        if (contentItem.paymentMint) throw new Error('Token-priced content cannot be bought here yet.');
+
+        const [treasuryPDA] = PublicKey.findProgramAddressSync(
+            [Buffer.from("treasury")],
+            program.programId
+        );
+        const [feeOverridePDA] = PublicKey.findProgramAddressSync(
+            [Buffer.from("fee_override"), creatorPubkey!.toBuffer()],
+            program.programId
+        );
+        const [supporterStatsPDA] = PublicKey.findProgramAddressSync(
+            [Buffer.from("supporters"), creatorPubkey!.toBuffer(), PublicKey.default.toBuffer()],
+            program.programId
+        );
+        const contentSplitPDA = getSplitPDA(creatorPubkey!, contentItem.id);
+        const creatorSplitPDA = getSplitPDA(creatorPubkey!, CREATOR_DEFAULT_SPLIT);
+
+        // If the creator splits their revenue, every recipient must be passed, in the split's order
+        const revenueSplit =
+          (await program.account.revenueSplit.fetchNullable(contentSplitPDA)) ??
+          (await program.account.revenueSplit.fetchNullable(creatorSplitPDA));
+        const splitRecipients = (revenueSplit?.recipients ?? []).map((recipient) => ({
+          pubkey: recipient.wallet,
+          isWritable: true,
+          isSigner: false,
+        }));
 
         const [paidAccessPDA] = PublicKey.findProgramAddressSync(
           [
             Buffer.from("access"),
             publicKey.toBuffer(),
+            creatorPubkey!.toBuffer(),
             new anchor.BN(contentItem.id.toNumber()).toArrayLike(Buffer, "le", 8),
           ],
           program.programId
         );
 
+        // The listed price is the most the buyer agrees to pay, so a price change made
+        // meanwhile can't overcharge them
         const ix = await program.methods
-          .processPayment(new anchor.BN(contentItem.id.toNumber()))
+          .processPayment(contentItem.id, contentItem.price, null)
           .accounts({
             paidAccessAccount: paidAccessPDA,
             protocolConfig: configPDA,
             creatorAccount: creatorAccountPDA!,
+            contentItem: getContentPDA(creatorPubkey!, contentItem.id.toNumber()),
             creatorWallet: creatorPubkey!,
-            adminWallet: adminWallet,
+            treasury: treasuryPDA,
+            feeOverride: feeOverridePDA,
+            contentSplit: contentSplitPDA,
+            creatorSplit: creatorSplitPDA,
+            supporterStats: supporterStatsPDA,
             buyer: publicKey,
             systemProgram: SystemProgram.programId,
+            paymentMint: null,
+            buyerTokenAccount: null,
+            creatorTokenAccount: null,
+            treasuryTokenAccount: null,
+            tokenProgram: null,
+            memoProgram: null,
+            referrerStats: null,
+            referrer: null,
+            referrerTokenAccount: null,
+            coupon: null,
+            couponRedemption: null,
           } as any)
+          .remainingAccounts(splitRecipients)
           .instruction();
         
         const transaction = new Transaction().add(ix);
@@ -503,7 +575,7 @@ export default function CreatorContentPage({ creatorId }: CreatorContentPageProp
 
           <div className="flex flex-col items-end justify-center border-l border-dashed border-zinc-800 pl-8">
              <div className="text-right">
-                <span className="font-pixel text-4xl text-neon-green">{creatorAccount.content.length}</span>
+                <span className="font-pixel text-4xl text-neon-green">{contentItems.length}</span>
                 <p className="font-mono text-xs text-zinc-500 uppercase">DROPS_ACTIVE</p>
              </div>
           </div>
@@ -555,7 +627,7 @@ export default function CreatorContentPage({ creatorId }: CreatorContentPageProp
 
         {/* Content Grid */}
         <div className="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-6">
-          {creatorAccount.content.map((item) => {
+          {contentItems.map((item) => {
             const isUnlocked = decryptedCids.has(item.id.toNumber());
             const isProcessing = paymentProcessing.get(item.id.toNumber());
 
@@ -7,6 +7,7 @@ import { useWallet } from '@solana/wallet-adapter-react';
 import IDL from '@/lib/anchor/auton_program.json';
 import { AutonProgram } from '@/lib/anchor/auton_program';
 import { Download, ChevronDown, ChevronUp, ExternalLink } from 'lucide-react';
+import { fetchReceipts } from '@/lib/legacy-receipt';
 
 const SOLANA_RPC_URL = process.env.NEXT_PUBLIC_SOLANA_RPC_URL || 'http://127.0.0.1:8899';
 const AUTON_PROGRAM_ID = process.env.NEXT_PUBLIC_AUTON_PROGRAM_ID;
@@ -67,18 +68,8 @@ export function MyPurchases() {
       setLoading(true);
       setError(null);
       try {
-        const buyerFilter = [
-          {
-            memcmp: {
-              offset: 8,
-              bytes: publicKey.toBase58(),
-            },
-          },
-        ];
-
-        const receipts = await program.account.paidAccessAccount.all(buyerFilter);
+        const receipts = await fetchReceipts(program, [{ memcmp: { offset: 8, bytes: publicKey.toBase58() } }]);
         const resolved: PurchaseItem[] = [];
-        const creators = await program.account.creatorAccount.all();
 
         for (const receipt of receipts) {
           const contentId =
@@ -93,31 +84,24 @@ export function MyPurchases() {
           let creatorAddr = '';
 
           try {
-            for (const ca of creators) {
-              const items = (ca.account as any).content || [];
-              const match = items.find((it: any) => {
-                const id = it.id?.toNumber?.() || Number(it.id);
-                return id === contentId;
-              });
-              if (match) {
-                const creatorWallet = (ca.account as any).creatorWallet || (ca.account as any).creator_wallet;
-                creatorAddr = creatorWallet?.toBase58?.() || String(creatorWallet) || ca.publicKey.toBase58();
-
-                const encrypted = match.encrypted_cid || match.encryptedCid || match.encryptedCid?.toString?.() || match.encrypted_cid?.toString?.();
-                if (encrypted) {
-                  try {
-                    if (typeof encrypted === 'string') ipfsCid = encrypted;
-                    else if (Buffer.isBuffer(encrypted)) ipfsCid = encrypted.toString('utf8');
-                    else ipfsCid = String(encrypted);
-                  } catch (e) {
-                    ipfsCid = String(encrypted);
-                  }
-                }
-                break;
+            // The receipt names the creator; the content item is the PDA [b"content", creator, id (u64 LE)]
+            const creatorWallet = receipt.account.creator;
+            creatorAddr = creatorWallet.toBase58();
+            const [contentPDA] = PublicKey.findProgramAddressSync(
+              [Buffer.from('content'), creatorWallet.toBuffer(), new anchor.BN(contentId).toArrayLike(Buffer, 'le', 8)],
+              program.programId
+            );
+            const match = await program.account.contentItem.fetchNullable(contentPDA);
+            if (match) {
+              const encrypted = match.encryptedCid;
+              try {
+                ipfsCid = Buffer.isBuffer(encrypted) ? encrypted.toString('utf8') : String(encrypted);
+              } catch (e) {
+                ipfsCid = String(encrypted);
               }
             }
           } catch (err) {
-            console.debug(`Error searching creator accounts for contentId ${contentId}:`, err);
+            console.debug(`Error fetching content item ${contentId}:`, err);
           }
 
           resolved.push({
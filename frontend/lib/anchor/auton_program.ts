@@ -13,6 +13,45 @@ export type AutonProgram = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "acceptAdmin",
+      "discriminator": [
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "newAdmin",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
     {
       "name": "addContent",
       "discriminator": [
@@ -50,6 +89,28 @@ export type AutonProgram = {
             ]
           }
         },
+        {
+          "name": "contentItem",
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "creator",
           "writable": true,
@@ -60,6 +121,10 @@ export type AutonProgram = {
           "writable": true,
           "signer": true
         },
+        {
+          "name": "paymentMint",
+          "optional": true
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -77,20 +142,28 @@ export type AutonProgram = {
         {
           "name": "encryptedCid",
           "type": "bytes"
+        },
+        {
+          "name": "transferFeePayer",
+          "type": {
+            "defined": {
+              "name": "transferFeePayer"
+            }
+          }
         }
       ]
     },
     {
-      "name": "initializeConfig",
+      "name": "cancelAdminProposal",
       "discriminator": [
-        208,
-        127,
-        21,
-        1,
-        194,
-        190,
-        196,
-        70
+        68,
+        6,
+        145,
+        131,
+        16,
+        73,
+        182,
+        229
       ],
       "accounts": [
         {
@@ -116,34 +189,78 @@ export type AutonProgram = {
           "name": "admin",
           "writable": true,
           "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeBundle",
+      "discriminator": [
+        102,
+        24,
+        15,
+        14,
+        127,
+        75,
+        214,
+        155
+      ],
+      "accounts": [
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "bundleId"
+              }
+            ]
+          }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "creator",
+          "writable": true,
+          "signer": true
         }
       ],
       "args": [
         {
-          "name": "initialFeePercentage",
-          "type": "u64"
+          "name": "bundleId",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "initializeCreator",
+      "name": "closeCoupon",
       "discriminator": [
-        29,
-        153,
-        44,
-        99,
-        52,
-        172,
-        81,
-        115
+        145,
+        129,
+        128,
+        62,
+        140,
+        121,
+        104,
+        203
       ],
       "accounts": [
         {
-          "name": "creatorAccount",
+          "name": "coupon",
           "writable": true,
           "pda": {
             "seeds": [
@@ -151,17 +268,20 @@ export type AutonProgram = {
                 "kind": "const",
                 "value": [
                   99,
-                  114,
-                  101,
-                  97,
-                  116,
                   111,
-                  114
+                  117,
+                  112,
+                  111,
+                  110
                 ]
               },
               {
                 "kind": "account",
                 "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "code"
               }
             ]
           }
@@ -170,55 +290,53 @@ export type AutonProgram = {
           "name": "creator",
           "writable": true,
           "signer": true
-        },
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "code",
+          "type": "string"
+        }
+      ]
     },
     {
-      "name": "processPayment",
+      "name": "closeFeeOverride",
       "discriminator": [
-        189,
-        81,
-        30,
-        198,
-        139,
-        186,
-        115,
-        23
+        59,
+        240,
+        182,
+        119,
+        99,
+        4,
+        208,
+        8
       ],
       "accounts": [
         {
-          "name": "paidAccessAccount",
+          "name": "feeOverride",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  99,
-                  99,
+                  102,
                   101,
-                  115,
-                  115
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
                 ]
               },
-              {
-                "kind": "account",
-                "path": "buyer"
-              },
               {
                 "kind": "arg",
-                "path": "contentId"
+                "path": "creator"
               }
             ]
           }
@@ -242,68 +360,53 @@ export type AutonProgram = {
           }
         },
         {
-          "name": "creatorAccount",
-          "writable": true
-        },
-        {
-          "name": "creatorWallet",
-          "writable": true
-        },
-        {
-          "name": "adminWallet",
-          "writable": true
-        },
-        {
-          "name": "buyer",
+          "name": "admin",
           "writable": true,
           "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "contentId",
-          "type": "u64"
+          "name": "creator",
+          "type": "pubkey"
         }
       ]
     },
     {
-      "name": "registerUsername",
+      "name": "closeRevenueSplit",
       "discriminator": [
-        134,
-        54,
-        123,
-        181,
-        28,
-        151,
-        36,
-        0
+        199,
+        101,
+        220,
+        65,
+        13,
+        20,
+        159,
+        139
       ],
       "accounts": [
         {
-          "name": "usernameAccount",
+          "name": "revenueSplit",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
                   115,
-                  101,
-                  114,
-                  110,
-                  97,
-                  109,
-                  101
+                  112,
+                  108,
+                  105,
+                  116
                 ]
               },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
               {
                 "kind": "arg",
-                "path": "username"
+                "path": "contentId"
               }
             ]
           }
@@ -312,92 +415,142 @@ export type AutonProgram = {
           "name": "creator",
           "writable": true,
           "signer": true
-        },
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "username",
-          "type": "string"
+          "name": "contentId",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "updateConfig",
+      "name": "createBundle",
       "discriminator": [
-        29,
-        158,
-        252,
-        191,
-        10,
-        83,
-        219,
-        99
+        108,
+        43,
+        176,
+        128,
+        45,
+        94,
+        197,
+        95
       ],
       "accounts": [
         {
-          "name": "protocolConfig",
+          "name": "bundle",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  111,
+                  98,
+                  117,
                   110,
-                  102,
-                  105,
-                  103
+                  100,
+                  108,
+                  101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "bundleId"
               }
             ]
           }
         },
         {
-          "name": "admin",
-          "writable": true,
-          "signer": true
+          "name": "creatorAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paymentMint",
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "newAdminWallet",
+          "name": "bundleId",
+          "type": "u8"
+        },
+        {
+          "name": "contentIds",
           "type": {
-            "option": "pubkey"
+            "vec": "u64"
           }
         },
         {
-          "name": "newFeePercentage",
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "discountBps",
           "type": {
             "option": "u64"
           }
+        },
+        {
+          "name": "transferFeePayer",
+          "type": {
+            "defined": {
+              "name": "transferFeePayer"
+            }
+          }
         }
       ]
     },
     {
-      "name": "updateProfile",
+      "name": "createCoupon",
       "discriminator": [
-        98,
-        67,
-        99,
-        206,
-        86,
-        115,
-        175,
-        1
+        29,
+        170,
+        159,
+        88,
+        211,
+        20,
+        13,
+        56
       ],
       "accounts": [
         {
-          "name": "creatorAccount",
+          "name": "coupon",
           "writable": true,
           "pda": {
             "seeds": [
@@ -405,26 +558,37 @@ export type AutonProgram = {
                 "kind": "const",
                 "value": [
                   99,
-                  114,
-                  101,
-                  97,
-                  116,
                   111,
-                  114
+                  117,
+                  112,
+                  111,
+                  110
                 ]
               },
               {
                 "kind": "account",
                 "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "code"
               }
             ]
           }
         },
         {
           "name": "creator",
+          "signer": true
+        },
+        {
+          "name": "payer",
           "writable": true,
           "signer": true
         },
+        {
+          "name": "paymentMint",
+          "optional": true
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -432,179 +596,6944 @@ export type AutonProgram = {
       ],
       "args": [
         {
-          "name": "profileCid",
+          "name": "code",
           "type": "string"
+        },
+        {
+          "name": "discount",
+          "type": {
+            "defined": {
+              "name": "couponDiscount"
+            }
+          }
+        },
+        {
+          "name": "maxRedemptions",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "maxPerBuyer",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          }
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "creatorAccount",
+      "name": "createCouponRedemption",
       "discriminator": [
-        222,
-        163,
-        32,
-        169,
-        204,
-        8,
-        200,
-        32
-      ]
+        175,
+        61,
+        27,
+        240,
+        102,
+        1,
+        133,
+        132
+      ],
+      "accounts": [
+        {
+          "name": "couponRedemption",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  117,
+                  112,
+                  111,
+                  110,
+                  95,
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "coupon"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "coupon"
+        },
+        {
+          "name": "buyer",
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "paidAccessAccount",
+      "name": "createFeeOverride",
       "discriminator": [
-        231,
-        188,
+        16,
+        238,
+        51,
+        215,
         255,
-        33,
-        153,
-        205,
-        111,
-        44
+        171,
+        121,
+        126
+      ],
+      "accounts": [
+        {
+          "name": "feeOverride",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "creator",
+          "type": "pubkey"
+        },
+        {
+          "name": "feeBps",
+          "type": "u64"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          }
+        }
       ]
     },
     {
-      "name": "protocolConfig",
+      "name": "createRevenueSplit",
       "discriminator": [
-        207,
-        91,
-        250,
-        28,
-        152,
-        179,
-        215,
-        209
-      ]
+        244,
+        232,
+        94,
+        167,
+        99,
+        4,
+        39,
+        96
+      ],
+      "accounts": [
+        {
+          "name": "revenueSplit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  108,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        },
+        {
+          "name": "recipients",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "splitRecipient"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createSubscriptionTier",
+      "discriminator": [
+        17,
+        201,
+        236,
+        180,
+        89,
+        127,
+        237,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "subscriptionTier",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "tierId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "paymentMint",
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tierId",
+          "type": "u8"
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "periodSeconds",
+          "type": "i64"
+        },
+        {
+          "name": "contentIds",
+          "type": {
+            "vec": "u64"
+          }
+        },
+        {
+          "name": "transferFeePayer",
+          "type": {
+            "defined": {
+              "name": "transferFeePayer"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createSupporterStats",
+      "discriminator": [
+        200,
+        196,
+        6,
+        175,
+        165,
+        51,
+        59,
+        244
+      ],
+      "accounts": [
+        {
+          "name": "supporterStats",
+          "writable": true
+        },
+        {
+          "name": "creator",
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "paymentMint",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeConfig",
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "initialFeePercentage",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeCreator",
+      "discriminator": [
+        29,
+        153,
+        44,
+        99,
+        52,
+        172,
+        81,
+        115
+      ],
+      "accounts": [
+        {
+          "name": "creatorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateConfig",
+      "discriminator": [
+        92,
+        131,
+        58,
+        105,
+        210,
+        154,
+        224,
+        193
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateCreatorContent",
+      "discriminator": [
+        45,
+        221,
+        86,
+        83,
+        76,
+        154,
+        15,
+        31
+      ],
+      "accounts": [
+        {
+          "name": "creatorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateReceipt",
+      "discriminator": [
+        58,
+        241,
+        190,
+        80,
+        230,
+        70,
+        189,
+        255
+      ],
+      "accounts": [
+        {
+          "name": "legacyAccessAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "paidAccessAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "arg",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        },
+        {
+          "name": "creator",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "processPayment",
+      "discriminator": [
+        189,
+        81,
+        30,
+        198,
+        139,
+        186,
+        115,
+        23
+      ],
+      "accounts": [
+        {
+          "name": "paidAccessAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "account",
+                "path": "creatorAccount.creatorWallet",
+                "account": "creatorAccount"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorAccount",
+          "writable": true
+        },
+        {
+          "name": "contentItem",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creatorAccount.creatorWallet",
+                "account": "creatorAccount"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorWallet",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeOverride",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creatorAccount.creatorWallet",
+                "account": "creatorAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "contentSplit",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  108,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creatorAccount.creatorWallet",
+                "account": "creatorAccount"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorSplit",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  108,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creatorAccount.creatorWallet",
+                "account": "creatorAccount"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "supporterStats",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "paymentMint",
+          "optional": true
+        },
+        {
+          "name": "buyerTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        },
+        {
+          "name": "referrerStats",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referrer",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referrerTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "coupon",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "couponRedemption",
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        },
+        {
+          "name": "maxFeeBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAdmin",
+      "discriminator": [
+        121,
+        214,
+        199,
+        212,
+        87,
+        39,
+        117,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "purchaseBundle",
+      "discriminator": [
+        76,
+        60,
+        192,
+        10,
+        119,
+        47,
+        5,
+        32
+      ],
+      "accounts": [
+        {
+          "name": "bundleReceipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101,
+                  95,
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "account",
+                "path": "bundle.creator",
+                "account": "bundle"
+              },
+              {
+                "kind": "arg",
+                "path": "bundleId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bundle",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.creator",
+                "account": "bundle"
+              },
+              {
+                "kind": "arg",
+                "path": "bundleId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.creator",
+                "account": "bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorWallet",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeOverride",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.creator",
+                "account": "bundle"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorSplit",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  108,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bundle.creator",
+                "account": "bundle"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "paymentMint",
+          "optional": true
+        },
+        {
+          "name": "buyerTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        }
+      ],
+      "args": [
+        {
+          "name": "bundleId",
+          "type": "u8"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        },
+        {
+          "name": "maxFeeBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "registerReferrer",
+      "discriminator": [
+        122,
+        229,
+        215,
+        169,
+        100,
+        145,
+        198,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "referrerStats",
+          "writable": true
+        },
+        {
+          "name": "referrer",
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "paymentMint",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "registerUsername",
+      "discriminator": [
+        134,
+        54,
+        123,
+        181,
+        28,
+        151,
+        36,
+        0
+      ],
+      "accounts": [
+        {
+          "name": "usernameAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  110,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "username"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "username",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "relistContent",
+      "discriminator": [
+        242,
+        12,
+        56,
+        157,
+        25,
+        57,
+        113,
+        223
+      ],
+      "accounts": [
+        {
+          "name": "contentItem",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          },
+          "relations": [
+            "creator"
+          ]
+        },
+        {
+          "name": "creator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "renewRental",
+      "discriminator": [
+        108,
+        208,
+        165,
+        46,
+        76,
+        135,
+        42,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "paidAccessAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "account",
+                "path": "contentItem.creator",
+                "account": "contentItem"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contentItem.creator",
+                "account": "contentItem"
+              }
+            ]
+          }
+        },
+        {
+          "name": "contentItem",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contentItem.creator",
+                "account": "contentItem"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorWallet",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeOverride",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contentItem.creator",
+                "account": "contentItem"
+              }
+            ]
+          }
+        },
+        {
+          "name": "contentSplit",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  108,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contentItem.creator",
+                "account": "contentItem"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorSplit",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  108,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contentItem.creator",
+                "account": "contentItem"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "paymentMint",
+          "optional": true
+        },
+        {
+          "name": "buyerTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        },
+        {
+          "name": "maxFeeBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "renewSubscription",
+      "discriminator": [
+        45,
+        75,
+        154,
+        194,
+        160,
+        10,
+        111,
+        183
+      ],
+      "accounts": [
+        {
+          "name": "subscription",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  115,
+                  99,
+                  114,
+                  105,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subscriber"
+              },
+              {
+                "kind": "account",
+                "path": "subscriptionTier.creator",
+                "account": "subscriptionTier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subscriptionTier",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subscriptionTier.creator",
+                "account": "subscriptionTier"
+              },
+              {
+                "kind": "arg",
+                "path": "tierId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subscriptionTier.creator",
+                "account": "subscriptionTier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorWallet",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeOverride",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subscriptionTier.creator",
+                "account": "subscriptionTier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subscriber",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "paymentMint",
+          "optional": true
+        },
+        {
+          "name": "subscriberTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        }
+      ],
+      "args": [
+        {
+          "name": "tierId",
+          "type": "u8"
+        },
+        {
+          "name": "periods",
+          "type": "u32"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        },
+        {
+          "name": "maxFeeBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "rentContent",
+      "discriminator": [
+        93,
+        94,
+        111,
+        198,
+        91,
+        78,
+        137,
+        250
+      ],
+      "accounts": [
+        {
+          "name": "paidAccessAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  99,
+                  99,
+                  101,
+                  115,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "account",
+                "path": "contentItem.creator",
+                "account": "contentItem"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contentItem.creator",
+                "account": "contentItem"
+              }
+            ]
+          }
+        },
+        {
+          "name": "contentItem",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contentItem.creator",
+                "account": "contentItem"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorWallet",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeOverride",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contentItem.creator",
+                "account": "contentItem"
+              }
+            ]
+          }
+        },
+        {
+          "name": "contentSplit",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  108,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contentItem.creator",
+                "account": "contentItem"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorSplit",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  108,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contentItem.creator",
+                "account": "contentItem"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "paymentMint",
+          "optional": true
+        },
+        {
+          "name": "buyerTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        },
+        {
+          "name": "maxFeeBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setAffiliateShare",
+      "discriminator": [
+        36,
+        34,
+        4,
+        122,
+        246,
+        150,
+        236,
+        176
+      ],
+      "accounts": [
+        {
+          "name": "creatorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "affiliateBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setContentAffiliateShare",
+      "discriminator": [
+        18,
+        45,
+        186,
+        236,
+        220,
+        175,
+        192,
+        104
+      ],
+      "accounts": [
+        {
+          "name": "contentItem",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          },
+          "relations": [
+            "creator"
+          ]
+        },
+        {
+          "name": "creator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        },
+        {
+          "name": "affiliateBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFeeRecipient",
+      "discriminator": [
+        227,
+        18,
+        215,
+        42,
+        237,
+        246,
+        151,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeRecipient",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newFeeRecipient",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setFeeTiers",
+      "discriminator": [
+        162,
+        35,
+        72,
+        250,
+        39,
+        183,
+        30,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "feeTiers",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "feeTier"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setGovernance",
+      "discriminator": [
+        34,
+        71,
+        128,
+        245,
+        179,
+        42,
+        140,
+        137
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governance",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newGovernance",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setGuardian",
+      "discriminator": [
+        147,
+        243,
+        50,
+        121,
+        154,
+        164,
+        50,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newGuardian",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setMaxFeePercentage",
+      "discriminator": [
+        247,
+        33,
+        40,
+        118,
+        19,
+        198,
+        119,
+        232
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newMaxFeePercentage",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setMaxSupply",
+      "discriminator": [
+        16,
+        207,
+        140,
+        77,
+        107,
+        20,
+        202,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "contentItem",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          },
+          "relations": [
+            "creator"
+          ]
+        },
+        {
+          "name": "creator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        },
+        {
+          "name": "maxSupply",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "discriminator": [
+        91,
+        60,
+        125,
+        192,
+        176,
+        225,
+        166,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setPayWhatYouWant",
+      "discriminator": [
+        232,
+        23,
+        125,
+        198,
+        25,
+        14,
+        72,
+        123
+      ],
+      "accounts": [
+        {
+          "name": "contentItem",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          },
+          "relations": [
+            "creator"
+          ]
+        },
+        {
+          "name": "supporterStats"
+        },
+        {
+          "name": "creator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        },
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setRentalTerms",
+      "discriminator": [
+        248,
+        210,
+        223,
+        204,
+        18,
+        152,
+        118,
+        33
+      ],
+      "accounts": [
+        {
+          "name": "contentItem",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          },
+          "relations": [
+            "creator"
+          ]
+        },
+        {
+          "name": "creator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        },
+        {
+          "name": "rental",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "rentalTerms"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "subscribe",
+      "discriminator": [
+        254,
+        28,
+        191,
+        138,
+        156,
+        179,
+        183,
+        53
+      ],
+      "accounts": [
+        {
+          "name": "subscription",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  115,
+                  99,
+                  114,
+                  105,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subscriber"
+              },
+              {
+                "kind": "account",
+                "path": "subscriptionTier.creator",
+                "account": "subscriptionTier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subscriptionTier",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subscriptionTier.creator",
+                "account": "subscriptionTier"
+              },
+              {
+                "kind": "arg",
+                "path": "tierId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subscriptionTier.creator",
+                "account": "subscriptionTier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorWallet",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeOverride",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subscriptionTier.creator",
+                "account": "subscriptionTier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subscriber",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "paymentMint",
+          "optional": true
+        },
+        {
+          "name": "subscriberTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        }
+      ],
+      "args": [
+        {
+          "name": "tierId",
+          "type": "u8"
+        },
+        {
+          "name": "periods",
+          "type": "u32"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        },
+        {
+          "name": "maxFeeBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "tipCreator",
+      "discriminator": [
+        48,
+        126,
+        181,
+        9,
+        20,
+        187,
+        187,
+        133
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorAccount"
+        },
+        {
+          "name": "creatorWallet",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeOverride",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creatorAccount.creatorWallet",
+                "account": "creatorAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "supporterStats",
+          "writable": true
+        },
+        {
+          "name": "tipper",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "paymentMint",
+          "optional": true
+        },
+        {
+          "name": "tipperTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFeeBps",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "message",
+          "type": {
+            "option": "string"
+          }
+        }
+      ]
+    },
+    {
+      "name": "unlistContent",
+      "discriminator": [
+        140,
+        22,
+        30,
+        2,
+        68,
+        23,
+        91,
+        204
+      ],
+      "accounts": [
+        {
+          "name": "contentItem",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          },
+          "relations": [
+            "creator"
+          ]
+        },
+        {
+          "name": "creator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateBundle",
+      "discriminator": [
+        243,
+        102,
+        200,
+        60,
+        41,
+        167,
+        95,
+        146
+      ],
+      "accounts": [
+        {
+          "name": "bundle",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  117,
+                  110,
+                  100,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "bundleId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bundleId",
+          "type": "u8"
+        },
+        {
+          "name": "contentIds",
+          "type": {
+            "vec": "u64"
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "discountBps",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newFeePercentage",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "newFeeRounding",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "feeRounding"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateContent",
+      "discriminator": [
+        201,
+        145,
+        238,
+        112,
+        36,
+        231,
+        69,
+        8
+      ],
+      "accounts": [
+        {
+          "name": "contentItem",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          },
+          "relations": [
+            "creator"
+          ]
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        },
+        {
+          "name": "title",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "price",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "encryptedCid",
+          "type": {
+            "option": "bytes"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFeeOverride",
+      "discriminator": [
+        159,
+        148,
+        89,
+        203,
+        208,
+        182,
+        143,
+        77
+      ],
+      "accounts": [
+        {
+          "name": "feeOverride",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "creator",
+          "type": "pubkey"
+        },
+        {
+          "name": "feeBps",
+          "type": "u64"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateProfile",
+      "discriminator": [
+        98,
+        67,
+        99,
+        206,
+        86,
+        115,
+        175,
+        1
+      ],
+      "accounts": [
+        {
+          "name": "creatorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "profileCid",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "updateRevenueSplit",
+      "discriminator": [
+        149,
+        137,
+        197,
+        166,
+        71,
+        142,
+        16,
+        147
+      ],
+      "accounts": [
+        {
+          "name": "revenueSplit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  108,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "contentId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "contentId",
+          "type": "u64"
+        },
+        {
+          "name": "recipients",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "splitRecipient"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateSubscriptionTier",
+      "discriminator": [
+        102,
+        59,
+        50,
+        253,
+        172,
+        250,
+        159,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "subscriptionTier",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "tierId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "tierId",
+          "type": "u8"
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "periodSeconds",
+          "type": "i64"
+        },
+        {
+          "name": "contentIds",
+          "type": {
+            "vec": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawTreasury",
+      "discriminator": [
+        40,
+        63,
+        122,
+        158,
+        144,
+        216,
+        83,
+        96
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeRecipient",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "paymentMint",
+          "optional": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "recipientTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "memoProgram",
+          "optional": true,
+          "address": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "bundle",
+      "discriminator": [
+        15,
+        82,
+        167,
+        230,
+        37,
+        214,
+        82,
+        80
+      ]
+    },
+    {
+      "name": "bundleReceipt",
+      "discriminator": [
+        72,
+        233,
+        211,
+        189,
+        68,
+        91,
+        203,
+        101
+      ]
+    },
+    {
+      "name": "contentItem",
+      "discriminator": [
+        19,
+        15,
+        150,
+        108,
+        236,
+        150,
+        85,
+        109
+      ]
+    },
+    {
+      "name": "coupon",
+      "discriminator": [
+        24,
+        230,
+        224,
+        210,
+        200,
+        206,
+        79,
+        57
+      ]
+    },
+    {
+      "name": "couponRedemption",
+      "discriminator": [
+        202,
+        2,
+        229,
+        196,
+        155,
+        205,
+        249,
+        115
+      ]
+    },
+    {
+      "name": "creatorAccount",
+      "discriminator": [
+        222,
+        163,
+        32,
+        169,
+        204,
+        8,
+        200,
+        32
+      ]
+    },
+    {
+      "name": "feeOverride",
+      "discriminator": [
+        45,
+        33,
+        41,
+        248,
+        253,
+        236,
+        239,
+        85
+      ]
+    },
+    {
+      "name": "paidAccessAccount",
+      "discriminator": [
+        231,
+        188,
+        255,
+        33,
+        153,
+        205,
+        111,
+        44
+      ]
+    },
+    {
+      "name": "protocolConfig",
+      "discriminator": [
+        207,
+        91,
+        250,
+        28,
+        152,
+        179,
+        215,
+        209
+      ]
+    },
+    {
+      "name": "referrerStats",
+      "discriminator": [
+        181,
+        235,
+        242,
+        229,
+        103,
+        242,
+        144,
+        118
+      ]
+    },
+    {
+      "name": "revenueSplit",
+      "discriminator": [
+        119,
+        154,
+        208,
+        62,
+        69,
+        109,
+        27,
+        144
+      ]
+    },
+    {
+      "name": "subscription",
+      "discriminator": [
+        64,
+        7,
+        26,
+        135,
+        102,
+        132,
+        98,
+        33
+      ]
+    },
+    {
+      "name": "subscriptionTier",
+      "discriminator": [
+        137,
+        112,
+        75,
+        38,
+        237,
+        16,
+        69,
+        210
+      ]
+    },
+    {
+      "name": "supporterStats",
+      "discriminator": [
+        195,
+        201,
+        78,
+        124,
+        250,
+        12,
+        83,
+        18
+      ]
+    },
+    {
+      "name": "treasury",
+      "discriminator": [
+        238,
+        239,
+        123,
+        238,
+        89,
+        1,
+        168,
+        253
+      ]
+    },
+    {
+      "name": "usernameAccount",
+      "discriminator": [
+        120,
+        2,
+        212,
+        44,
+        208,
+        63,
+        20,
+        122
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "adminProposalCancelled",
+      "discriminator": [
+        158,
+        7,
+        69,
+        243,
+        15,
+        126,
+        0,
+        184
+      ]
+    },
+    {
+      "name": "adminProposed",
+      "discriminator": [
+        129,
+        249,
+        226,
+        227,
+        199,
+        82,
+        110,
+        243
+      ]
+    },
+    {
+      "name": "adminTransferred",
+      "discriminator": [
+        255,
+        147,
+        182,
+        5,
+        199,
+        217,
+        38,
+        179
+      ]
+    },
+    {
+      "name": "affiliateShareUpdated",
+      "discriminator": [
+        96,
+        102,
+        146,
+        140,
+        61,
+        58,
+        63,
+        18
+      ]
+    },
+    {
+      "name": "bundlePurchased",
+      "discriminator": [
+        34,
+        101,
+        111,
+        95,
+        241,
+        5,
+        13,
+        15
+      ]
+    },
+    {
+      "name": "bundleRemoved",
+      "discriminator": [
+        61,
+        211,
+        26,
+        157,
+        171,
+        134,
+        206,
+        163
+      ]
+    },
+    {
+      "name": "bundleSet",
+      "discriminator": [
+        243,
+        143,
+        12,
+        52,
+        253,
+        70,
+        54,
+        21
+      ]
+    },
+    {
+      "name": "configInitialized",
+      "discriminator": [
+        181,
+        49,
+        200,
+        156,
+        19,
+        167,
+        178,
+        91
+      ]
+    },
+    {
+      "name": "configMigrated",
+      "discriminator": [
+        115,
+        69,
+        99,
+        100,
+        192,
+        77,
+        40,
+        50
+      ]
+    },
+    {
+      "name": "configUpdated",
+      "discriminator": [
+        40,
+        241,
+        230,
+        122,
+        11,
+        19,
+        198,
+        194
+      ]
+    },
+    {
+      "name": "contentAdded",
+      "discriminator": [
+        2,
+        8,
+        195,
+        62,
+        212,
+        6,
+        60,
+        31
+      ]
+    },
+    {
+      "name": "contentListingChanged",
+      "discriminator": [
+        219,
+        217,
+        163,
+        36,
+        0,
+        212,
+        66,
+        165
+      ]
+    },
+    {
+      "name": "contentPurchased",
+      "discriminator": [
+        169,
+        15,
+        77,
+        175,
+        180,
+        109,
+        24,
+        168
+      ]
+    },
+    {
+      "name": "contentRented",
+      "discriminator": [
+        104,
+        60,
+        108,
+        86,
+        78,
+        101,
+        75,
+        22
+      ]
+    },
+    {
+      "name": "contentUpdated",
+      "discriminator": [
+        24,
+        63,
+        46,
+        115,
+        240,
+        169,
+        95,
+        241
+      ]
+    },
+    {
+      "name": "couponCreated",
+      "discriminator": [
+        11,
+        158,
+        13,
+        126,
+        64,
+        79,
+        194,
+        48
+      ]
+    },
+    {
+      "name": "couponRemoved",
+      "discriminator": [
+        89,
+        235,
+        155,
+        60,
+        148,
+        106,
+        186,
+        91
+      ]
+    },
+    {
+      "name": "creatorContentMigrated",
+      "discriminator": [
+        141,
+        8,
+        216,
+        103,
+        168,
+        188,
+        253,
+        234
+      ]
+    },
+    {
+      "name": "creatorInitialized",
+      "discriminator": [
+        154,
+        186,
+        158,
+        218,
+        144,
+        59,
+        57,
+        77
+      ]
+    },
+    {
+      "name": "creatorTipped",
+      "discriminator": [
+        66,
+        44,
+        75,
+        55,
+        8,
+        161,
+        232,
+        143
+      ]
+    },
+    {
+      "name": "feeOverrideRemoved",
+      "discriminator": [
+        4,
+        59,
+        160,
+        233,
+        13,
+        11,
+        21,
+        137
+      ]
+    },
+    {
+      "name": "feeOverrideSet",
+      "discriminator": [
+        93,
+        117,
+        225,
+        31,
+        184,
+        115,
+        83,
+        4
+      ]
+    },
+    {
+      "name": "feeRecipientUpdated",
+      "discriminator": [
+        24,
+        150,
+        233,
+        92,
+        169,
+        221,
+        233,
+        244
+      ]
+    },
+    {
+      "name": "feeTiersUpdated",
+      "discriminator": [
+        172,
+        186,
+        57,
+        25,
+        74,
+        242,
+        109,
+        42
+      ]
+    },
+    {
+      "name": "governanceUpdated",
+      "discriminator": [
+        242,
+        176,
+        180,
+        4,
+        237,
+        220,
+        12,
+        2
+      ]
+    },
+    {
+      "name": "guardianUpdated",
+      "discriminator": [
+        31,
+        95,
+        81,
+        24,
+        90,
+        9,
+        246,
+        32
+      ]
+    },
+    {
+      "name": "maxFeePercentageUpdated",
+      "discriminator": [
+        220,
+        52,
+        170,
+        248,
+        191,
+        202,
+        247,
+        52
+      ]
+    },
+    {
+      "name": "maxSupplySet",
+      "discriminator": [
+        196,
+        180,
+        44,
+        116,
+        157,
+        8,
+        158,
+        230
+      ]
+    },
+    {
+      "name": "pauseUpdated",
+      "discriminator": [
+        203,
+        203,
+        33,
+        225,
+        130,
+        103,
+        90,
+        105
+      ]
+    },
+    {
+      "name": "payWhatYouWantChanged",
+      "discriminator": [
+        88,
+        187,
+        175,
+        1,
+        118,
+        47,
+        218,
+        57
+      ]
+    },
+    {
+      "name": "profileUpdated",
+      "discriminator": [
+        186,
+        248,
+        62,
+        98,
+        112,
+        98,
+        161,
+        252
+      ]
+    },
+    {
+      "name": "receiptMigrated",
+      "discriminator": [
+        39,
+        0,
+        47,
+        178,
+        97,
+        199,
+        157,
+        21
+      ]
+    },
+    {
+      "name": "referrerRegistered",
+      "discriminator": [
+        106,
+        198,
+        28,
+        51,
+        115,
+        46,
+        57,
+        3
+      ]
+    },
+    {
+      "name": "rentalTermsChanged",
+      "discriminator": [
+        100,
+        37,
+        74,
+        84,
+        65,
+        180,
+        29,
+        151
+      ]
+    },
+    {
+      "name": "revenueSplitRemoved",
+      "discriminator": [
+        14,
+        179,
+        153,
+        85,
+        234,
+        38,
+        42,
+        7
+      ]
+    },
+    {
+      "name": "revenueSplitSet",
+      "discriminator": [
+        17,
+        149,
+        225,
+        85,
+        60,
+        75,
+        173,
+        230
+      ]
+    },
+    {
+      "name": "subscriptionPaid",
+      "discriminator": [
+        204,
+        65,
+        145,
+        54,
+        154,
+        163,
+        113,
+        229
+      ]
+    },
+    {
+      "name": "subscriptionTierCreated",
+      "discriminator": [
+        135,
+        182,
+        109,
+        182,
+        216,
+        225,
+        173,
+        248
+      ]
+    },
+    {
+      "name": "subscriptionTierUpdated",
+      "discriminator": [
+        156,
+        245,
+        69,
+        34,
+        195,
+        58,
+        107,
+        195
+      ]
+    },
+    {
+      "name": "supporterStatsCreated",
+      "discriminator": [
+        158,
+        120,
+        244,
+        156,
+        217,
+        70,
+        87,
+        83
+      ]
+    },
+    {
+      "name": "treasuryWithdrawn",
+      "discriminator": [
+        143,
+        181,
+        157,
+        169,
+        87,
+        155,
+        170,
+        46
+      ]
+    },
+    {
+      "name": "usernameRegistered",
+      "discriminator": [
+        241,
+        79,
+        103,
+        207,
+        185,
+        19,
+        151,
+        5
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "unauthorized",
+      "msg": "You are not authorized to perform this action."
+    },
+    {
+      "code": 6001,
+      "name": "contentNotFound",
+      "msg": "The specified content was not found in the creator's account."
+    },
+    {
+      "code": 6002,
+      "name": "invalidUsername",
+      "msg": "Invalid username. Must be 3-32 characters, alphanumeric or underscore only."
+    },
+    {
+      "code": 6003,
+      "name": "invalidFeePercentage",
+      "msg": "Invalid fee percentage. Must be <= 10000 (100%)."
+    },
+    {
+      "code": 6004,
+      "name": "paymentMintMismatch",
+      "msg": "The payment mint does not match the mint the content is priced in."
+    },
+    {
+      "code": 6005,
+      "name": "missingTokenAccounts",
+      "msg": "Token-priced content requires the mint, token accounts and token program."
+    },
+    {
+      "code": 6006,
+      "name": "invalidTokenAccount",
+      "msg": "A token account does not belong to the expected wallet."
+    },
+    {
+      "code": 6007,
+      "name": "mathOverflow",
+      "msg": "Arithmetic overflow while computing payment amounts."
+    },
+    {
+      "code": 6008,
+      "name": "memoProgramRequired",
+      "msg": "A receiving token account requires a memo; pass the memo program."
+    },
+    {
+      "code": 6009,
+      "name": "invalidSubscriptionPeriod",
+      "msg": "Subscription periods and period lengths must be greater than zero."
+    },
+    {
+      "code": 6010,
+      "name": "tooManyTierContentIds",
+      "msg": "A subscription tier can list at most 32 content IDs."
+    },
+    {
+      "code": 6011,
+      "name": "subscriptionTierMismatch",
+      "msg": "An active subscription can only be renewed on its current tier."
+    },
+    {
+      "code": 6012,
+      "name": "priceAboveMaximum",
+      "msg": "The content price is higher than the maximum price the buyer accepted."
+    },
+    {
+      "code": 6013,
+      "name": "feeAboveMaximum",
+      "msg": "The platform fee is higher than the maximum fee the buyer accepted."
+    },
+    {
+      "code": 6014,
+      "name": "invalidLegacyCreator",
+      "msg": "The creator account is not in the legacy layout, or the content accounts don't match its content list."
+    },
+    {
+      "code": 6015,
+      "name": "titleTooLong",
+      "msg": "Title is too long. Must be at most 128 bytes."
+    },
+    {
+      "code": 6016,
+      "name": "encryptedCidTooLong",
+      "msg": "Encrypted CID is too long. Must be at most 128 bytes."
+    },
+    {
+      "code": 6017,
+      "name": "profileCidTooLong",
+      "msg": "Profile CID is too long. Must be at most 100 bytes."
+    },
+    {
+      "code": 6018,
+      "name": "contentUnlisted",
+      "msg": "This content has been unlisted by its creator and cannot be bought."
+    },
+    {
+      "code": 6019,
+      "name": "invalidLegacyReceipt",
+      "msg": "The legacy receipt is missing or does not match the given buyer and creator."
+    },
+    {
+      "code": 6020,
+      "name": "invalidLegacyConfig",
+      "msg": "The config account is missing or not in the legacy layout."
+    },
+    {
+      "code": 6021,
+      "name": "noPendingAdmin",
+      "msg": "There is no pending admin proposal."
+    },
+    {
+      "code": 6022,
+      "name": "insufficientTreasuryBalance",
+      "msg": "The treasury does not hold enough to cover this withdrawal."
+    },
+    {
+      "code": 6023,
+      "name": "protocolPaused",
+      "msg": "This instruction is paused by the protocol admin or guardian."
+    },
+    {
+      "code": 6024,
+      "name": "invalidPauseFlags",
+      "msg": "Unknown pause flags. Only the PAUSE_* bits can be set."
+    },
+    {
+      "code": 6025,
+      "name": "feeAboveCap",
+      "msg": "The fee is above the protocol's fee cap."
+    },
+    {
+      "code": 6026,
+      "name": "tooManyFeeTiers",
+      "msg": "The protocol config can hold at most 4 fee tiers."
+    },
+    {
+      "code": 6027,
+      "name": "invalidFeeTiers",
+      "msg": "Fee tiers must be listed by strictly increasing minimum sales."
+    },
+    {
+      "code": 6028,
+      "name": "invalidRevenueSplit",
+      "msg": "Revenue split shares must be non-zero and sum to 10000 basis points."
+    },
+    {
+      "code": 6029,
+      "name": "tooManySplitRecipients",
+      "msg": "A revenue split can have at most 8 recipients."
+    },
+    {
+      "code": 6030,
+      "name": "splitRecipientMismatch",
+      "msg": "The split recipient accounts are missing or do not match the stored revenue split."
+    },
+    {
+      "code": 6031,
+      "name": "invalidAffiliateShare",
+      "msg": "Invalid affiliate share. Must be <= 10000 (100%)."
+    },
+    {
+      "code": 6032,
+      "name": "invalidReferrer",
+      "msg": "The referrer's accounts are missing or not registered for the content's currency."
+    },
+    {
+      "code": 6033,
+      "name": "selfReferral",
+      "msg": "A buyer cannot refer their own purchase."
+    },
+    {
+      "code": 6034,
+      "name": "invalidBundle",
+      "msg": "A bundle must list between 1 and 32 distinct content IDs of its creator."
+    },
+    {
+      "code": 6035,
+      "name": "invalidDiscount",
+      "msg": "Invalid discount. Must be <= 10000 (100%)."
+    },
+    {
+      "code": 6036,
+      "name": "invalidCoupon",
+      "msg": "Invalid coupon. Codes are 1 to 32 bytes, discounts and limits must be non-zero and expiry in the future."
+    },
+    {
+      "code": 6037,
+      "name": "couponNotApplicable",
+      "msg": "This coupon can't be used on this purchase."
+    },
+    {
+      "code": 6038,
+      "name": "couponExpired",
+      "msg": "This coupon has expired."
+    },
+    {
+      "code": 6039,
+      "name": "couponLimitReached",
+      "msg": "This coupon has no redemptions left."
+    },
+    {
+      "code": 6040,
+      "name": "supporterStatsMissing",
+      "msg": "The creator has no supporter stats for this currency."
+    },
+    {
+      "code": 6041,
+      "name": "invalidSupporterStats",
+      "msg": "Supporter stats don't belong to this creator or currency."
+    },
+    {
+      "code": 6042,
+      "name": "invalidTipAmount",
+      "msg": "Tips must be greater than zero."
+    },
+    {
+      "code": 6043,
+      "name": "tipMessageTooLong",
+      "msg": "Tip message exceeds maximum length of 140 bytes."
+    },
+    {
+      "code": 6044,
+      "name": "rentalUnavailable",
+      "msg": "This content isn't offered for rent."
+    },
+    {
+      "code": 6045,
+      "name": "invalidRentalTerms",
+      "msg": "Invalid rental terms. The duration must be greater than zero."
+    },
+    {
+      "code": 6046,
+      "name": "notARental",
+      "msg": "This receipt is for a purchase, not a rental."
+    },
+    {
+      "code": 6047,
+      "name": "alreadyPurchased",
+      "msg": "This content has already been bought."
+    },
+    {
+      "code": 6048,
+      "name": "soldOut",
+      "msg": "This content is sold out."
+    },
+    {
+      "code": 6049,
+      "name": "invalidMaxSupply",
+      "msg": "Invalid max supply. It can only be lowered, and not below the copies already sold."
+    },
+    {
+      "code": 6050,
+      "name": "contentNotBundleable",
+      "msg": "Limited editions and unlisted content can't be sold in a bundle."
+    },
+    {
+      "code": 6051,
+      "name": "limitedEditionRental",
+      "msg": "Limited editions can't be offered for rent."
+    }
+  ],
+  "types": [
+    {
+      "name": "adminProposalCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "cancelledAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminProposed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminTransferred",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousAdminWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "adminWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "affiliateShareUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "affiliateBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bundle",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "bundleId",
+            "type": "u8"
+          },
+          {
+            "name": "contentIds",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "discountBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "transferFeePayer",
+            "type": {
+              "defined": {
+                "name": "transferFeePayer"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bundlePurchased",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "bundleId",
+            "type": "u8"
+          },
+          {
+            "name": "contentIds",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "creatorAmount",
+            "type": "u64"
+          },
+          {
+            "name": "buyerTotal",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bundleReceipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "bundleId",
+            "type": "u8"
+          },
+          {
+            "name": "contentIds",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "creatorAmount",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bundleRemoved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "bundleId",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bundleSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "bundleId",
+            "type": "u8"
+          },
+          {
+            "name": "contentIds",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "discountBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configInitialized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "feePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "feeRounding",
+            "type": {
+              "defined": {
+                "name": "feeRounding"
+              }
+            }
+          },
+          {
+            "name": "feeRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configMigrated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "feePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousFeePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "feePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "feeRounding",
+            "type": {
+              "defined": {
+                "name": "feeRounding"
+              }
+            }
+          },
+          {
+            "name": "pendingFeePercentage",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "feeEffectiveAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "contentAdded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "transferFeePayer",
+            "type": {
+              "defined": {
+                "name": "transferFeePayer"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "contentItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "encryptedCid",
+            "type": "bytes"
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "transferFeePayer",
+            "type": {
+              "defined": {
+                "name": "transferFeePayer"
+              }
+            }
+          },
+          {
+            "name": "listed",
+            "type": "bool"
+          },
+          {
+            "name": "affiliateBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "payWhatYouWant",
+            "type": "bool"
+          },
+          {
+            "name": "rental",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "rentalTerms"
+                }
+              }
+            }
+          },
+          {
+            "name": "maxSupply",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "sold",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "contentListingChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "listed",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "contentPurchased",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "creatorAmount",
+            "type": "u64"
+          },
+          {
+            "name": "buyerTotal",
+            "type": "u64"
+          },
+          {
+            "name": "revenueSplit",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "referrer",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "referralAmount",
+            "type": "u64"
+          },
+          {
+            "name": "coupon",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "discountAmount",
+            "type": "u64"
+          },
+          {
+            "name": "edition",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "contentRented",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "creatorAmount",
+            "type": "u64"
+          },
+          {
+            "name": "buyerTotal",
+            "type": "u64"
+          },
+          {
+            "name": "revenueSplit",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "renewal",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "contentUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "coupon",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "code",
+            "type": "string"
+          },
+          {
+            "name": "discount",
+            "type": {
+              "defined": {
+                "name": "couponDiscount"
+              }
+            }
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "maxRedemptions",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "redemptions",
+            "type": "u64"
+          },
+          {
+            "name": "maxPerBuyer",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "couponCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "coupon",
+            "type": "pubkey"
+          },
+          {
+            "name": "code",
+            "type": "string"
+          },
+          {
+            "name": "discount",
+            "type": {
+              "defined": {
+                "name": "couponDiscount"
+              }
+            }
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "maxRedemptions",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxPerBuyer",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "couponDiscount",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "percentOff",
+            "fields": [
+              {
+                "name": "bps",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "amountOff",
+            "fields": [
+              {
+                "name": "amount",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "couponRedemption",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "coupon",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "redemptions",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "couponRemoved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "code",
+            "type": "string"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "creatorAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creatorWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "lastContentId",
+            "type": "u64"
+          },
+          {
+            "name": "profileCid",
+            "type": "string"
+          },
+          {
+            "name": "salesCount",
+            "type": "u64"
+          },
+          {
+            "name": "affiliateBps",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "creatorContentMigrated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "migrated",
+            "type": "u64"
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "creatorInitialized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "creatorTipped",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tipper",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "creatorAmount",
+            "type": "u64"
+          },
+          {
+            "name": "message",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeOverride",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeBps",
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeOverrideRemoved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeOverrideSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeBps",
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeRecipientUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousFeeRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeRounding",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "down"
+          },
+          {
+            "name": "up"
+          },
+          {
+            "name": "halfUp"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minSales",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTiersUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeTiers",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeTier"
+                }
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "governanceUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousGovernance",
+            "type": "pubkey"
+          },
+          {
+            "name": "governance",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "guardianUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousGuardian",
+            "type": "pubkey"
+          },
+          {
+            "name": "guardian",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "maxFeePercentageUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousMaxFeePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "maxFeePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "maxSupplySet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "maxSupply",
+            "type": "u64"
+          },
+          {
+            "name": "sold",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paidAccessAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "creatorAmount",
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "referrer",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "referralAmount",
+            "type": "u64"
+          },
+          {
+            "name": "coupon",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "discountAmount",
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "edition",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pauseUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousPaused",
+            "type": "u8"
+          },
+          {
+            "name": "paused",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "payWhatYouWantChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "profileUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "profileCid",
+            "type": "string"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "feePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "feeRounding",
+            "type": {
+              "defined": {
+                "name": "feeRounding"
+              }
+            }
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feeRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "type": "u8"
+          },
+          {
+            "name": "guardian",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingFeePercentage",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "feeEffectiveAt",
+            "type": "i64"
+          },
+          {
+            "name": "maxFeePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "governance",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeTiers",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeTier"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "receiptMigrated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referrerRegistered",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referrer",
+            "type": "pubkey"
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referrerStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referrer",
+            "type": "pubkey"
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "referralCount",
+            "type": "u64"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rentalTerms",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "duration",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rentalTermsChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "rental",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "rentalTerms"
+                }
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "revenueSplit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "recipients",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "splitRecipient"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "revenueSplitRemoved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "revenueSplitSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentId",
+            "type": "u64"
+          },
+          {
+            "name": "recipients",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "splitRecipient"
+                }
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "usernameAccount",
-      "discriminator": [
-        120,
-        2,
-        212,
-        44,
-        208,
-        63,
-        20,
-        122
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "unauthorized",
-      "msg": "You are not authorized to perform this action."
+      "name": "splitRecipient",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "shareBps",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "contentNotFound",
-      "msg": "The specified content was not found in the creator's account."
+      "name": "subscription",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "subscriber",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "tierId",
+            "type": "u8"
+          },
+          {
+            "name": "startedAt",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "invalidUsername",
-      "msg": "Invalid username. Must be 3-32 characters, alphanumeric or underscore only."
+      "name": "subscriptionPaid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "subscriber",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "tierId",
+            "type": "u8"
+          },
+          {
+            "name": "periods",
+            "type": "u32"
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "creatorAmount",
+            "type": "u64"
+          },
+          {
+            "name": "buyerTotal",
+            "type": "u64"
+          },
+          {
+            "name": "startedAt",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "renewal",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "invalidFeePercentage",
-      "msg": "Invalid fee percentage. Must be <= 10000 (100%)."
-    }
-  ],
-  "types": [
-    {
-      "name": "contentItem",
+      "name": "subscriptionTier",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "id",
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "tierId",
+            "type": "u8"
+          },
+          {
+            "name": "price",
             "type": "u64"
           },
           {
-            "name": "title",
-            "type": "string"
+            "name": "periodSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "transferFeePayer",
+            "type": {
+              "defined": {
+                "name": "transferFeePayer"
+              }
+            }
+          },
+          {
+            "name": "contentIds",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "subscriptionTierCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "tierId",
+            "type": "u8"
           },
           {
             "name": "price",
             "type": "u64"
           },
           {
-            "name": "encryptedCid",
-            "type": "bytes"
+            "name": "periodSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "contentIds",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "creatorAccount",
+      "name": "subscriptionTierUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "creatorWallet",
+            "name": "creator",
             "type": "pubkey"
           },
           {
-            "name": "lastContentId",
+            "name": "tierId",
+            "type": "u8"
+          },
+          {
+            "name": "price",
             "type": "u64"
           },
           {
-            "name": "content",
+            "name": "periodSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "contentIds",
             "type": {
-              "vec": {
-                "defined": {
-                  "name": "contentItem"
-                }
-              }
+              "vec": "u64"
             }
           },
           {
-            "name": "profileCid",
-            "type": "string"
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "paidAccessAccount",
+      "name": "supporterStats",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "buyer",
+            "name": "creator",
             "type": "pubkey"
           },
           {
-            "name": "contentId",
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "tipCount",
+            "type": "u64"
+          },
+          {
+            "name": "totalTipped",
             "type": "u64"
           },
+          {
+            "name": "payWhatYouWantCount",
+            "type": "u64"
+          },
+          {
+            "name": "totalOverpaid",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "supporterStatsCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "creator",
             "type": "pubkey"
           },
           {
-            "name": "createdAt",
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "timestamp",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "protocolConfig",
+      "name": "transferFeePayer",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "buyer"
+          },
+          {
+            "name": "creator"
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasury",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "adminWallet",
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasuryWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeRecipient",
             "type": "pubkey"
           },
           {
-            "name": "feePercentage",
+            "name": "paymentMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "amount",
             "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
@@ -624,6 +7553,26 @@ export type AutonProgram = {
           }
         ]
       }
+    },
+    {
+      "name": "usernameRegistered",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "username",
+            "type": "string"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     }
   ]
 };
@@ -0,0 +1,49 @@
+import * as anchor from '@coral-xyz/anchor';
+import { PublicKey } from '@solana/web3.js';
+import bs58 from 'bs58';
+import IDL from '@/lib/anchor/auton_program.json';
+
+// Receipts from before receipts were scoped per creator only hold the buyer, content ID,
+// creator and creation time, so the current PaidAccessAccount layout can't decode them.
+// They live at [b"access", buyer, content_id] until `migrate_receipt` moves them.
+// Layout: discriminator (8) | buyer (32) | content_id u64 LE (8) | creator (32) | created_at i64 LE (8)
+export const LEGACY_RECEIPT_LEN = 8 + 32 + 8 + 32 + 8;
+
+export const PAID_ACCESS_DISCRIMINATOR = Buffer.from(
+  IDL.accounts.find((account) => account.name === 'PaidAccessAccount')!.discriminator
+);
+
+export type LegacyReceipt = {
+  buyer: PublicKey;
+  contentId: anchor.BN;
+  creator: PublicKey;
+  createdAt: anchor.BN;
+};
+
+// Returns null unless `data` is a receipt in the legacy layout.
+export function decodeLegacyReceipt(data: Buffer): LegacyReceipt | null {
+  if (data.length !== LEGACY_RECEIPT_LEN || !data.subarray(0, 8).equals(PAID_ACCESS_DISCRIMINATOR)) {
+    return null;
+  }
+  return {
+    buyer: new PublicKey(data.subarray(8, 40)),
+    contentId: new anchor.BN(data.subarray(40, 48), 'le'),
+    creator: new PublicKey(data.subarray(48, 80)),
+    createdAt: new anchor.BN(data.subarray(80, 88), 'le'),
+  };
+}
+
+// Fetches receipts matching `filters` in either layout. `program.account.paidAccessAccount.all`
+// can't be used while legacy receipts remain, since it fails on the first one it can't decode.
+export async function fetchReceipts(
+  program: anchor.Program<any>,
+  filters: { memcmp: { offset: number; bytes: string } }[]
+) {
+  const accounts = await program.provider.connection.getProgramAccounts(program.programId, {
+    filters: [{ memcmp: { offset: 0, bytes: bs58.encode(PAID_ACCESS_DISCRIMINATOR) } }, ...filters],
+  });
+  return accounts.map(({ pubkey, account }) => ({
+    publicKey: pubkey,
+    account: decodeLegacyReceipt(account.data) ?? program.coder.accounts.decode('paidAccessAccount', account.data),
+  }));
+}
@@ -23,5 +23,5 @@ export type ContentItem = {
 export type CreatorAccountData = {
   creatorWallet: PublicKey;
   lastContentId: anchor.BN;
-  content: ContentItem[];
+  content: ContentItem[]; // Loaded from the content item PDAs, not stored on the creator account
 };